@@ -0,0 +1,338 @@
+//! # Adjustments
+//! Levels and curves on the gamma encoded channels of an image, like image editors do. <br>
+//! A [ToneCurve] maps a channel value in 0..=1 and can be compiled to lookup tables for u8 and u16 values.
+//! [Channels] applies a curve to every channel and then a master curve to all of them.
+//! The alpha is kept.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::adjust::{Channels, Curve, Levels};
+//! use xblend::image::ImageViewMut;
+//! // stretch 0.2..0.8 to the full range
+//! let levels = Levels{ input_black : 0.2, input_white : 0.8, ..Levels::default() };
+//! let mut pixels = [rgba!(51,128,204,100)];
+//! Channels::new(levels).apply_image(&mut ImageViewMut::from_slice(&mut pixels, 1, 1).unwrap());
+//! assert_eq!(pixels[0], rgba!(0,128,255,100));
+//! // an S curve on the red channel only
+//! let s = Curve::new(&[(0.0, 0.0), (0.25, 0.15), (0.75, 0.85), (1.0, 1.0)]);
+//! let channels = Channels::per_channel(s, Curve::default(), Curve::default());
+//! assert_eq!(channels.luts_u8()[0][64], 39);
+//! assert_eq!(channels.luts_u8()[1][64], 64);
+//! ```
+use std::cell::OnceCell;
+use crate::RGB;
+use crate::image::{ImageViewMut, RgbPixel, map_image_in_place};
+use crate::stats::{Histogram, Stats};
+
+/// A function of a channel value in 0..=1
+pub trait ToneCurve {
+    /// Map a channel value.
+    fn map(&self, v : f32) -> f32;
+
+    /// Compile the curve to a lookup table of u8 values, rounded and clamped.
+    fn lut_u8(&self) -> [u8; 256] {
+        std::array::from_fn(|i| (self.map(i as f32 / 255.0).clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
+    /// Compile the curve to a lookup table of the 65536 u16 values, rounded and clamped.
+    fn lut_u16(&self) -> Vec<u16> {
+        (0..=u16::MAX).map(|i| (self.map(i as f32 / 65535.0).clamp(0.0, 1.0) * 65535.0).round() as u16).collect()
+    }
+}
+
+/// Input and output levels with a gamma<br>
+/// ```out = output_black + (output_white - output_black) * x^(1 / gamma)```
+/// where ```x = (v - input_black) / (input_white - input_black)``` is clamped to 0..=1.
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub struct Levels {
+    pub input_black : f32,
+    pub input_white : f32,
+    /// Above 1 lightens the midtones, below 1 darkens them
+    pub gamma : f32,
+    pub output_black : f32,
+    pub output_white : f32,
+}
+
+impl Default for Levels {
+    /// The identity
+    fn default() -> Self {
+        Levels{ input_black : 0.0, input_white : 1.0, gamma : 1.0, output_black : 0.0, output_white : 1.0 }
+    }
+}
+
+impl ToneCurve for Levels {
+    fn map(&self, v : f32) -> f32 {
+        let range = self.input_white - self.input_black;
+        let x = if range > 0.0 {
+            ((v - self.input_black) / range).clamp(0.0, 1.0)
+        } else if v >= self.input_white {
+            1.0
+        } else {
+            0.0
+        };
+        let x = if self.gamma > 0.0 && self.gamma != 1.0 { x.powf(1.0 / self.gamma) } else { x };
+        self.output_black + (self.output_white - self.output_black) * x
+    }
+}
+
+/// A curve through control points with a monotonic cubic interpolation (Fritsch-Carlson),
+/// which does not overshoot between the points. It is flat before the first point and after the last one.
+#[derive(Debug,Clone,PartialEq)]
+pub struct Curve {
+    points : Vec<(f32, f32)>,
+    /// The slope at every point
+    tangents : Vec<f32>,
+}
+
+impl Default for Curve {
+    /// The identity
+    fn default() -> Self {
+        Curve::new(&[(0.0, 0.0), (1.0, 1.0)])
+    }
+}
+
+impl Curve {
+    /// Create a curve through control points, in any order.
+    /// Of the points with the same x the last one is kept. Without a point the curve is the identity.
+    /// ```
+    /// # extern crate xblend;
+    /// use xblend::adjust::{Curve, ToneCurve};
+    /// let curve = Curve::new(&[(1.0, 1.0), (0.0, 0.0), (0.5, 0.8)]);
+    /// assert_eq!(curve.map(0.5), 0.8);
+    /// // monotonic between the points
+    /// let lut = curve.lut_u8();
+    /// assert!(lut.windows(2).all(|w| w[0] <= w[1]));
+    /// assert_eq!((lut[0], lut[255]), (0, 255));
+    /// ```
+    pub fn new(points : &[(f32, f32)]) -> Curve {
+        if points.is_empty() {
+            return Curve::default();
+        }
+        let mut sorted : Vec<(f32, f32)> = Vec::with_capacity(points.len());
+        for &p in points.iter().rev() {
+            if !sorted.iter().any(|q| q.0 == p.0) {
+                sorted.push(p);
+            }
+        }
+        sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let n = sorted.len();
+        let secants : Vec<f32> = sorted.windows(2).map(|w| (w[1].1 - w[0].1) / (w[1].0 - w[0].0)).collect();
+        let mut tangents = vec![0.0; n];
+        if n > 1 {
+            tangents[0] = secants[0];
+            tangents[n - 1] = secants[n - 2];
+            for k in 1..n - 1 {
+                let (d0, d1) = (secants[k - 1], secants[k]);
+                tangents[k] = if d0 * d1 > 0.0 { (d0 + d1) / 2.0 } else { 0.0 };
+            }
+            // limit the tangents so that every segment stays monotonic
+            for (k, &d) in secants.iter().enumerate() {
+                if d == 0.0 {
+                    tangents[k] = 0.0;
+                    tangents[k + 1] = 0.0;
+                    continue;
+                }
+                let (a, b) = (tangents[k] / d, tangents[k + 1] / d);
+                let h = a.hypot(b);
+                if h > 3.0 {
+                    tangents[k] = 3.0 / h * a * d;
+                    tangents[k + 1] = 3.0 / h * b * d;
+                }
+            }
+        }
+        Curve{ points : sorted, tangents }
+    }
+
+    /// Get the control points sorted by x.
+    pub fn points(&self) -> &[(f32, f32)] {
+        &self.points
+    }
+}
+
+impl ToneCurve for Curve {
+    fn map(&self, v : f32) -> f32 {
+        let (first, last) = (self.points[0], self.points[self.points.len() - 1]);
+        if v.is_nan() || v <= first.0 {
+            return first.1;
+        }
+        if v >= last.0 {
+            return last.1;
+        }
+        let k = self.points.partition_point(|p| p.0 <= v) - 1;
+        let ((x0, y0), (x1, y1)) = (self.points[k], self.points[k + 1]);
+        let h = x1 - x0;
+        let t = (v - x0) / h;
+        let (t2, t3) = (t * t, t * t * t);
+        (2.0 * t3 - 3.0 * t2 + 1.0) * y0 + (t3 - 2.0 * t2 + t) * h * self.tangents[k]
+            + (3.0 * t2 - 2.0 * t3) * y1 + (t3 - t2) * h * self.tangents[k + 1]
+    }
+}
+
+/// A curve for every channel and a master curve applied after them
+#[derive(Debug,Clone,PartialEq)]
+pub struct Channels<T> {
+    pub red : T,
+    pub green : T,
+    pub blue : T,
+    pub master : T,
+}
+
+impl<T : ToneCurve + Default> Channels<T> {
+    /// Apply the same curve to every channel.
+    pub fn new(master : T) -> Channels<T> {
+        Channels{ red : T::default(), green : T::default(), blue : T::default(), master }
+    }
+
+    /// Apply a curve to each channel.
+    pub fn per_channel(red : T, green : T, blue : T) -> Channels<T> {
+        Channels{ red, green, blue, master : T::default() }
+    }
+}
+
+impl<T : ToneCurve> Channels<T> {
+    /// The red, green and blue curves, each followed by the master curve
+    fn curves(&self) -> [Then<'_, T>; 3] {
+        [&self.red, &self.green, &self.blue].map(|c| Then{ first : c, second : &self.master })
+    }
+
+    /// Map a gamma encoded color.
+    pub fn map(&self, color : RGB<f32>) -> RGB<f32> {
+        let [r, g, b] = self.curves();
+        RGB::new(r.map(color.r()), g.map(color.g()), b.map(color.b()))
+    }
+
+    /// Compile the red, green and blue curves, each followed by the master curve, to u8 lookup tables.
+    pub fn luts_u8(&self) -> [[u8; 256]; 3] {
+        self.curves().map(|c| c.lut_u8())
+    }
+
+    /// Compile the red, green and blue curves, each followed by the master curve, to u16 lookup tables.
+    pub fn luts_u16(&self) -> [Vec<u16>; 3] {
+        self.curves().map(|c| c.lut_u16())
+    }
+
+    /// Adjust every pixel of an image, the result is clamped to 0..=1.
+    /// The u8 pixels go through the tables of [Channels::luts_u8].
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::adjust::{Channels, Levels};
+    /// use xblend::image::ImageViewMut;
+    /// let levels = Levels{ input_black : 0.1, gamma : 1.6, output_white : 0.9, ..Levels::default() };
+    /// let mut channels = Channels::per_channel(levels, Levels::default(), Levels::default());
+    /// channels.master = Levels{ gamma : 0.8, output_black : 0.05, ..Levels::default() };
+    /// let mut pixels : Vec<RGBA<u8>> = (0..=255).map(|v| rgba!(v, v, 255 - v, v)).collect();
+    /// let mut floats : Vec<RGB<f32>> = pixels.iter().map(|p| p.rgb().to_f32()).collect();
+    /// channels.apply_image(&mut ImageViewMut::from_slice(&mut pixels, 16, 16).unwrap());
+    /// channels.apply_image(&mut ImageViewMut::from_slice(&mut floats, 16, 16).unwrap());
+    /// let luts = channels.luts_u8();
+    /// for (v, (p, f)) in pixels.iter().zip(&floats).enumerate() {
+    ///     assert_eq!(*p, rgba!(luts[0][v], luts[1][v], luts[2][255 - v], v as u8));
+    ///     // the same as the rounded f32 result
+    ///     let near = |u : u8, f : f32| (u as f32 - f * 255.0).abs() <= 0.5;
+    ///     assert!(near(p.r(), f.r()) && near(p.g(), f.g()) && near(p.b(), f.b()));
+    /// }
+    /// ```
+    pub fn apply_image<P : RgbPixel>(&self, image : &mut ImageViewMut<'_, P>) {
+        // the tables are compiled at the first u8 pixel
+        let luts = OnceCell::new();
+        map_image_in_place(image, |p| {
+            let lut = |c : RGB<u8>| {
+                let luts : &[[u8; 256]; 3] = luts.get_or_init(|| self.luts_u8());
+                RGB::new(luts[0][c.r() as usize], luts[1][c.g() as usize], luts[2][c.b() as usize])
+            };
+            p.map_rgb_u8(lut).unwrap_or_else(|| p.map_rgb(|c| {
+                let c = self.map(c);
+                RGB::new(c.r().clamp(0.0, 1.0), c.g().clamp(0.0, 1.0), c.b().clamp(0.0, 1.0))
+            }))
+        });
+    }
+}
+
+/// A curve followed by another one
+struct Then<'a, T>{
+    first : &'a T,
+    second : &'a T,
+}
+
+impl<T : ToneCurve> ToneCurve for Then<'_, T>{
+    fn map(&self, v : f32) -> f32 {
+        self.second.map(self.first.map(v))
+    }
+}
+
+/// The levels that stretch black..white to the full range, the identity if the range is empty
+fn stretch(black : Option<f32>, white : Option<f32>) -> Levels {
+    match (black, white) {
+        (Some(black), Some(white)) if white > black => Levels{ input_black : black, input_white : white, ..Levels::default() },
+        _ => Levels::default(),
+    }
+}
+
+/// The value of the first bin where the cumulative weight exceeds p percent of the total
+fn bound<'a>(histogram : &Histogram, p : f32, bins : impl Iterator<Item = (usize, &'a f64)>) -> Option<f32> {
+    let clipped = histogram.weight() * p.clamp(0.0, 100.0) as f64 / 100.0;
+    let mut cumulative = 0.0;
+    for (i, &w) in bins {
+        cumulative += w;
+        if w > 0.0 && cumulative > clipped {
+            return Some(histogram.value_of(i));
+        }
+    }
+    None
+}
+
+/// The first and the last bins beyond the darkest ```low``` and the lightest ```high``` percent of the weight
+fn clip(histogram : &Histogram, low : f32, high : f32) -> (Option<f32>, Option<f32>) {
+    let bins = histogram.bins();
+    (bound(histogram, low, bins.iter().enumerate()), bound(histogram, high, bins.iter().enumerate().rev()))
+}
+
+/// Stretch every channel on its own to the full range,
+/// ignoring the darkest ```low``` and the lightest ```high``` percent of its histogram. <br>
+/// It removes a color cast, and may change the hues.
+/// The stats should be measured in gamma encoded sRGB like the adjustment.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::adjust::auto_levels;
+/// use xblend::image::{ImageView, ImageViewMut};
+/// use xblend::stats::Analyzer;
+/// // a dull image with a blue cast
+/// let mut pixels : Vec<RGB<u8>> = (0..100).map(|i| rgb!(50 + i, 60 + i, 100 + i)).collect();
+/// let stats = Analyzer::new().analyze(&ImageView::from_slice(&pixels, 10, 10).unwrap());
+/// auto_levels(&stats, 0.0, 0.0).apply_image(&mut ImageViewMut::from_slice(&mut pixels, 10, 10).unwrap());
+/// assert_eq!((pixels[0], pixels[99]), (rgb!(0,0,0), rgb!(255,255,255)));
+/// ```
+pub fn auto_levels(stats : &Stats, low : f32, high : f32) -> Channels<Levels> {
+    let levels = |h : &Histogram| {
+        let (black, white) = clip(h, low, high);
+        stretch(black, white)
+    };
+    Channels::per_channel(levels(&stats.red), levels(&stats.green), levels(&stats.blue))
+}
+
+/// Stretch all channels together to the full range,
+/// ignoring the darkest ```low``` and the lightest ```high``` percent of every channel. <br>
+/// It keeps the balance of the channels.
+/// The stats should be measured in gamma encoded sRGB like the adjustment.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::adjust::auto_contrast;
+/// use xblend::image::{ImageView, ImageViewMut};
+/// use xblend::stats::Analyzer;
+/// let mut pixels : Vec<RGB<u8>> = (0..100).map(|i| rgb!(50 + i, 60 + i, 100 + i)).collect();
+/// // the outlier is clipped
+/// pixels[0] = rgb!(0,0,0);
+/// let stats = Analyzer::new().analyze(&ImageView::from_slice(&pixels, 10, 10).unwrap());
+/// auto_contrast(&stats, 1.0, 0.0).apply_image(&mut ImageViewMut::from_slice(&mut pixels, 10, 10).unwrap());
+/// assert_eq!(pixels[1], rgb!(0,17,86));
+/// assert_eq!(pixels[99], rgb!(169,186,255));
+/// ```
+pub fn auto_contrast(stats : &Stats, low : f32, high : f32) -> Channels<Levels> {
+    let channels = [&stats.red, &stats.green, &stats.blue].map(|h| clip(h, low, high));
+    let black = channels.iter().filter_map(|c| c.0).reduce(f32::min);
+    let white = channels.iter().filter_map(|c| c.1).reduce(f32::max);
+    Channels::new(stretch(black, white))
+}
@@ -1,6 +1,7 @@
 //! # Some blend modes
 //! Assume that we have to colors called ```src``` and ```dst```<br>
 //! The ```output``` is the result color
+use crate::RGB;
 
 /// Clear blending mode <br>
 /// ```output = (0,0,0,0)```
@@ -113,3 +114,129 @@ pub trait Screen{
     type Output;
     fn screen(self,rhs : Self) -> Self::Output;
 }
+
+/// A blending mode chosen at runtime. <br>
+/// Every variant corresponds to the trait with the same name.
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum BlendMode {
+    Clear,
+    Src,
+    Dst,
+    #[default]
+    SrcOver,
+    DstOver,
+    SrcIn,
+    DstIn,
+    SrcOut,
+    DstOut,
+    SrcATop,
+    DstATop,
+    Xor,
+    Darken,
+    Lighten,
+    Multiply,
+    Screen,
+}
+
+/// A color that can be blended with a [BlendMode] selected at runtime.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::blend::{Blend, BlendMode, SrcATop};
+/// let src = rgba!(0.4,0.2,0.1,0.5);
+/// let dst = rgba!(0.5,0.5,0.0,0.5);
+/// assert_eq!(src.blend(dst, BlendMode::SrcATop), src.src_atop(dst));
+/// ```
+pub trait Blend : Copy {
+    /// Blend ```self``` as ```src``` with ```rhs``` as ```dst```. <br>
+    /// RGBA&lt;u8&gt; results are rounded to the nearest value in every mode,
+    /// products like ```round(a * b / 255)```,
+    /// and the scalar and SIMD paths give identical results.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::blend::{Blend, BlendMode};
+    /// let src = rgba!(100,50,0,128);
+    /// let dst = rgba!(200,100,40,255);
+    /// // 100 * 200 / 255 = 78.4 and 50 * 100 / 255 = 19.6
+    /// assert_eq!(src.blend(dst, BlendMode::Multiply), rgba!(78,20,0,128));
+    /// // the other modes round too, green is 50 + 100 * 127 / 255 = 99.8
+    /// assert_eq!(src.blend(dst, BlendMode::SrcATop), rgba!(200,100,20,255));
+    /// ```
+    fn blend(self, rhs : Self, mode : BlendMode) -> Self;
+
+    /// Scale all components by a constant opacity in 0..=1.
+    fn fade(self, opacity : f32) -> Self;
+
+    /// Blend every pixel of ```src``` onto the same pixel of ```dst``` in place. <br>
+    /// RGBA&lt;u8&gt; and RGBA&lt;f32&gt; use SIMD kernels for SrcOver, Multiply and Screen
+    /// when the CPU supports them, which give exactly the same result as [Blend::blend].
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::blend::{Blend, BlendMode};
+    /// let mut seed = 1u32;
+    /// let mut next = move || { seed = seed.wrapping_mul(1664525).wrapping_add(1013904223); (seed >> 24) as u8 };
+    /// let mut premultiplied = || { let a = next(); let mut c = || next() % a.saturating_add(1); rgba!(c(), c(), c(), a) };
+    /// let src : Vec<RGBA<u8>> = (0..37).map(|_| premultiplied()).collect();
+    /// let dst : Vec<RGBA<u8>> = (0..37).map(|_| premultiplied()).collect();
+    /// for &mode in &[BlendMode::SrcOver, BlendMode::Multiply, BlendMode::Screen] {
+    ///     let mut out = dst.clone();
+    ///     RGBA::blend_slice(&src, &mut out, mode);
+    ///     let mut out_f32 : Vec<RGBA<f32>> = dst.iter().map(|c| c.to_f32()).collect();
+    ///     let src_f32 : Vec<RGBA<f32>> = src.iter().map(|c| c.to_f32()).collect();
+    ///     RGBA::blend_slice(&src_f32, &mut out_f32, mode);
+    ///     for i in 0..src.len() {
+    ///         assert_eq!(out[i], src[i].blend(dst[i], mode));
+    ///         assert_eq!(out_f32[i], src_f32[i].blend(dst[i].to_f32(), mode));
+    ///     }
+    /// }
+    /// ```
+    /// # Panics
+    /// Panics if the slices have different lengths.
+    fn blend_slice(src : &[Self], dst : &mut [Self], mode : BlendMode) {
+        assert_eq!(src.len(), dst.len(), "slices have different lengths");
+        for (s, d) in src.iter().zip(dst.iter_mut()) {
+            *d = s.blend(*d, mode);
+        }
+    }
+}
+
+impl BlendMode {
+    /// Whether the mode mixes the colors with a blend function ```B(Cb, Cs)```,
+    /// which are Darken, Lighten, Multiply and Screen. The other modes are Porter-Duff operators.
+    pub fn has_blend_function(self) -> bool {
+        matches!(self, BlendMode::Darken | BlendMode::Lighten | BlendMode::Multiply | BlendMode::Screen)
+    }
+
+    /// Whether the blend function works on every channel on its own, which are Multiply and Screen.
+    /// Darken and Lighten pick a whole color by its gray value like [Darken] and [Lighten].
+    pub fn is_separable(self) -> bool {
+        matches!(self, BlendMode::Multiply | BlendMode::Screen)
+    }
+
+    /// The blend function ```B(Cb, Cs)``` of the W3C compositing specification on non-premultiplied colors,
+    /// with the Darken and Lighten of this crate. <br>
+    /// Returns ```cs``` for modes without a blend function.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::blend::BlendMode;
+    /// let cb = rgb!(0.25,0.75,0.5);
+    /// let cs = rgb!(0.5,0.25,0.5);
+    /// assert_eq!(BlendMode::Multiply.blend_color(cb, cs), rgb!(0.125,0.1875,0.25));
+    /// assert_eq!(BlendMode::Screen.blend_color(cb, cs), rgb!(0.625,0.8125,0.75));
+    /// // the source is darker as a whole
+    /// assert_eq!(BlendMode::Darken.blend_color(cb, cs), cs);
+    /// assert_eq!(BlendMode::Lighten.blend_color(cb, cs), cb);
+    /// ```
+    pub fn blend_color(self, cb : RGB<f32>, cs : RGB<f32>) -> RGB<f32> {
+        match self {
+            BlendMode::Darken => cs.darken(cb),
+            BlendMode::Lighten => cs.lighten(cb),
+            BlendMode::Multiply => cs.multiply(cb),
+            BlendMode::Screen => cs.screen(cb),
+            _ => cs,
+        }
+    }
+}
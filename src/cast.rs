@@ -0,0 +1,136 @@
+//! # Zero-copy casting between bytes and pixels
+//! ```RGB<T>``` and ```RGBA<T>``` are ```#[repr(C)]```, so a byte buffer coming from a decoder
+//! or a window surface can be viewed as a slice of colors without copying.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::cast::{as_pixels, as_bytes};
+//! let bytes = [255u8, 0, 0, 255, 0, 255, 0, 128];
+//! let pixels : &[RGBA<u8>] = as_pixels(&bytes).unwrap();
+//! assert_eq!(pixels, &[rgba!(255,0,0,255), rgba!(0,255,0,128)]);
+//! assert_eq!(as_bytes(pixels), &bytes);
+//! ```
+use std::fmt;
+use std::mem::{align_of, size_of, size_of_val};
+use std::slice;
+use crate::{RGB, RGBA};
+
+/// A pixel type that has no padding and accepts every bit pattern.
+///
+/// # Safety
+/// Implementors must be ```#[repr(C)]``` (or transparent), contain no padding bytes,
+/// be valid for any bit pattern and not be zero-sized.
+pub unsafe trait Plain : Copy + 'static {}
+
+unsafe impl Plain for RGB<u8>{}
+unsafe impl Plain for RGBA<u8>{}
+unsafe impl Plain for RGB<f32>{}
+unsafe impl Plain for RGBA<f32>{}
+
+/// The reason why a byte slice can not be viewed as pixels.
+#[derive(Debug,Copy,Clone,PartialEq,Eq)]
+pub enum CastError {
+    /// The length of the byte slice is not a multiple of the pixel size.
+    Length{ len : usize, pixel_size : usize },
+    /// The byte slice is not aligned to the pixel alignment.
+    Alignment{ align : usize },
+}
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CastError::Length{ len, pixel_size } =>
+                write!(f, "byte length {} is not a multiple of the pixel size {}", len, pixel_size),
+            CastError::Alignment{ align } =>
+                write!(f, "byte slice is not aligned to {} bytes", align),
+        }
+    }
+}
+
+impl std::error::Error for CastError {}
+
+// usize::is_multiple_of needs Rust 1.87, newer than the rust-version of the crate
+#[allow(clippy::manual_is_multiple_of)]
+fn check<P : Plain>(ptr : *const u8, len : usize) -> Result<usize, CastError> {
+    let pixel_size = size_of::<P>();
+    if len % pixel_size != 0 {
+        return Err(CastError::Length{ len, pixel_size });
+    }
+    let align = align_of::<P>();
+    if ptr as usize % align != 0 {
+        return Err(CastError::Alignment{ align });
+    }
+    Ok(len / pixel_size)
+}
+
+/// View a byte slice as a slice of pixels.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::cast::{as_pixels, CastError};
+/// let bytes = [0u8; 7];
+/// let result : Result<&[RGB<u8>], _> = as_pixels(&bytes);
+/// assert_eq!(result, Err(CastError::Length{ len : 7, pixel_size : 3 }));
+/// ```
+pub fn as_pixels<P : Plain>(bytes : &[u8]) -> Result<&[P], CastError> {
+    let count = check::<P>(bytes.as_ptr(), bytes.len())?;
+    // SAFETY: length and alignment are checked above and P accepts every bit pattern
+    Ok(unsafe { slice::from_raw_parts(bytes.as_ptr() as *const P, count) })
+}
+
+/// View a mutable byte slice as a mutable slice of pixels.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::cast::{as_pixels_mut, CastError};
+/// let mut bytes = [0u8; 6];
+/// let pixels : &mut [RGB<u8>] = as_pixels_mut(&mut bytes).unwrap();
+/// pixels[1] = rgb!(1,2,3);
+/// assert_eq!(bytes, [0, 0, 0, 1, 2, 3]);
+/// // f32 pixels need 4 byte alignment
+/// let mut floats = [RGBA::new(0.0f32, 0.0, 0.0, 0.0); 2];
+/// let bytes = &mut xblend::cast::as_bytes_mut(&mut floats)[1..13];
+/// let result : Result<&mut [RGB<f32>], _> = as_pixels_mut(bytes);
+/// assert_eq!(result, Err(CastError::Alignment{ align : 4 }));
+/// ```
+pub fn as_pixels_mut<P : Plain>(bytes : &mut [u8]) -> Result<&mut [P], CastError> {
+    let count = check::<P>(bytes.as_ptr(), bytes.len())?;
+    // SAFETY: length and alignment are checked above and P accepts every bit pattern
+    Ok(unsafe { slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut P, count) })
+}
+
+/// View a slice of pixels as bytes.
+pub fn as_bytes<P : Plain>(pixels : &[P]) -> &[u8] {
+    // SAFETY: P has no padding, so every byte is initialized
+    unsafe { slice::from_raw_parts(pixels.as_ptr() as *const u8, size_of_val(pixels)) }
+}
+
+/// View a mutable slice of pixels as mutable bytes.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::cast::as_bytes_mut;
+/// let mut pixels = [rgba!(0,0,0,0), rgba!(10,20,30,40)];
+/// as_bytes_mut(&mut pixels)[3] = 255;
+/// assert_eq!(pixels[0], rgba!(0,0,0,255));
+/// assert_eq!(as_bytes_mut(&mut pixels).len(), 8);
+/// ```
+pub fn as_bytes_mut<P : Plain>(pixels : &mut [P]) -> &mut [u8] {
+    // SAFETY: P has no padding and accepts every bit pattern
+    unsafe { slice::from_raw_parts_mut(pixels.as_mut_ptr() as *mut u8, size_of_val(pixels)) }
+}
+
+#[cfg(feature = "bytemuck")]
+mod pod {
+    use bytemuck::{Pod, Zeroable};
+    use crate::{RGB, RGBA};
+
+    unsafe impl Zeroable for RGB<u8>{}
+    unsafe impl Zeroable for RGBA<u8>{}
+    unsafe impl Zeroable for RGB<f32>{}
+    unsafe impl Zeroable for RGBA<f32>{}
+    unsafe impl Pod for RGB<u8>{}
+    unsafe impl Pod for RGBA<u8>{}
+    unsafe impl Pod for RGB<f32>{}
+    unsafe impl Pod for RGBA<f32>{}
+}
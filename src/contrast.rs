@@ -0,0 +1,237 @@
+//! # Contrast
+//! The contrast of text on a background with WCAG 2.1 and APCA. <br>
+//! Colors are in sRGB with a straight alpha: a translucent text is composited over the background
+//! with [SrcOver], and a translucent background over white.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::contrast::*;
+//! let ratio = contrast_ratio(rgb!(0x777777), rgb!(0xFFFFFF));
+//! assert!((ratio - 4.48).abs() < 0.01);
+//! assert!(!passes(ratio, Level::AA, TextSize::Normal));
+//! assert!(passes(ratio, Level::AA, TextSize::Large));
+//! // black at 50% over white is the same as #808080
+//! assert_eq!(contrast_ratio(rgba!(0,0,0,128), rgb!(0xFFFFFF)), contrast_ratio(rgb!(0x7F7F7F), rgb!(0xFFFFFF)));
+//! ```
+use crate::{RGB, RGBA};
+use crate::blend::SrcOver;
+use crate::difference::delta_e_ok;
+use crate::space::{srgb_to_linear, Oklch};
+
+/// A color that can be flattened into an opaque color over a backdrop
+pub trait ContrastColor : Copy {
+    /// Composite the color over an opaque backdrop.
+    fn flatten(self, backdrop : RGB<f32>) -> RGB<f32>;
+}
+
+impl ContrastColor for RGB<f32>{
+    fn flatten(self, _ : RGB<f32>) -> RGB<f32> {
+        self
+    }
+}
+
+impl ContrastColor for RGB<u8>{
+    fn flatten(self, _ : RGB<f32>) -> RGB<f32> {
+        self.to_f32()
+    }
+}
+
+impl ContrastColor for RGBA<f32>{
+    fn flatten(self, backdrop : RGB<f32>) -> RGB<f32> {
+        let a = self.a();
+        let src = RGBA::new(self.r() * a, self.g() * a, self.b() * a, a);
+        src.src_over(RGBA::from_rgb(&backdrop, 1.0)).rgb()
+    }
+}
+
+impl ContrastColor for RGBA<u8>{
+    fn flatten(self, backdrop : RGB<f32>) -> RGB<f32> {
+        self.to_f32().flatten(backdrop)
+    }
+}
+
+const WHITE : RGB<f32> = RGB::new(1.0, 1.0, 1.0);
+
+/// Flatten the text and the background
+fn flatten<F : ContrastColor, B : ContrastColor>(text : F, background : B) -> (RGB<f32>, RGB<f32>) {
+    let background = background.flatten(WHITE);
+    (text.flatten(background), background)
+}
+
+/// The relative luminance of WCAG 2.1<br>
+/// ```L = 0.2126 * R + 0.7152 * G + 0.0722 * B``` with linear components.
+pub fn relative_luminance<C : ContrastColor>(color : C) -> f32 {
+    let color = color.flatten(WHITE);
+    0.2126 * srgb_to_linear(color.r()) + 0.7152 * srgb_to_linear(color.g()) + 0.0722 * srgb_to_linear(color.b())
+}
+
+/// The contrast ratio of WCAG 2.1, from 1 to 21 and independent of the order<br>
+/// ```ratio = (L1 + 0.05) / (L2 + 0.05)``` where L1 is the lighter luminance.
+pub fn contrast_ratio<F : ContrastColor, B : ContrastColor>(text : F, background : B) -> f32 {
+    let (text, background) = flatten(text, background);
+    ratio_of_luminances(relative_luminance(text), relative_luminance(background))
+}
+
+fn ratio_of_luminances(l1 : f32, l2 : f32) -> f32 {
+    (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+}
+
+/// A WCAG conformance level
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Level {
+    /// Success criterion 1.4.3
+    #[default]
+    AA,
+    /// Success criterion 1.4.6
+    AAA,
+}
+
+/// The size of the text
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum TextSize {
+    #[default]
+    Normal,
+    /// At least 18pt, or 14pt bold
+    Large,
+}
+
+/// The minimum contrast ratio of a level.
+/// ```
+/// # extern crate xblend;
+/// use xblend::contrast::*;
+/// assert_eq!(required_ratio(Level::AA, TextSize::Normal), 4.5);
+/// assert_eq!(required_ratio(Level::AAA, TextSize::Large), 4.5);
+/// ```
+pub fn required_ratio(level : Level, size : TextSize) -> f32 {
+    match (level, size) {
+        (Level::AA, TextSize::Normal) => 4.5,
+        (Level::AA, TextSize::Large) => 3.0,
+        (Level::AAA, TextSize::Normal) => 7.0,
+        (Level::AAA, TextSize::Large) => 4.5,
+    }
+}
+
+/// Check if a contrast ratio meets a level.
+pub fn passes(ratio : f32, level : Level, size : TextSize) -> bool {
+    ratio >= required_ratio(level, size)
+}
+
+/// The screen luminance of APCA with its soft clamp of the black level
+fn apca_luminance(color : RGB<f32>) -> f32 {
+    let y = 0.212_672_9 * color.r().max(0.0).powf(2.4)
+        + 0.715_152_2 * color.g().max(0.0).powf(2.4)
+        + 0.072_175 * color.b().max(0.0).powf(2.4);
+    if y < 0.022 { y + (0.022 - y).powf(1.414) } else { y }
+}
+
+/// The lightness contrast Lc of APCA 0.0.98G.<br>
+/// It is positive for dark text on a light background, negative for light text on a dark background,
+/// and its magnitude is about 0 to 108. The order of the arguments matters.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::contrast::apca_contrast;
+/// let close = |a : f32, b : f32| (a - b).abs() < 1e-3;
+/// assert!(close(apca_contrast(rgb!(0x888888), rgb!(0xFFFFFF)), 63.056));
+/// assert!(close(apca_contrast(rgb!(0xFFFFFF), rgb!(0x888888)), -68.541));
+/// assert!(close(apca_contrast(rgb!(0x000000), rgb!(0xFFFFFF)), 106.041));
+/// assert!(close(apca_contrast(rgb!(0xFFFFFF), rgb!(0x000000)), -107.885));
+/// ```
+pub fn apca_contrast<F : ContrastColor, B : ContrastColor>(text : F, background : B) -> f32 {
+    let (text, background) = flatten(text, background);
+    apca_of_luminances(apca_luminance(text), apca_luminance(background))
+}
+
+fn apca_of_luminances(text : f32, background : f32) -> f32 {
+    if (background - text).abs() < 0.0005 {
+        return 0.0;
+    }
+    if background > text {
+        let s = (background.powf(0.56) - text.powf(0.57)) * 1.14;
+        if s < 0.1 { 0.0 } else { (s - 0.027) * 100.0 }
+    } else {
+        let s = (background.powf(0.65) - text.powf(0.62)) * 1.14;
+        if s > -0.1 { 0.0 } else { (s + 0.027) * 100.0 }
+    }
+}
+
+/// A minimum contrast
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub enum Target {
+    /// A WCAG 2.1 contrast ratio, see [required_ratio]
+    Wcag(f32),
+    /// An APCA lightness contrast, the magnitude of Lc
+    Apca(f32),
+}
+
+impl Target {
+    /// Check if an opaque text color meets the target on an opaque background.
+    fn met(self, text : RGB<f32>, background : RGB<f32>) -> bool {
+        match self {
+            Target::Wcag(ratio) => contrast_ratio(text, background) >= ratio,
+            Target::Apca(lc) => apca_contrast(text, background).abs() >= lc,
+        }
+    }
+}
+
+/// The number of steps of the binary searches
+const SEARCH_STEPS : usize = 24;
+
+/// An Oklch color in the sRGB gamut, with the chroma reduced if necessary
+fn oklch_in_gamut(l : f32, c : f32, h : f32) -> RGB<f32> {
+    let in_gamut = |color : RGB<f32>| {
+        [color.r(), color.g(), color.b()].iter().all(|v| (-1e-4..=1.0 + 1e-4).contains(v))
+    };
+    let to_rgb = |c : f32| RGB::from(Oklch{ l, c, h });
+    let mut color = to_rgb(c);
+    if !in_gamut(color) {
+        let (mut lo, mut hi) = (0.0, c);
+        for _ in 0..SEARCH_STEPS {
+            let mid = (lo + hi) / 2.0;
+            if in_gamut(to_rgb(mid)) { lo = mid } else { hi = mid }
+        }
+        color = to_rgb(lo);
+    }
+    RGB::new(color.r().clamp(0.0, 1.0), color.g().clamp(0.0, 1.0), color.b().clamp(0.0, 1.0))
+}
+
+/// Find the text color nearest in Oklab that meets a target contrast,
+/// changing only the Oklch lightness of the text. <br>
+/// The chroma is reduced only where the color would leave the sRGB gamut.
+/// A translucent text is flattened over the background first, so the result is opaque.
+/// Return the text itself if it already meets the target, or None if no lightness meets it.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::contrast::*;
+/// let target = Target::Wcag(required_ratio(Level::AA, TextSize::Normal));
+/// let fixed = fix_contrast(rgb!(0x777777), rgb!(0xFFFFFF), target).unwrap();
+/// let ratio = contrast_ratio(fixed, rgb!(0xFFFFFF));
+/// assert!(ratio >= 4.5 && ratio < 4.51);
+/// // a light blue on white becomes a darker blue
+/// let fixed = fix_contrast(rgb!(0x80B0FF), rgb!(0xFFFFFF), Target::Apca(60.0)).unwrap();
+/// assert!(apca_contrast(fixed, rgb!(0xFFFFFF)) >= 60.0 && fixed.b() > fixed.r());
+/// // no color has a ratio of 10 on a middle gray
+/// assert_eq!(fix_contrast(rgb!(0x777777), rgb!(0x777777), Target::Wcag(10.0)), None);
+/// ```
+pub fn fix_contrast<F : ContrastColor, B : ContrastColor>(text : F, background : B, target : Target) -> Option<RGB<f32>> {
+    let (text, background) = flatten(text, background);
+    if target.met(text, background) {
+        return Some(text);
+    }
+    let oklch = Oklch::from(text);
+    let candidate = |l : f32| oklch_in_gamut(l, oklch.c, oklch.h);
+    // the contrast grows as the lightness moves away from the background,
+    // so the passing lightnesses on each side form a range ending at 0 or 1
+    [0.0, 1.0].iter()
+        .filter(|&&end| target.met(candidate(end), background))
+        .map(|&end| {
+            let (mut pass, mut fail) = (end, oklch.l);
+            for _ in 0..SEARCH_STEPS {
+                let mid = (pass + fail) / 2.0;
+                if target.met(candidate(mid), background) { pass = mid } else { fail = mid }
+            }
+            candidate(pass)
+        })
+        .min_by(|&a, &b| delta_e_ok(text, a).total_cmp(&delta_e_ok(text, b)))
+}
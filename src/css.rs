@@ -0,0 +1,756 @@
+//! # CSS colors
+//! Parse every color syntax of CSS Color Level 4 with [FromStr]:
+//! hex colors, ```rgb()```, ```rgba()```, ```hsl()```, ```hsla()```, ```hwb()```, ```lab()```, ```lch()```,
+//! ```oklab()```, ```oklch()```, ```color()``` with the ```srgb```, ```srgb-linear```, ```display-p3```,
+//! ```xyz```, ```xyz-d50``` and ```xyz-d65``` color spaces, named colors and ```transparent```. <br>
+//! The result has a straight (not premultiplied) alpha, and colors outside of the sRGB gamut are clipped.<br>
+//! Colors are serialized back with [Display](std::fmt::Display), [to_css_string](crate::RGBA::to_css_string) and [CssFormat].
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! let color : RGBA<u8> = "#ff000080".parse().unwrap();
+//! assert_eq!(color, rgba!(255,0,0,128));
+//! let color : RGBA<u8> = "rgb(0 128 255 / 50%)".parse().unwrap();
+//! assert_eq!(color, rgba!(0,128,255,128));
+//! let color : RGBA<u8> = "hsl(120, 100%, 25%)".parse().unwrap();
+//! assert_eq!(color, rgba!(0,128,0,255));
+//! let color : RGBA<u8> = "RebeccaPurple".parse().unwrap();
+//! assert_eq!(color, rgba!(0x663399FF));
+//! let color : RGBA<u8> = "oklch(62.8% 0.2577 29.23)".parse().unwrap();
+//! assert_eq!(color, rgba!(255,0,0,255));
+//! ```
+use std::fmt;
+use std::str::FromStr;
+use crate::{RGB, RGBA};
+use crate::named;
+use crate::space::{self, Hsl, Hwb, Lab, Lch, Oklab, Oklch, Xyz};
+
+/// What went wrong while parsing a color.
+#[derive(Debug,Copy,Clone,PartialEq,Eq)]
+pub enum ParseColorErrorKind {
+    /// The string is empty.
+    Empty,
+    /// The string ends in the middle of a color.
+    UnexpectedEnd,
+    /// A character which is not allowed here.
+    UnexpectedChar(char),
+    /// A hex color without 3, 4, 6 or 8 hex digits.
+    InvalidHex,
+    /// An unknown color name.
+    UnknownName,
+    /// An unknown color function.
+    UnknownFunction,
+    /// An unknown color space in ```color()```.
+    UnknownColorSpace,
+    /// A value of the wrong type, e.g. an angle for a red component.
+    InvalidValue,
+    /// A wrong number of components.
+    ArgumentCount,
+}
+
+/// An error with the byte position where parsing failed.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::css::ParseColorErrorKind;
+/// let error = "rgb(0 0 10deg)".parse::<RGBA<u8>>().unwrap_err();
+/// assert_eq!(error.kind(), ParseColorErrorKind::InvalidValue);
+/// assert_eq!(error.position(), 8);
+/// let error = "#12345".parse::<RGBA<u8>>().unwrap_err();
+/// assert_eq!(error.kind(), ParseColorErrorKind::InvalidHex);
+/// let error = "rgb(1, 2)".parse::<RGBA<u8>>().unwrap_err();
+/// assert_eq!((error.kind(), error.position()), (ParseColorErrorKind::ArgumentCount, 8));
+/// ```
+#[derive(Debug,Copy,Clone,PartialEq,Eq)]
+pub struct ParseColorError {
+    kind : ParseColorErrorKind,
+    position : usize,
+}
+
+impl ParseColorError {
+    /// Get the kind of the error.
+    pub fn kind(&self) -> ParseColorErrorKind {
+        self.kind
+    }
+
+    /// Get the byte position in the string where the error occurred.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self.kind {
+            ParseColorErrorKind::Empty => "empty color",
+            ParseColorErrorKind::UnexpectedEnd => "unexpected end of color",
+            ParseColorErrorKind::UnexpectedChar(c) => return write!(f, "unexpected character {:?} at {}", c, self.position),
+            ParseColorErrorKind::InvalidHex => "invalid hex color",
+            ParseColorErrorKind::UnknownName => "unknown color name",
+            ParseColorErrorKind::UnknownFunction => "unknown color function",
+            ParseColorErrorKind::UnknownColorSpace => "unknown color space",
+            ParseColorErrorKind::InvalidValue => "invalid value",
+            ParseColorErrorKind::ArgumentCount => "wrong number of components",
+        };
+        write!(f, "{} at {}", message, self.position)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// A component of a color function
+#[derive(Debug,Copy,Clone,PartialEq)]
+enum Value {
+    Number(f32),
+    Percent(f32),
+    /// An angle in degrees
+    Angle(f32),
+    None,
+}
+
+struct Parser<'a> {
+    s : &'a str,
+    pos : usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, kind : ParseColorErrorKind) -> ParseColorError {
+        ParseColorError{ kind, position : self.pos }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.s[self.pos..].chars().next()
+    }
+
+    fn unexpected(&self) -> ParseColorError {
+        match self.peek() {
+            Some(c) => self.error(ParseColorErrorKind::UnexpectedChar(c)),
+            None => self.error(ParseColorErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take_while(|c| c.is_ascii_whitespace());
+    }
+
+    fn eat(&mut self, c : char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c : char) -> Result<(), ParseColorError> {
+        if self.eat(c) { Ok(()) } else { Err(self.unexpected()) }
+    }
+
+    fn take_while<F : Fn(char) -> bool>(&mut self, f : F) -> &'a str {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if !f(c) {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+        &self.s[start..self.pos]
+    }
+
+    fn ident(&mut self) -> &'a str {
+        self.take_while(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+
+    fn color(&mut self) -> Result<RGBA<f32>, ParseColorError> {
+        self.skip_whitespace();
+        if self.pos == self.s.len() {
+            return Err(self.error(ParseColorErrorKind::Empty));
+        }
+        let color = if self.eat('#') {
+            self.hex()?
+        } else {
+            let start = self.pos;
+            let name = self.ident();
+            if name.is_empty() {
+                return Err(self.unexpected());
+            }
+            if self.eat('(') {
+                self.function(name, start)?
+            } else if name.eq_ignore_ascii_case("transparent") {
+                RGBA::new(0.0, 0.0, 0.0, 0.0)
+            } else {
+                match named::css::lookup(name) {
+                    Some(color) => color.to_f32(),
+                    None => return Err(ParseColorError{ kind : ParseColorErrorKind::UnknownName, position : start }),
+                }
+            }
+        };
+        self.skip_whitespace();
+        if self.pos != self.s.len() {
+            return Err(self.unexpected());
+        }
+        Ok(color)
+    }
+
+    fn hex(&mut self) -> Result<RGBA<f32>, ParseColorError> {
+        let start = self.pos;
+        let digits = self.take_while(|c| c.is_ascii_alphanumeric());
+        let mut v = [0u8; 8];
+        for (i, c) in digits.chars().enumerate().take(8) {
+            v[i] = c.to_digit(16).ok_or(ParseColorError{
+                kind : ParseColorErrorKind::InvalidHex,
+                position : start + i,
+            })? as u8;
+        }
+        let (r, g, b, a) = match digits.len() {
+            3 => (v[0] * 17, v[1] * 17, v[2] * 17, 255),
+            4 => (v[0] * 17, v[1] * 17, v[2] * 17, v[3] * 17),
+            6 => (v[0] << 4 | v[1], v[2] << 4 | v[3], v[4] << 4 | v[5], 255),
+            8 => (v[0] << 4 | v[1], v[2] << 4 | v[3], v[4] << 4 | v[5], v[6] << 4 | v[7]),
+            _ => return Err(ParseColorError{ kind : ParseColorErrorKind::InvalidHex, position : start }),
+        };
+        Ok(RGBA::new(r, g, b, a).to_f32())
+    }
+
+    fn number(&mut self) -> Result<f32, ParseColorError> {
+        let start = self.pos;
+        let _ = self.eat('+') || self.eat('-');
+        let integer = self.take_while(|c| c.is_ascii_digit());
+        let dot = self.pos;
+        let fraction = if self.eat('.') { self.take_while(|c| c.is_ascii_digit()) } else { "" };
+        if integer.is_empty() && fraction.is_empty() {
+            self.pos = start;
+            return Err(self.unexpected());
+        }
+        // a dot must be followed by digits, as in "1.0" or ".5"
+        if fraction.is_empty() && self.pos > dot {
+            self.pos = dot;
+            return Err(self.unexpected());
+        }
+        // an exponent is only taken when digits follow it
+        let mantissa_end = self.pos;
+        if self.eat('e') || self.eat('E') {
+            let _ = self.eat('+') || self.eat('-');
+            if self.take_while(|c| c.is_ascii_digit()).is_empty() {
+                self.pos = mantissa_end;
+            }
+        }
+        self.s[start..self.pos].parse().map_err(|_| invalid(start))
+    }
+
+    /// Parse a component and return it with its position
+    fn value(&mut self) -> Result<(Value, usize), ParseColorError> {
+        let start = self.pos;
+        if self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            if self.ident().eq_ignore_ascii_case("none") {
+                return Ok((Value::None, start));
+            }
+            self.pos = start;
+            return Err(self.unexpected());
+        }
+        let number = self.number()?;
+        let value = if self.eat('%') {
+            Value::Percent(number)
+        } else {
+            let unit_start = self.pos;
+            match self.ident().to_ascii_lowercase().as_str() {
+                "" => Value::Number(number),
+                "deg" => Value::Angle(number),
+                "rad" => Value::Angle(number.to_degrees()),
+                "grad" => Value::Angle(number * 0.9),
+                "turn" => Value::Angle(number * 360.0),
+                _ => {
+                    self.pos = unit_start;
+                    return Err(self.unexpected());
+                }
+            }
+        };
+        Ok((value, start))
+    }
+
+    /// Parse the components up to ')' in the legacy comma separated syntax
+    /// or in the space separated syntax with an optional alpha after '/'
+    fn arguments(&mut self, legacy_allowed : bool) -> Result<Arguments, ParseColorError> {
+        let mut values = Vec::new();
+        let mut alpha = None;
+        self.skip_whitespace();
+        values.push(self.value()?);
+        self.skip_whitespace();
+        let legacy = legacy_allowed && self.peek() == Some(',');
+        if legacy {
+            while self.eat(',') {
+                self.skip_whitespace();
+                values.push(self.value()?);
+                self.skip_whitespace();
+            }
+            // none is not allowed in the legacy syntax
+            if let Some(&(_, position)) = values.iter().find(|(v, _)| *v == Value::None) {
+                return Err(invalid(position));
+            }
+            if values.len() == 4 {
+                alpha = values.pop();
+            }
+        } else {
+            while !matches!(self.peek(), Some(')') | Some('/') | None) {
+                values.push(self.value()?);
+                self.skip_whitespace();
+            }
+            if self.eat('/') {
+                self.skip_whitespace();
+                alpha = Some(self.value()?);
+                self.skip_whitespace();
+            }
+        }
+        if values.len() != 3 {
+            let position = values.get(3).map_or(self.pos, |&(_, p)| p);
+            return Err(ParseColorError{ kind : ParseColorErrorKind::ArgumentCount, position });
+        }
+        self.expect(')')?;
+        Ok(Arguments{ values : [values[0], values[1], values[2]], alpha, legacy })
+    }
+
+    fn function(&mut self, name : &str, start : usize) -> Result<RGBA<f32>, ParseColorError> {
+        let name = name.to_ascii_lowercase();
+        let known = matches!(name.as_str(),
+            "rgb" | "rgba" | "hsl" | "hsla" | "hwb" | "lab" | "lch" | "oklab" | "oklch" | "color");
+        if !known {
+            return Err(ParseColorError{ kind : ParseColorErrorKind::UnknownFunction, position : start });
+        }
+        let space = if name == "color" {
+            self.skip_whitespace();
+            let space_start = self.pos;
+            let space = self.ident().to_ascii_lowercase();
+            if space.is_empty() {
+                return Err(self.unexpected());
+            }
+            if !matches!(space.as_str(), "srgb" | "srgb-linear" | "display-p3" | "xyz" | "xyz-d50" | "xyz-d65") {
+                return Err(ParseColorError{ kind : ParseColorErrorKind::UnknownColorSpace, position : space_start });
+            }
+            space
+        } else {
+            String::new()
+        };
+        let legacy = matches!(name.as_str(), "rgb" | "rgba" | "hsl" | "hsla");
+        let args = self.arguments(legacy)?;
+        let [c0, c1, c2] = args.values;
+        if args.legacy {
+            check_legacy(&name, args.values)?;
+        }
+        let rgb : RGB<f32> = match name.as_str() {
+            "rgb" | "rgba" => RGB::new(channel(c0)?, channel(c1)?, channel(c2)?),
+            "hsl" | "hsla" => RGB::from(Hsl{ h : hue(c0)?, s : fraction(c1)?, l : fraction(c2)? }),
+            "hwb" => RGB::from(Hwb{ h : hue(c0)?, w : fraction(c1)?, b : fraction(c2)? }),
+            "lab" => RGB::from(Lab{ l : scaled(c0, 100.0)?.clamp(0.0, 100.0), a : scaled(c1, 125.0)?, b : scaled(c2, 125.0)? }),
+            "lch" => RGB::from(Lch{ l : scaled(c0, 100.0)?.clamp(0.0, 100.0), c : scaled(c1, 150.0)?.max(0.0), h : hue(c2)? }),
+            "oklab" => RGB::from(Oklab{ l : scaled(c0, 1.0)?.clamp(0.0, 1.0), a : scaled(c1, 0.4)?, b : scaled(c2, 0.4)? }),
+            "oklch" => RGB::from(Oklch{ l : scaled(c0, 1.0)?.clamp(0.0, 1.0), c : scaled(c1, 0.4)?.max(0.0), h : hue(c2)? }),
+            _ => {
+                let v = RGB::new(scaled(c0, 1.0)?, scaled(c1, 1.0)?, scaled(c2, 1.0)?);
+                match space.as_str() {
+                    "srgb-linear" => space::from_linear(v),
+                    "display-p3" => space::display_p3_to_srgb(v),
+                    "xyz" | "xyz-d65" => RGB::from(Xyz{ x : v.r(), y : v.g(), z : v.b() }),
+                    "xyz-d50" => RGB::from(Xyz::from_d50(v.r(), v.g(), v.b())),
+                    _ => v,
+                }
+            }
+        };
+        let alpha = match args.alpha {
+            Some(a) => scaled(a, 1.0)?,
+            None => 1.0,
+        };
+        Ok(RGBA::new(
+            rgb.r().clamp(0.0, 1.0),
+            rgb.g().clamp(0.0, 1.0),
+            rgb.b().clamp(0.0, 1.0),
+            alpha.clamp(0.0, 1.0),
+        ))
+    }
+}
+
+struct Arguments {
+    values : [(Value, usize); 3],
+    alpha : Option<(Value, usize)>,
+    /// Whether the components are separated by commas
+    legacy : bool,
+}
+
+/// The legacy syntax does not mix numbers and percentages in rgb(),
+/// and only takes percentages for the saturation and the lightness of hsl()
+fn check_legacy(name : &str, values : [(Value, usize); 3]) -> Result<(), ParseColorError> {
+    let percent = |v : &(Value, usize)| matches!(v.0, Value::Percent(_));
+    let wrong = if name.starts_with("rgb") {
+        values.iter().find(|v| percent(v) != percent(&values[0]))
+    } else {
+        values[1..].iter().find(|v| !percent(v))
+    };
+    match wrong {
+        Some(&(_, position)) => Err(invalid(position)),
+        None => Ok(()),
+    }
+}
+
+fn invalid(position : usize) -> ParseColorError {
+    ParseColorError{ kind : ParseColorErrorKind::InvalidValue, position }
+}
+
+/// A component where 100% means ```reference```
+fn scaled((value, position) : (Value, usize), reference : f32) -> Result<f32, ParseColorError> {
+    match value {
+        Value::Number(n) => Ok(n),
+        Value::Percent(p) => Ok(p / 100.0 * reference),
+        Value::None => Ok(0.0),
+        Value::Angle(_) => Err(invalid(position)),
+    }
+}
+
+/// A red, green or blue component of rgb() where a number is in 0..=255
+fn channel((value, position) : (Value, usize)) -> Result<f32, ParseColorError> {
+    match value {
+        Value::Number(n) => Ok(n / 255.0),
+        _ => scaled((value, position), 1.0),
+    }
+}
+
+/// A saturation, lightness, whiteness or blackness where a number is in 0..=100
+fn fraction((value, position) : (Value, usize)) -> Result<f32, ParseColorError> {
+    match value {
+        Value::Number(n) => Ok(n / 100.0),
+        _ => scaled((value, position), 1.0),
+    }
+}
+
+/// A hue in degrees
+fn hue((value, position) : (Value, usize)) -> Result<f32, ParseColorError> {
+    match value {
+        Value::Number(n) | Value::Angle(n) => Ok(n),
+        Value::None => Ok(0.0),
+        Value::Percent(_) => Err(invalid(position)),
+    }
+}
+
+/// Parse a CSS color into a straight alpha RGBA&lt;f32&gt;.
+/// ```
+/// # extern crate xblend;
+/// use xblend::css::{parse, ParseColorErrorKind};
+/// // the legacy comma syntax does not mix numbers and percentages
+/// let error = parse("rgb(255, 50%, 0)").unwrap_err();
+/// assert_eq!((error.kind(), error.position()), (ParseColorErrorKind::InvalidValue, 9));
+/// assert!(parse("rgb(100%, 50%, 0%)").is_ok());
+/// assert!(parse("rgba(255, 128, 0, 50%)").is_ok());
+/// assert!(parse("rgb(255 50% 0)").is_ok());
+/// // and the saturation and lightness of hsl() are percentages
+/// assert!(parse("hsl(120, 100, 50%)").is_err());
+/// assert!(parse("hsl(120deg, 100%, 50%)").is_ok());
+/// assert!(parse("hsl(120 100 50)").is_ok());
+/// // a number does not end with a dot
+/// let error = parse("rgb(1. 2 3)").unwrap_err();
+/// assert_eq!((error.kind(), error.position()), (ParseColorErrorKind::UnexpectedChar('.'), 5));
+/// assert!(parse("rgb(1., 2, 3)").is_err());
+/// assert!(parse("rgb(1.0, .5, 3)").is_ok());
+/// ```
+pub fn parse(s : &str) -> Result<RGBA<f32>, ParseColorError> {
+    Parser{ s, pos : 0 }.color()
+}
+
+impl FromStr for RGBA<f32> {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+impl FromStr for RGBA<u8> {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let color = parse(s)?;
+        let round = |c : f32| (c * 255.0).round() as u8;
+        Ok(RGBA::new(round(color.r()), round(color.g()), round(color.b()), round(color.a())))
+    }
+}
+
+/// The notation used to serialize a color.
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Notation {
+    /// ```#rgb```, ```#rgba```, ```#rrggbb```, ```#rrggbbaa``` or a shorter color name
+    #[default]
+    Hex,
+    /// ```rgb(r g b / a)``` with the components in 0..=255
+    Rgb,
+    /// ```hsl(h s% l% / a)```
+    Hsl,
+    /// ```oklch(l% c h / a)```
+    Oklch,
+}
+
+/// The minimum number of digits after the decimal point of the alpha,
+/// enough to tell apart the 256 values of a u8 alpha
+const ALPHA_PRECISION : usize = 3;
+
+/// How to serialize a color into a CSS string.<br>
+/// The output is as short as possible: trailing zeros and an opaque alpha are left out,
+/// and with the default precision it can be parsed back into the same RGBA&lt;u8&gt; color.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::css::{CssFormat, Notation};
+/// let color = rgba!(0,128,0,128);
+/// assert_eq!(CssFormat::new(Notation::Hex).format(color.to_f32()), "#00800080");
+/// assert_eq!(CssFormat::new(Notation::Rgb).format(color.to_f32()), "rgb(0 128 0 / 0.502)");
+/// assert_eq!(CssFormat::new(Notation::Hsl).precision(1).format(color.to_f32()), "hsl(120 100% 25.1% / 0.502)");
+/// for notation in [Notation::Hex, Notation::Rgb, Notation::Hsl, Notation::Oklch] {
+///     let css = color.to_css_string_with(CssFormat::new(notation));
+///     assert_eq!(css.parse::<RGBA<u8>>().unwrap(), color);
+/// }
+/// ```
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct CssFormat {
+    /// The notation
+    pub notation : Notation,
+    /// The maximum number of digits after the decimal point
+    pub precision : usize,
+}
+
+impl Default for CssFormat {
+    fn default() -> Self {
+        CssFormat{ notation : Notation::Hex, precision : 3 }
+    }
+}
+
+impl CssFormat {
+    /// Create a format with the default precision of 3 digits.
+    pub fn new(notation : Notation) -> CssFormat {
+        CssFormat{ notation, ..CssFormat::default() }
+    }
+
+    /// Set the maximum number of digits after the decimal point. <br>
+    /// The alpha keeps at least 3 digits, so that it is parsed back into the same u8 value.
+    /// A lower precision may change the other components of the Hsl and Oklch notations.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::css::{CssFormat, Notation};
+    /// let color = rgba!(10,20,30,128);
+    /// let format = CssFormat::new(Notation::Rgb).precision(0);
+    /// assert_eq!(color.to_css_string_with(format), "rgb(10 20 30 / 0.502)");
+    /// assert_eq!(color.to_css_string_with(format).parse::<RGBA<u8>>().unwrap(), color);
+    /// let format = CssFormat::new(Notation::Hsl).precision(0);
+    /// assert_eq!(color.to_css_string_with(format), "hsl(210 50% 8% / 0.502)");
+    /// // the lightness is rounded to 8%
+    /// assert_eq!(color.to_css_string_with(format).parse::<RGBA<u8>>().unwrap(), rgba!(10,20,31,128));
+    /// ```
+    pub fn precision(self, precision : usize) -> CssFormat {
+        CssFormat{ precision, ..self }
+    }
+
+    /// Serialize a straight alpha RGBA&lt;f32&gt;, the components are clamped to 0..=1.
+    pub fn format(&self, color : RGBA<f32>) -> String {
+        let color = RGBA::new(
+            color.r().clamp(0.0, 1.0),
+            color.g().clamp(0.0, 1.0),
+            color.b().clamp(0.0, 1.0),
+            color.a().clamp(0.0, 1.0),
+        );
+        let rgb = color.rgb();
+        let digits = |v : f32| number(v, self.precision);
+        let alpha = if color.a() < 1.0 {
+            format!(" / {}", number(color.a(), self.precision.max(ALPHA_PRECISION)))
+        } else {
+            String::new()
+        };
+        match self.notation {
+            Notation::Hex => hex(color),
+            Notation::Rgb => format!("rgb({} {} {}{})",
+                digits(rgb.r() * 255.0), digits(rgb.g() * 255.0), digits(rgb.b() * 255.0), alpha),
+            Notation::Hsl => {
+                let hsl = Hsl::from(rgb);
+                format!("hsl({} {}% {}%{})", digits(hsl.h), digits(hsl.s * 100.0), digits(hsl.l * 100.0), alpha)
+            }
+            Notation::Oklch => {
+                // the chroma is about 100 times smaller than the lightness in percent,
+                // round it before deciding whether the hue is meaningful
+                let oklch = Oklch::from(rgb);
+                let c = number(oklch.c, self.precision + 2);
+                let h = if c == "0" { "0".to_string() } else { digits(oklch.h) };
+                format!("oklch({}% {} {}{})", digits(oklch.l * 100.0), c, h, alpha)
+            }
+        }
+    }
+}
+
+/// Format a number with at most ```precision``` digits after the decimal point
+fn number(v : f32, precision : usize) -> String {
+    let mut s = format!("{:.*}", precision, v);
+    if s.contains('.') {
+        let len = s.trim_end_matches('0').trim_end_matches('.').len();
+        s.truncate(len);
+    }
+    if s == "-0" {
+        s.remove(0);
+    }
+    s
+}
+
+/// The shortest hex color or a color name when it is shorter
+fn hex(color : RGBA<f32>) -> String {
+    let round = |c : f32| (c * 255.0).round() as u8;
+    let bytes = [round(color.r()), round(color.g()), round(color.b()), round(color.a())];
+    let bytes = if bytes[3] == 255 { &bytes[..3] } else { &bytes[..] };
+    let short = bytes.iter().all(|b| b >> 4 == b & 0xF);
+    let mut s = String::from("#");
+    for b in bytes {
+        if short {
+            s.push_str(&format!("{:x}", b & 0xF));
+        } else {
+            s.push_str(&format!("{:02x}", b));
+        }
+    }
+    if bytes.len() == 3 {
+        let value = RGBA::new(bytes[0], bytes[1], bytes[2], 255);
+        let name = named::css::COLORS.iter()
+            .filter(|&&(n, v)| v == value && n.len() < s.len())
+            .min_by_key(|(n, _)| n.len());
+        if let Some((name, _)) = name {
+            return name.to_string();
+        }
+    }
+    s
+}
+
+impl RGBA<f32>{
+    /// Serialize a straight alpha color into the shortest hex color or color name.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// assert_eq!(rgba!(1.0,0.0,0.0,1.0).to_css_string(), "red");
+    /// assert_eq!(rgba!(0.2,0.4,0.6,0.0).to_css_string(), "#3690");
+    /// ```
+    pub fn to_css_string(&self) -> String {
+        CssFormat::default().format(*self)
+    }
+
+    /// Serialize a straight alpha color with a format.
+    pub fn to_css_string_with(&self, format : CssFormat) -> String {
+        format.format(*self)
+    }
+}
+
+impl RGBA<u8>{
+    /// Serialize a straight alpha color into the shortest hex color or color name.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// assert_eq!(rgba!(0x663399FF).to_css_string(), "#639");
+    /// assert_eq!(rgba!(0x12345678).to_css_string(), "#12345678");
+    /// ```
+    pub fn to_css_string(&self) -> String {
+        self.to_f32().to_css_string()
+    }
+
+    /// Serialize a straight alpha color with a format.
+    pub fn to_css_string_with(&self, format : CssFormat) -> String {
+        format.format(self.to_f32())
+    }
+}
+
+impl RGB<f32>{
+    /// Serialize the color into the shortest hex color or color name.
+    pub fn to_css_string(&self) -> String {
+        RGBA::from_rgb(self, 1.0).to_css_string()
+    }
+
+    /// Serialize the color with a format.
+    pub fn to_css_string_with(&self, format : CssFormat) -> String {
+        format.format(RGBA::from_rgb(self, 1.0))
+    }
+}
+
+impl RGB<u8>{
+    /// Serialize the color into the shortest hex color or color name.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// assert_eq!(rgb!(0xF5DEB3).to_css_string(), "wheat");
+    /// ```
+    pub fn to_css_string(&self) -> String {
+        self.to_f32().to_css_string()
+    }
+
+    /// Serialize the color with a format.
+    pub fn to_css_string_with(&self, format : CssFormat) -> String {
+        format.format(RGBA::from_rgb(&self.to_f32(), 1.0))
+    }
+}
+
+/// Display the color as [to_css_string](RGBA::to_css_string),
+/// or in the ```rgb()``` notation when a precision is given.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// assert_eq!(format!("{}", rgba!(255,0,0,128)), "#ff000080");
+/// assert_eq!(format!("{:.1}", rgba!(0.5,0.25,1.0,1.0)), "rgb(127.5 63.8 255)");
+/// ```
+impl fmt::Display for RGBA<f32>{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => f.write_str(&self.to_css_string_with(CssFormat::new(Notation::Rgb).precision(precision))),
+            None => f.write_str(&self.to_css_string()),
+        }
+    }
+}
+
+impl fmt::Display for RGBA<u8>{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f32(), f)
+    }
+}
+
+impl fmt::Display for RGB<f32>{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&RGBA::from_rgb(self, 1.0), f)
+    }
+}
+
+impl fmt::Display for RGB<u8>{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f32(), f)
+    }
+}
+
+/// Format as ```rrggbbaa```, or ```#rrggbbaa``` with the ```#``` flag.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// assert_eq!(format!("{:x}", rgba!(0x12AB56FF)), "12ab56ff");
+/// assert_eq!(format!("{:#X}", rgba!(0x12AB56FF)), "#12AB56FF");
+/// assert_eq!(format!("{:#x}", rgb!(0x12AB56)), "#12ab56");
+/// ```
+impl fmt::LowerHex for RGBA<u8>{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = if f.alternate() { "#" } else { "" };
+        write!(f, "{}{:08x}", prefix, self.as_u32())
+    }
+}
+
+impl fmt::UpperHex for RGBA<u8>{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = if f.alternate() { "#" } else { "" };
+        write!(f, "{}{:08X}", prefix, self.as_u32())
+    }
+}
+
+impl fmt::LowerHex for RGB<u8>{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = if f.alternate() { "#" } else { "" };
+        write!(f, "{}{:06x}", prefix, self.as_u32())
+    }
+}
+
+impl fmt::UpperHex for RGB<u8>{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = if f.alternate() { "#" } else { "" };
+        write!(f, "{}{:06X}", prefix, self.as_u32())
+    }
+}
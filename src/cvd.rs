@@ -0,0 +1,366 @@
+//! # Color vision deficiency
+//! Simulate how people with a color vision deficiency see colors. <br>
+//! The simulations work on linear sRGB. A severity of 1 is a dichromacy (protanopia, deuteranopia, tritanopia),
+//! and a lower severity an anomalous trichromacy. <br>
+//! [Method::Machado] uses the matrices published for the severities 0, 0.1, ..., 1,
+//! the other methods interpolate between the normal and the dichromatic vision, which is an approximation.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::cvd::{Deficiency, Method, Simulation};
+//! let protanopia = Simulation::new(Deficiency::Protan, Method::Machado, 1.0);
+//! // red looks like a dark olive
+//! assert_eq!(protanopia.apply(rgb!(255,0,0)), rgb!(109,95,0));
+//! // gray does not change
+//! assert_eq!(protanopia.apply(rgb!(128,128,128)), rgb!(128,128,128));
+//! ```
+//! A [Daltonizer] corrects colors so that the differences lost under a deficiency become visible again.
+use crate::RGB;
+use crate::image::{ImageViewMut, map_image_in_place};
+#[doc(no_inline)]
+pub use crate::image::RgbPixel;
+use crate::space::{from_linear, mul, to_linear, Matrix};
+
+/// A type of color vision deficiency
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Deficiency {
+    /// Missing or anomalous L cones, red-green
+    Protan,
+    /// Missing or anomalous M cones, red-green
+    Deutan,
+    /// Missing or anomalous S cones, blue-yellow
+    Tritan,
+}
+
+/// A simulation model
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Method {
+    /// H. Brettel, F. Viénot and J. D. Mollon, 1997, two half-planes in LMS
+    Brettel,
+    /// F. Viénot, H. Brettel and J. D. Mollon, 1999, a single plane in LMS.
+    /// It is not accurate for tritans.
+    Vienot,
+    /// G. M. Machado, M. M. Oliveira and L. A. F. Fernandes, 2009
+    #[default]
+    Machado,
+}
+
+// The matrices convert linear sRGB into the linear sRGB seen with the deficiency.
+
+const IDENTITY : Matrix = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// The protanomaly matrices of Machado et al. for the severities 0, 0.1, ..., 1
+const MACHADO_PROTAN : [Matrix; 11] = [
+    IDENTITY,
+    [[0.856_167, 0.182_038, -0.038_205], [0.029_342, 0.955_115, 0.015_544], [-0.002_880, -0.001_563, 1.004_443]],
+    [[0.734_766, 0.334_872, -0.069_637], [0.051_840, 0.919_198, 0.028_963], [-0.004_928, -0.004_209, 1.009_137]],
+    [[0.630_323, 0.465_641, -0.095_964], [0.069_181, 0.890_046, 0.040_773], [-0.006_308, -0.007_724, 1.014_032]],
+    [[0.539_009, 0.579_343, -0.118_352], [0.082_546, 0.866_121, 0.051_332], [-0.007_136, -0.011_959, 1.019_095]],
+    [[0.458_064, 0.679_578, -0.137_642], [0.092_785, 0.846_313, 0.060_902], [-0.007_494, -0.016_807, 1.024_301]],
+    [[0.385_450, 0.769_005, -0.154_455], [0.100_526, 0.829_802, 0.069_673], [-0.007_442, -0.022_190, 1.029_632]],
+    [[0.319_627, 0.849_633, -0.169_261], [0.106_241, 0.815_969, 0.077_790], [-0.007_025, -0.028_051, 1.035_076]],
+    [[0.259_411, 0.923_008, -0.182_420], [0.110_296, 0.804_340, 0.085_364], [-0.006_276, -0.034_346, 1.040_622]],
+    [[0.203_876, 0.990_338, -0.194_214], [0.112_975, 0.794_542, 0.092_483], [-0.005_222, -0.041_043, 1.046_265]],
+    [[0.152_286, 1.052_583, -0.204_868], [0.114_503, 0.786_281, 0.099_216], [-0.003_882, -0.048_116, 1.051_998]],
+];
+
+/// The deuteranomaly matrices of Machado et al. for the severities 0, 0.1, ..., 1
+const MACHADO_DEUTAN : [Matrix; 11] = [
+    IDENTITY,
+    [[0.866_435, 0.177_704, -0.044_139], [0.049_567, 0.939_063, 0.011_370], [-0.003_453, 0.007_233, 0.996_220]],
+    [[0.760_729, 0.319_078, -0.079_807], [0.090_568, 0.889_315, 0.020_117], [-0.006_027, 0.013_325, 0.992_702]],
+    [[0.675_425, 0.433_850, -0.109_275], [0.125_303, 0.847_755, 0.026_942], [-0.007_950, 0.018_572, 0.989_378]],
+    [[0.605_511, 0.528_560, -0.134_071], [0.155_318, 0.812_366, 0.032_316], [-0.009_376, 0.023_176, 0.986_200]],
+    [[0.547_494, 0.607_765, -0.155_259], [0.181_692, 0.781_742, 0.036_566], [-0.010_410, 0.027_275, 0.983_136]],
+    [[0.498_864, 0.674_741, -0.173_604], [0.205_199, 0.754_872, 0.039_929], [-0.011_131, 0.030_969, 0.980_162]],
+    [[0.457_771, 0.731_899, -0.189_670], [0.226_409, 0.731_012, 0.042_579], [-0.011_595, 0.034_333, 0.977_261]],
+    [[0.422_823, 0.781_057, -0.203_881], [0.245_752, 0.709_602, 0.044_646], [-0.011_843, 0.037_423, 0.974_421]],
+    [[0.392_952, 0.823_610, -0.216_562], [0.263_559, 0.690_210, 0.046_232], [-0.011_910, 0.040_281, 0.971_630]],
+    [[0.367_322, 0.860_646, -0.227_968], [0.280_085, 0.672_501, 0.047_413], [-0.011_820, 0.042_940, 0.968_881]],
+];
+
+/// The tritanomaly matrices of Machado et al. for the severities 0, 0.1, ..., 1
+const MACHADO_TRITAN : [Matrix; 11] = [
+    IDENTITY,
+    [[0.926_670, 0.092_514, -0.019_184], [0.021_191, 0.964_503, 0.014_306], [0.008_437, 0.054_813, 0.936_750]],
+    [[0.895_720, 0.133_330, -0.029_050], [0.029_997, 0.945_400, 0.024_603], [0.013_027, 0.104_707, 0.882_266]],
+    [[0.905_871, 0.127_791, -0.033_662], [0.026_856, 0.941_251, 0.031_893], [0.013_410, 0.148_296, 0.838_294]],
+    [[0.948_035, 0.089_490, -0.037_526], [0.014_364, 0.946_792, 0.038_844], [0.010_853, 0.193_991, 0.795_156]],
+    [[1.017_277, 0.027_029, -0.044_306], [-0.006_113, 0.958_479, 0.047_634], [0.006_379, 0.248_708, 0.744_913]],
+    [[1.104_996, -0.046_633, -0.058_363], [-0.032_137, 0.971_635, 0.060_503], [0.001_336, 0.317_922, 0.680_742]],
+    [[1.193_214, -0.109_812, -0.083_402], [-0.058_496, 0.979_410, 0.079_086], [-0.002_346, 0.403_492, 0.598_854]],
+    [[1.257_728, -0.139_648, -0.118_081], [-0.078_003, 0.975_409, 0.102_594], [-0.003_316, 0.501_214, 0.502_102]],
+    [[1.278_864, -0.125_333, -0.153_531], [-0.084_748, 0.957_674, 0.127_074], [-0.000_989, 0.601_151, 0.399_838]],
+    [[1.255_528, -0.076_749, -0.178_779], [-0.078_411, 0.930_809, 0.147_602], [0.004_733, 0.691_367, 0.303_900]],
+];
+
+const VIENOT_PROTAN : Matrix = [
+    [0.112_38, 0.887_62, 0.0],
+    [0.112_38, 0.887_62, 0.0],
+    [0.004_01, -0.004_01, 1.0],
+];
+
+const VIENOT_DEUTAN : Matrix = [
+    [0.292_75, 0.707_25, 0.0],
+    [0.292_75, 0.707_25, 0.0],
+    [-0.022_34, 0.022_34, 1.0],
+];
+
+const VIENOT_TRITAN : Matrix = [
+    [1.0, 0.144_61, -0.144_61],
+    [0.0, 0.859_24, 0.140_76],
+    [0.0, 0.859_24, 0.140_76],
+];
+
+/// The two half-plane projections of Brettel and the normal of the plane that separates them
+struct Brettel {
+    first : Matrix,
+    second : Matrix,
+    normal : [f32; 3],
+}
+
+const BRETTEL_PROTAN : Brettel = Brettel{
+    first : [[0.149_80, 1.195_48, -0.345_28], [0.107_64, 0.848_64, 0.043_72], [0.003_84, -0.005_40, 1.001_56]],
+    second : [[0.145_70, 1.161_72, -0.307_42], [0.108_16, 0.852_91, 0.038_92], [0.003_86, -0.005_24, 1.001_39]],
+    normal : [0.000_48, 0.003_93, -0.004_41],
+};
+
+const BRETTEL_DEUTAN : Brettel = Brettel{
+    first : [[0.364_77, 0.863_81, -0.228_58], [0.262_94, 0.642_45, 0.094_62], [-0.020_06, 0.027_28, 0.992_78]],
+    second : [[0.372_98, 0.881_66, -0.254_64], [0.259_54, 0.635_06, 0.105_40], [-0.019_80, 0.027_84, 0.991_96]],
+    normal : [-0.002_81, -0.006_11, 0.008_92],
+};
+
+const BRETTEL_TRITAN : Brettel = Brettel{
+    first : [[1.012_77, 0.135_48, -0.148_26], [-0.012_43, 0.868_12, 0.144_31], [0.075_89, 0.805_00, 0.119_11]],
+    second : [[0.936_78, 0.189_79, -0.126_57], [0.061_54, 0.815_26, 0.123_20], [-0.375_62, 1.127_67, 0.247_96]],
+    normal : [0.039_01, -0.027_88, -0.011_13],
+};
+
+/// Transform a pixel in linear sRGB and clamp the result
+fn in_linear<P : RgbPixel, F : Fn(RGB<f32>) -> RGB<f32>>(color : P, f : F) -> P {
+    color.map_rgb(|c| {
+        let d = from_linear(f(to_linear(c)));
+        RGB::new(d.r().clamp(0.0, 1.0), d.g().clamp(0.0, 1.0), d.b().clamp(0.0, 1.0))
+    })
+}
+
+/// Interpolate between two matrices
+fn lerp(a : &Matrix, b : &Matrix, t : f32) -> Matrix {
+    std::array::from_fn(|i| std::array::from_fn(|j| a[i][j] + (b[i][j] - a[i][j]) * t))
+}
+
+/// A color vision deficiency simulation
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub struct Simulation {
+    deficiency : Deficiency,
+    /// The matrix on the positive side of the plane, at the severity
+    first : Matrix,
+    /// The matrix on the negative side of the plane, the same as first except for Brettel
+    second : Matrix,
+    normal : [f32; 3],
+    severity : f32,
+}
+
+impl Simulation {
+    /// Create a simulation, the severity is clamped into 0..=1. <br>
+    /// Machado interpolates between the two nearest of its matrices for the severities 0, 0.1, ..., 1.
+    /// Brettel and Viénot only model dichromacy, so below 1 their result is
+    /// ```color + (dichromat(color) - color) * severity``` in linear sRGB, an approximation of an anomalous trichromacy.
+    pub fn new(deficiency : Deficiency, method : Method, severity : f32) -> Simulation {
+        let severity = severity.clamp(0.0, 1.0);
+        let machado = |table : &[Matrix; 11]| {
+            let x = severity * 10.0;
+            let i = (x.floor() as usize).min(9);
+            let m = lerp(&table[i], &table[i + 1], x - i as f32);
+            (m, m, [0.0; 3])
+        };
+        let single = |m : Matrix| {
+            let m = lerp(&IDENTITY, &m, severity);
+            (m, m, [0.0; 3])
+        };
+        let brettel = |b : Brettel| (lerp(&IDENTITY, &b.first, severity), lerp(&IDENTITY, &b.second, severity), b.normal);
+        let (first, second, normal) = match (method, deficiency) {
+            (Method::Machado, Deficiency::Protan) => machado(&MACHADO_PROTAN),
+            (Method::Machado, Deficiency::Deutan) => machado(&MACHADO_DEUTAN),
+            (Method::Machado, Deficiency::Tritan) => machado(&MACHADO_TRITAN),
+            (Method::Vienot, Deficiency::Protan) => single(VIENOT_PROTAN),
+            (Method::Vienot, Deficiency::Deutan) => single(VIENOT_DEUTAN),
+            (Method::Vienot, Deficiency::Tritan) => single(VIENOT_TRITAN),
+            (Method::Brettel, Deficiency::Protan) => brettel(BRETTEL_PROTAN),
+            (Method::Brettel, Deficiency::Deutan) => brettel(BRETTEL_DEUTAN),
+            (Method::Brettel, Deficiency::Tritan) => brettel(BRETTEL_TRITAN),
+        };
+        Simulation{ deficiency, first, second, normal, severity }
+    }
+
+    /// Get the deficiency.
+    pub fn deficiency(&self) -> Deficiency {
+        self.deficiency
+    }
+
+    /// Get the severity.
+    pub fn severity(&self) -> f32 {
+        self.severity
+    }
+
+    /// Simulate a linear sRGB color, the result is not clamped.
+    pub fn linear(&self, color : RGB<f32>) -> RGB<f32> {
+        let v = [color.r(), color.g(), color.b()];
+        let side = self.normal[0] * v[0] + self.normal[1] * v[1] + self.normal[2] * v[2];
+        let m = if side >= 0.0 { &self.first } else { &self.second };
+        let d = mul(m, v);
+        RGB::new(d[0], d[1], d[2])
+    }
+
+    /// Simulate a color, the alpha is kept.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::cvd::{Deficiency, Method, Simulation};
+    /// let color = rgba!(40,180,90,200);
+    /// for method in [Method::Brettel, Method::Vienot, Method::Machado] {
+    ///     // a severity of 0 is the normal vision
+    ///     assert_eq!(Simulation::new(Deficiency::Deutan, method, 0.0).apply(color), color);
+    ///     let simulated = Simulation::new(Deficiency::Deutan, method, 1.0).apply(color);
+    ///     assert_eq!(simulated.a(), 200);
+    /// }
+    /// // reference values of the full deficiencies
+    /// let red = rgb!(1.0,0.0,0.0);
+    /// let close = |a : RGB<f32>, b : RGB<f32>| (a.r() - b.r()).abs() < 1e-4 && (a.g() - b.g()).abs() < 1e-4 && (a.b() - b.b()).abs() < 1e-4;
+    /// let simulate = |d, m| Simulation::new(d, m, 1.0).linear(red);
+    /// assert!(close(simulate(Deficiency::Deutan, Method::Machado), rgb!(0.367322,0.280085,-0.011820)));
+    /// assert!(close(simulate(Deficiency::Protan, Method::Vienot), rgb!(0.11238,0.11238,0.00401)));
+    /// assert!(close(simulate(Deficiency::Tritan, Method::Brettel), rgb!(1.01277,-0.01243,0.07589)));
+    /// // blue is on the other side of the plane
+    /// let blue = Simulation::new(Deficiency::Tritan, Method::Brettel, 1.0).linear(rgb!(0.0,0.0,1.0));
+    /// assert!(close(blue, rgb!(-0.12657,0.12320,0.24796)));
+    /// // the published matrices of Machado for the anomalous trichromacies, and between two of them
+    /// let anomalous = |d, s| Simulation::new(d, Method::Machado, s).linear(red);
+    /// assert!(close(anomalous(Deficiency::Protan, 0.3), rgb!(0.630323,0.069181,-0.006308)));
+    /// assert!(close(anomalous(Deficiency::Deutan, 0.7), rgb!(0.457771,0.226409,-0.011595)));
+    /// assert!(close(anomalous(Deficiency::Tritan, 0.35), rgb!(0.926953,0.020610,0.012132)));
+    /// // the published matrices of Machado and libDaltonLens applied in double precision
+    /// let colors = [rgb!(255,0,0), rgb!(0,255,0), rgb!(0,0,255), rgb!(255,128,0), rgb!(40,180,90)];
+    /// let expected = [
+    ///     (Deficiency::Protan, Method::Machado, [(109,95,0), (255,229,0), (0,89,255), (166,145,0), (181,164,83)]),
+    ///     (Deficiency::Deutan, Method::Machado, [(163,144,0), (239,214,58), (0,61,251), (196,174,0), (165,153,97)]),
+    ///     (Deficiency::Tritan, Method::Machado, [(255,0,15), (0,247,217), (0,107,150), (255,98,109), (0,177,159)]),
+    ///     (Deficiency::Protan, Method::Vienot, [(94,94,13), (242,242,0), (0,0,255), (150,150,10), (171,171,89)]),
+    ///     (Deficiency::Deutan, Method::Vienot, [(147,147,0), (219,219,41), (0,0,255), (178,178,0), (155,155,94)]),
+    ///     (Deficiency::Protan, Method::Brettel, [(108,92,12), (255,237,0), (0,56,255), (171,147,9), (190,168,89)]),
+    ///     (Deficiency::Tritan, Method::Brettel, [(255,0,78), (121,233,255), (0,98,136), (255,116,137), (86,167,193)]),
+    ///     (Deficiency::Tritan, Method::Vienot, [(255,0,0), (106,239,239), (0,105,105), (255,119,119), (76,171,171)]),
+    ///     (Deficiency::Deutan, Method::Brettel, [(164,139,0), (241,209,46), (0,87,254), (198,169,0), (167,150,95)]),
+    /// ];
+    /// for &(deficiency, method, outputs) in &expected {
+    ///     let simulation = Simulation::new(deficiency, method, 1.0);
+    ///     for (&color, &(r, g, b)) in colors.iter().zip(outputs.iter()) {
+    ///         assert_eq!(simulation.apply(color), rgb!(r,g,b));
+    ///     }
+    /// }
+    /// let anomalous = [
+    ///     (Deficiency::Deutan, 0.3, [(214,99,0), (176,237,37), (0,46,254), (227,151,0), (124,168,93)]),
+    ///     (Deficiency::Protan, 0.7, [(153,92,0), (237,233,0), (0,79,255), (188,145,0), (165,166,86)]),
+    /// ];
+    /// for &(deficiency, severity, outputs) in &anomalous {
+    ///     let simulation = Simulation::new(deficiency, Method::Machado, severity);
+    ///     for (&color, &(r, g, b)) in colors.iter().zip(outputs.iter()) {
+    ///         assert_eq!(simulation.apply(color), rgb!(r,g,b));
+    ///     }
+    /// }
+    /// ```
+    pub fn apply<P : RgbPixel>(&self, color : P) -> P {
+        in_linear(color, |c| self.linear(c))
+    }
+
+    /// Simulate every pixel of an image.
+    pub fn apply_image<P : RgbPixel>(&self, image : &mut ImageViewMut<'_, P>) {
+        map_image_in_place(image, |p| self.apply(p));
+    }
+}
+
+/// Move the error of a red-green simulation into green and blue
+const SHIFT_RED_GREEN : Matrix = [
+    [0.0, 0.0, 0.0],
+    [0.7, 1.0, 0.0],
+    [0.7, 0.0, 1.0],
+];
+
+/// Move the error of a blue-yellow simulation into red and green
+const SHIFT_BLUE_YELLOW : Matrix = [
+    [1.0, 0.0, 0.7],
+    [0.0, 1.0, 0.7],
+    [0.0, 0.0, 0.0],
+];
+
+/// Daltonization of O. Fidaner, P. Lin and N. Ozguven, 2005 <br>
+/// The difference between a color and its simulation is the information lost by the viewer,
+/// it is moved into the channels the viewer perceives and added to the color, in linear sRGB.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::cvd::{Daltonizer, Deficiency, Method, Simulation};
+/// use xblend::difference::delta_e_ok;
+/// let simulation = Simulation::new(Deficiency::Deutan, Method::Machado, 1.0);
+/// let daltonizer = Daltonizer::new(simulation, 1.0);
+/// let (red, green) = (rgba!(200,60,40,255), rgba!(90,150,40,255));
+/// let seen = |c : RGBA<u8>| simulation.apply(c).rgb();
+/// // the viewer tells the corrected colors apart better
+/// let before = delta_e_ok(seen(red), seen(green));
+/// let after = delta_e_ok(seen(daltonizer.apply(red)), seen(daltonizer.apply(green)));
+/// assert!(after > before);
+/// // colors without lost information do not change
+/// assert_eq!(daltonizer.apply(rgba!(128,128,128,64)), rgba!(128,128,128,64));
+/// // a strength of 0 changes nothing
+/// assert_eq!(Daltonizer::new(simulation, 0.0).apply(red), red);
+/// ```
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub struct Daltonizer {
+    simulation : Simulation,
+    shift : Matrix,
+    strength : f32,
+}
+
+impl Daltonizer {
+    /// Create a daltonizer for a simulated deficiency, a strength of 1 adds the whole shifted error.
+    pub fn new(simulation : Simulation, strength : f32) -> Daltonizer {
+        let shift = match simulation.deficiency() {
+            Deficiency::Protan | Deficiency::Deutan => SHIFT_RED_GREEN,
+            Deficiency::Tritan => SHIFT_BLUE_YELLOW,
+        };
+        Daltonizer{ simulation, shift, strength }
+    }
+
+    /// Get the simulation.
+    pub fn simulation(&self) -> Simulation {
+        self.simulation
+    }
+
+    /// Get the strength.
+    pub fn strength(&self) -> f32 {
+        self.strength
+    }
+
+    /// Correct a linear sRGB color, the result is not clamped.
+    pub fn linear(&self, color : RGB<f32>) -> RGB<f32> {
+        let simulated = self.simulation.linear(color);
+        let error = [color.r() - simulated.r(), color.g() - simulated.g(), color.b() - simulated.b()];
+        let shift = mul(&self.shift, error);
+        RGB::new(
+            color.r() + shift[0] * self.strength,
+            color.g() + shift[1] * self.strength,
+            color.b() + shift[2] * self.strength,
+        )
+    }
+
+    /// Correct a color, the alpha is kept.
+    pub fn apply<P : RgbPixel>(&self, color : P) -> P {
+        in_linear(color, |c| self.linear(c))
+    }
+
+    /// Correct every pixel of an image.
+    pub fn apply_image<P : RgbPixel>(&self, image : &mut ImageViewMut<'_, P>) {
+        map_image_in_place(image, |p| self.apply(p));
+    }
+}
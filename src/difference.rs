@@ -0,0 +1,233 @@
+//! # Color difference
+//! Perceptual distances between two colors. <br>
+//! Every function takes any color that converts into the space of the formula,
+//! e.g. RGB&lt;u8&gt;, RGB&lt;f32&gt; or the space itself. <br>
+//! The CIE formulas work in [Lab], and the first color is the reference for the asymmetric ones.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::difference::*;
+//! let red = rgb!(1.0,0.0,0.0);
+//! assert!(delta_e_2000(red, rgb!(0.99,0.0,0.0)) < 1.0);
+//! assert!(delta_e_ok(red, rgb!(0.0,0.0,1.0)) > 0.5);
+//! let (a, b) = (rgb!(200,100,50), rgb!(190,110,60));
+//! assert!(delta_e_94(a, b, Application::GraphicArts) < delta_e_76(a, b));
+//! assert!(delta_e_cmc(a, b, 2.0, 1.0) < delta_e_cmc(a, b, 1.0, 1.0));
+//! ```
+use crate::space::{Lab, Oklab};
+
+/// The CIE76 color difference, the Euclidean distance in Lab.
+pub fn delta_e_76<C : Into<Lab>>(reference : C, sample : C) -> f32 {
+    let (a, b) = (reference.into(), sample.into());
+    ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+}
+
+/// The weights of CIE94
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Application {
+    /// ```kL = 1, K1 = 0.045, K2 = 0.015```
+    #[default]
+    GraphicArts,
+    /// ```kL = 2, K1 = 0.048, K2 = 0.014```
+    Textiles,
+}
+
+/// Split the difference of two Lab colors into lightness, chroma and hue differences,
+/// and return them with the chroma and hue angle of the reference
+fn lch_differences(reference : Lab, sample : Lab) -> ([f32; 3], f32, f32) {
+    let c1 = reference.a.hypot(reference.b);
+    let c2 = sample.a.hypot(sample.b);
+    let dl = reference.l - sample.l;
+    let dc = c1 - c2;
+    let da = reference.a - sample.a;
+    let db = reference.b - sample.b;
+    // rounding can make the square of the hue difference slightly negative
+    let dh = (da * da + db * db - dc * dc).max(0.0).sqrt();
+    let h1 = reference.b.atan2(reference.a).to_degrees().rem_euclid(360.0);
+    ([dl, dc, dh], c1, h1)
+}
+
+/// The CIE94 color difference.
+/// ```
+/// # extern crate xblend;
+/// use xblend::difference::{delta_e_94, Application};
+/// use xblend::space::Lab;
+/// // the test data of colormath and Colour
+/// let data : [[f32; 8]; 4] = [
+///     [0.9, 16.3, -2.22, 0.7, 14.2, -1.80, 1.249, 1.204],
+///     [100.0, 21.5721036, 272.2281935, 100.0, 426.6794535, 72.3959084, 83.7792, 88.3356],
+///     [100.0, 21.5721036, 272.2281935, 100.0, 74.0521698, 276.4531819, 10.0539, 10.6127],
+///     [100.0, 21.5721036, 272.2281935, 100.0, 8.3228196, -73.5829772, 57.5355, 60.3687],
+/// ];
+/// for [l1, a1, b1, l2, a2, b2, graphic_arts, textiles] in data {
+///     let (reference, sample) = (Lab{ l : l1, a : a1, b : b1 }, Lab{ l : l2, a : a2, b : b2 });
+///     assert!((delta_e_94(reference, sample, Application::GraphicArts) - graphic_arts).abs() < 1e-3);
+///     assert!((delta_e_94(reference, sample, Application::Textiles) - textiles).abs() < 1e-3);
+/// }
+/// ```
+pub fn delta_e_94<C : Into<Lab>>(reference : C, sample : C, application : Application) -> f32 {
+    let ([dl, dc, dh], c1, _) = lch_differences(reference.into(), sample.into());
+    let (kl, k1, k2) = match application {
+        Application::GraphicArts => (1.0, 0.045, 0.015),
+        Application::Textiles => (2.0, 0.048, 0.014),
+    };
+    let sc = 1.0 + k1 * c1;
+    let sh = 1.0 + k2 * c1;
+    ((dl / kl).powi(2) + (dc / sc).powi(2) + (dh / sh).powi(2)).sqrt()
+}
+
+/// The CMC l:c color difference, usually with ```l:c = 2:1``` for acceptability
+/// and ```1:1``` for perceptibility.
+/// ```
+/// # extern crate xblend;
+/// use xblend::difference::delta_e_cmc;
+/// use xblend::space::Lab;
+/// // the test data of colormath and Colour
+/// let data : [[f32; 8]; 4] = [
+///     [0.9, 16.3, -2.22, 0.7, 14.2, -1.80, 1.443, 1.482],
+///     [100.0, 21.5721036, 272.2281935, 100.0, 426.6794535, 72.3959084, 172.7048, 172.7048],
+///     [100.0, 21.5721036, 272.2281935, 100.0, 74.0521698, 276.4531819, 20.5973, 20.5973],
+///     [100.0, 21.5721036, 272.2281935, 100.0, 8.3228196, -73.5829772, 121.7184, 121.7184],
+/// ];
+/// for [l1, a1, b1, l2, a2, b2, acceptability, perceptibility] in data {
+///     let (reference, sample) = (Lab{ l : l1, a : a1, b : b1 }, Lab{ l : l2, a : a2, b : b2 });
+///     assert!((delta_e_cmc(reference, sample, 2.0, 1.0) - acceptability).abs() < 1e-3);
+///     assert!((delta_e_cmc(reference, sample, 1.0, 1.0) - perceptibility).abs() < 1e-3);
+/// }
+/// ```
+pub fn delta_e_cmc<C : Into<Lab>>(reference : C, sample : C, l : f32, c : f32) -> f32 {
+    let reference = reference.into();
+    let ([dl, dc, dh], c1, h1) = lch_differences(reference, sample.into());
+    let l1 = reference.l;
+    let sl = if l1 < 16.0 { 0.511 } else { 0.040_975 * l1 / (1.0 + 0.017_65 * l1) };
+    let sc = 0.0638 * c1 / (1.0 + 0.0131 * c1) + 0.638;
+    let c1_4 = c1.powi(4);
+    let f = (c1_4 / (c1_4 + 1900.0)).sqrt();
+    let t = if (164.0..=345.0).contains(&h1) {
+        0.56 + (0.2 * (h1 + 168.0).to_radians().cos()).abs()
+    } else {
+        0.36 + (0.4 * (h1 + 35.0).to_radians().cos()).abs()
+    };
+    let sh = sc * (f * t + 1.0 - f);
+    ((dl / (l * sl)).powi(2) + (dc / (c * sc)).powi(2) + (dh / sh).powi(2)).sqrt()
+}
+
+/// The CIEDE2000 color difference with ```kL = kC = kH = 1```.
+/// A difference below 1 is hardly noticeable.<br>
+/// It gives the results of the test data of G. Sharma, W. Wu and E. N. Dalal,
+/// *The CIEDE2000 Color-Difference Formula: Implementation Notes, Supplementary Test Data,
+/// and Mathematical Observations*, 2005.
+/// ```
+/// # extern crate xblend;
+/// use xblend::difference::delta_e_2000;
+/// use xblend::space::Lab;
+/// let data : [[f32; 7]; 34] = [
+///     [50.0000, 2.6772, -79.7751, 50.0000, 0.0000, -82.7485, 2.0425],
+///     [50.0000, 3.1571, -77.2803, 50.0000, 0.0000, -82.7485, 2.8615],
+///     [50.0000, 2.8361, -74.0200, 50.0000, 0.0000, -82.7485, 3.4412],
+///     [50.0000, -1.3802, -84.2814, 50.0000, 0.0000, -82.7485, 1.0000],
+///     [50.0000, -1.1848, -84.8006, 50.0000, 0.0000, -82.7485, 1.0000],
+///     [50.0000, -0.9009, -85.5211, 50.0000, 0.0000, -82.7485, 1.0000],
+///     [50.0000, 0.0000, 0.0000, 50.0000, -1.0000, 2.0000, 2.3669],
+///     [50.0000, -1.0000, 2.0000, 50.0000, 0.0000, 0.0000, 2.3669],
+///     [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0009, 7.1792],
+///     [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0010, 7.1792],
+///     [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0011, 7.2195],
+///     [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0012, 7.2195],
+///     [50.0000, -0.0010, 2.4900, 50.0000, 0.0009, -2.4900, 4.8045],
+///     [50.0000, -0.0010, 2.4900, 50.0000, 0.0010, -2.4900, 4.8045],
+///     [50.0000, -0.0010, 2.4900, 50.0000, 0.0011, -2.4900, 4.7461],
+///     [50.0000, 2.5000, 0.0000, 50.0000, 0.0000, -2.5000, 4.3065],
+///     [50.0000, 2.5000, 0.0000, 73.0000, 25.0000, -18.0000, 27.1492],
+///     [50.0000, 2.5000, 0.0000, 61.0000, -5.0000, 29.0000, 22.8977],
+///     [50.0000, 2.5000, 0.0000, 56.0000, -27.0000, -3.0000, 31.9030],
+///     [50.0000, 2.5000, 0.0000, 58.0000, 24.0000, 15.0000, 19.4535],
+///     [50.0000, 2.5000, 0.0000, 50.0000, 3.1736, 0.5854, 1.0000],
+///     [50.0000, 2.5000, 0.0000, 50.0000, 3.2972, 0.0000, 1.0000],
+///     [50.0000, 2.5000, 0.0000, 50.0000, 1.8634, 0.5757, 1.0000],
+///     [50.0000, 2.5000, 0.0000, 50.0000, 3.2592, 0.3350, 1.0000],
+///     [60.2574, -34.0099, 36.2677, 60.4626, -34.1751, 39.4387, 1.2644],
+///     [63.0109, -31.0961, -5.8663, 62.8187, -29.7946, -4.0864, 1.2630],
+///     [61.2901, 3.7196, -5.3901, 61.4292, 2.2480, -4.9620, 1.8731],
+///     [35.0831, -44.1164, 3.7933, 35.0232, -40.0716, 1.5901, 1.8645],
+///     [22.7233, 20.0904, -46.6940, 23.0331, 14.9730, -42.5619, 2.0373],
+///     [36.4612, 47.8580, 18.3852, 36.2715, 50.5065, 21.2231, 1.4146],
+///     [90.8027, -2.0831, 1.4410, 91.1528, -1.6435, 0.0447, 1.4441],
+///     [90.9257, -0.5406, -0.9208, 88.6381, -0.8985, -0.7239, 1.5381],
+///     [6.7747, -0.2908, -2.4247, 5.8714, -0.0985, -2.2286, 0.6377],
+///     [2.0776, 0.0795, -1.1350, 0.9033, -0.0636, -0.5514, 0.9082],
+/// ];
+/// for [l1, a1, b1, l2, a2, b2, expected] in data {
+///     let (lab1, lab2) = (Lab{ l : l1, a : a1, b : b1 }, Lab{ l : l2, a : a2, b : b2 });
+///     assert!((delta_e_2000(lab1, lab2) - expected).abs() < 1e-4);
+///     // the formula is symmetric
+///     assert!((delta_e_2000(lab2, lab1) - expected).abs() < 1e-4);
+/// }
+/// ```
+pub fn delta_e_2000<C : Into<Lab>>(reference : C, sample : C) -> f32 {
+    let (lab1, lab2) = (reference.into(), sample.into());
+    // computed in f64, the formula has many cancellations
+    let (l1, a1, b1) = (lab1.l as f64, lab1.a as f64, lab1.b as f64);
+    let (l2, a2, b2) = (lab2.l as f64, lab2.a as f64, lab2.b as f64);
+    let pow25_7 = 25f64.powi(7);
+
+    let c_mean = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+    let g = 0.5 * (1.0 - (c_mean.powi(7) / (c_mean.powi(7) + pow25_7)).sqrt());
+    let (a1, a2) = (a1 * (1.0 + g), a2 * (1.0 + g));
+    let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+    let hue = |b : f64, a : f64| {
+        if a == 0.0 && b == 0.0 { 0.0 } else { b.atan2(a).to_degrees().rem_euclid(360.0) }
+    };
+    let (h1, h2) = (hue(b1, a1), hue(b2, a2));
+
+    let dl = l2 - l1;
+    let dc = c2 - c1;
+    let dh = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else {
+        h2 - h1 + 360.0
+    };
+    let dh = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+    let l_mean = (l1 + l2) / 2.0;
+    let c_mean = (c1 + c2) / 2.0;
+    let h_mean = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let cos = |deg : f64| deg.to_radians().cos();
+    let t = 1.0 - 0.17 * cos(h_mean - 30.0) + 0.24 * cos(2.0 * h_mean)
+        + 0.32 * cos(3.0 * h_mean + 6.0) - 0.20 * cos(4.0 * h_mean - 63.0);
+    let d_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+    let rc = 2.0 * (c_mean.powi(7) / (c_mean.powi(7) + pow25_7)).sqrt();
+    let sl = lightness_weight_2000(l_mean);
+    let sc = 1.0 + 0.045 * c_mean;
+    let sh = 1.0 + 0.015 * c_mean * t;
+    let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+
+    let (l, c, h) = (dl / sl, dc / sc, dh / sh);
+    (l * l + c * c + h * h + rt * c * h).sqrt() as f32
+}
+
+/// The weight SL of the lightness difference in CIEDE2000, from the mean lightness.
+/// It grows with the distance of the mean lightness from 50.
+pub(crate) fn lightness_weight_2000(l_mean : f64) -> f64 {
+    1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt()
+}
+
+/// The Euclidean distance in Oklab.
+/// A difference below 0.02 is hardly noticeable.
+pub fn delta_e_ok<C : Into<Oklab>>(reference : C, sample : C) -> f32 {
+    let (a, b) = (reference.into(), sample.into());
+    ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+}
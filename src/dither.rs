@@ -0,0 +1,501 @@
+//! # Dithering
+//! Reduce the colors of an image to a bit depth or to a [Palette] without banding. <br>
+//! Error diffusion spreads the quantization error of every pixel over its unprocessed neighbours,
+//! ordered dithering compares every pixel with a threshold map.
+//! The error is measured in an [ErrorSpace], linear light by default, so that the average light is kept.
+//! The alpha is not dithered.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::dither::{Algorithm, Dither, Target};
+//! use xblend::image::ImageViewMut;
+//! // a flat gray between two levels of 1 bit
+//! let mut pixels = vec![rgb!(0.5,0.5,0.5); 64 * 64];
+//! Dither::new(Algorithm::FloydSteinberg)
+//!     .apply(&mut ImageViewMut::from_slice(&mut pixels, 64, 64).unwrap(), Target::Depth(1, 1, 1));
+//! assert!(pixels.iter().all(|p| p.r() == 0.0 || p.r() == 1.0));
+//! // the linear light of the gray is about 21%
+//! let white = pixels.iter().filter(|p| p.r() == 1.0).count() as f32 / pixels.len() as f32;
+//! assert!((white - 0.214).abs() < 0.01);
+//! ```
+use std::sync::OnceLock;
+use crate::RGB;
+use crate::image::{ImageView, ImageViewMut, RgbPixel};
+use crate::palette::Palette;
+use crate::quantize::SplitMix64;
+use crate::space::{linear_to_srgb, srgb_to_linear};
+
+/// A dithering algorithm
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::dither::{Algorithm, Dither, Target};
+/// use xblend::image::ImageViewMut;
+/// let algorithms = [
+///     Algorithm::FloydSteinberg, Algorithm::Atkinson, Algorithm::JarvisJudiceNinke,
+///     Algorithm::Sierra, Algorithm::TwoRowSierra, Algorithm::SierraLite,
+/// ];
+/// for algorithm in algorithms {
+///     let mut pixels = vec![rgb!(0.5,0.5,0.5); 64 * 64];
+///     Dither::new(algorithm)
+///         .apply(&mut ImageViewMut::from_slice(&mut pixels, 64, 64).unwrap(), Target::Depth(1, 1, 1));
+///     assert!(pixels.iter().all(|p| p.r() == 0.0 || p.r() == 1.0));
+///     // the linear light of the gray is about 21%, Atkinson loses a quarter of the error
+///     let white = pixels.iter().filter(|p| p.r() == 1.0).count() as f32 / pixels.len() as f32;
+///     let tolerance = if algorithm == Algorithm::Atkinson { 0.03 } else { 0.005 };
+///     assert!((white - 0.214).abs() < tolerance);
+/// }
+/// ```
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Algorithm {
+    /// Error diffusion to 4 neighbours
+    #[default]
+    FloydSteinberg,
+    /// Error diffusion of 3/4 of the error to 6 neighbours, with more contrast
+    Atkinson,
+    /// Error diffusion to 12 neighbours over 3 rows
+    JarvisJudiceNinke,
+    /// Error diffusion to 10 neighbours over 3 rows
+    Sierra,
+    /// Error diffusion to 7 neighbours over 2 rows
+    TwoRowSierra,
+    /// Error diffusion to 3 neighbours
+    SierraLite,
+    /// A Bayer threshold map of ```2^n x 2^n``` pixels, n is clamped to 1..=6
+    Bayer(u32),
+    /// A 64x64 blue noise threshold map made with the void-and-cluster method
+    BlueNoise,
+}
+
+/// A weight of an error diffusion kernel at (dx, dy)
+type Weight = (isize, usize, f32);
+
+impl Algorithm {
+    /// The error diffusion kernel as (dx, dy, weight) and the divisor
+    fn kernel(self) -> Option<(&'static [Weight], f32)> {
+        const FLOYD_STEINBERG : [Weight; 4] = [(1, 0, 7.0), (-1, 1, 3.0), (0, 1, 5.0), (1, 1, 1.0)];
+        const ATKINSON : [Weight; 6] = [(1, 0, 1.0), (2, 0, 1.0), (-1, 1, 1.0), (0, 1, 1.0), (1, 1, 1.0), (0, 2, 1.0)];
+        const JARVIS_JUDICE_NINKE : [Weight; 12] = [
+            (1, 0, 7.0), (2, 0, 5.0),
+            (-2, 1, 3.0), (-1, 1, 5.0), (0, 1, 7.0), (1, 1, 5.0), (2, 1, 3.0),
+            (-2, 2, 1.0), (-1, 2, 3.0), (0, 2, 5.0), (1, 2, 3.0), (2, 2, 1.0),
+        ];
+        const SIERRA : [Weight; 10] = [
+            (1, 0, 5.0), (2, 0, 3.0),
+            (-2, 1, 2.0), (-1, 1, 4.0), (0, 1, 5.0), (1, 1, 4.0), (2, 1, 2.0),
+            (-1, 2, 2.0), (0, 2, 3.0), (1, 2, 2.0),
+        ];
+        const TWO_ROW_SIERRA : [Weight; 7] = [
+            (1, 0, 4.0), (2, 0, 3.0),
+            (-2, 1, 1.0), (-1, 1, 2.0), (0, 1, 3.0), (1, 1, 2.0), (2, 1, 1.0),
+        ];
+        const SIERRA_LITE : [Weight; 3] = [(1, 0, 2.0), (-1, 1, 1.0), (0, 1, 1.0)];
+        match self {
+            Algorithm::FloydSteinberg => Some((&FLOYD_STEINBERG, 16.0)),
+            Algorithm::Atkinson => Some((&ATKINSON, 8.0)),
+            Algorithm::JarvisJudiceNinke => Some((&JARVIS_JUDICE_NINKE, 48.0)),
+            Algorithm::Sierra => Some((&SIERRA, 32.0)),
+            Algorithm::TwoRowSierra => Some((&TWO_ROW_SIERRA, 16.0)),
+            Algorithm::SierraLite => Some((&SIERRA_LITE, 4.0)),
+            Algorithm::Bayer(_) | Algorithm::BlueNoise => None,
+        }
+    }
+
+    /// The threshold of an ordered dithering in 0..1
+    fn threshold(self, x : usize, y : usize) -> f32 {
+        match self {
+            Algorithm::Bayer(n) => {
+                let n = n.clamp(1, 6);
+                (bayer(n, x, y) as f32 + 0.5) / (1u32 << (2 * n)) as f32
+            }
+            _ => {
+                let map = blue_noise();
+                (map[(y % BLUE_NOISE_SIZE) * BLUE_NOISE_SIZE + x % BLUE_NOISE_SIZE] as f32 + 0.5)
+                    / (BLUE_NOISE_SIZE * BLUE_NOISE_SIZE) as f32
+            }
+        }
+    }
+}
+
+/// The rank of a pixel in a Bayer matrix of 2^n x 2^n,
+/// every bit of the position selects a quadrant of the 2x2 matrix, the lowest bit is the most significant
+pub(crate) fn bayer(n : u32, x : usize, y : usize) -> u32 {
+    const QUADRANTS : [[u32; 2]; 2] = [[0, 2], [3, 1]];
+    (0..n).fold(0, |v, i| v * 4 + QUADRANTS[(y >> i) & 1][(x >> i) & 1])
+}
+
+const BLUE_NOISE_SIZE : usize = 64;
+
+/// The ranks of a blue noise threshold map, computed once with the void-and-cluster method of Ulichney
+fn blue_noise() -> &'static [u16] {
+    static MAP : OnceLock<Vec<u16>> = OnceLock::new();
+    MAP.get_or_init(|| {
+        const N : usize = BLUE_NOISE_SIZE * BLUE_NOISE_SIZE;
+        // a toroidal Gaussian filter with a sigma of 1.5, negligible beyond a radius of 8
+        const RADIUS : usize = 8;
+        let update = |energy : &mut [f32], p : usize, sign : f32| {
+            let (px, py) = (p % BLUE_NOISE_SIZE, p / BLUE_NOISE_SIZE);
+            for dy in 0..=2 * RADIUS {
+                for dx in 0..=2 * RADIUS {
+                    let x = (px + BLUE_NOISE_SIZE + dx - RADIUS) % BLUE_NOISE_SIZE;
+                    let y = (py + BLUE_NOISE_SIZE + dy - RADIUS) % BLUE_NOISE_SIZE;
+                    let d2 = ((dx as f32 - RADIUS as f32).powi(2) + (dy as f32 - RADIUS as f32).powi(2)) / (2.0 * 1.5 * 1.5);
+                    energy[y * BLUE_NOISE_SIZE + x] += sign * (-d2).exp();
+                }
+            }
+        };
+        // the tightest cluster is the point with the most energy, the largest void the empty pixel with the least
+        let extreme = |energy : &[f32], points : &[bool], value : bool, most : bool| {
+            (0..N).filter(|&i| points[i] == value)
+                .max_by(|&a, &b| if most { energy[a].total_cmp(&energy[b]) } else { energy[b].total_cmp(&energy[a]) })
+                .unwrap()
+        };
+        // a random initial pattern of 10% of the pixels, moved until it is evenly spread
+        let mut rng = SplitMix64(BLUE_NOISE_SIZE as u64);
+        let mut points = vec![false; N];
+        let mut energy = vec![0.0; N];
+        let mut count = 0;
+        while count < N / 10 {
+            let p = (rng.next_f64() * N as f64) as usize;
+            if !points[p] {
+                points[p] = true;
+                update(&mut energy, p, 1.0);
+                count += 1;
+            }
+        }
+        loop {
+            let cluster = extreme(&energy, &points, true, true);
+            points[cluster] = false;
+            update(&mut energy, cluster, -1.0);
+            let void = extreme(&energy, &points, false, false);
+            points[void] = true;
+            update(&mut energy, void, 1.0);
+            if void == cluster {
+                break;
+            }
+        }
+        let mut ranks = vec![0u16; N];
+        // remove the tightest clusters of the initial pattern with decreasing ranks
+        let (mut phase1, mut energy1) = (points.clone(), energy.clone());
+        for rank in (0..count).rev() {
+            let cluster = extreme(&energy1, &phase1, true, true);
+            phase1[cluster] = false;
+            update(&mut energy1, cluster, -1.0);
+            ranks[cluster] = rank as u16;
+        }
+        // then fill the largest voids with increasing ranks
+        for rank in count..N {
+            let void = extreme(&energy, &points, false, false);
+            points[void] = true;
+            update(&mut energy, void, 1.0);
+            ranks[void] = rank as u16;
+        }
+        ranks
+    })
+}
+
+/// The space where the error is measured
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::dither::{Algorithm, Dither, ErrorSpace, Target};
+/// use xblend::image::ImageViewMut;
+/// for algorithm in [Algorithm::FloydSteinberg, Algorithm::Bayer(3), Algorithm::BlueNoise] {
+///     let mut pixels = vec![rgb!(0.5,0.5,0.5); 64 * 64];
+///     Dither::new(algorithm).with_space(ErrorSpace::Srgb)
+///         .apply(&mut ImageViewMut::from_slice(&mut pixels, 64, 64).unwrap(), Target::Depth(1, 1, 1));
+///     // in sRGB half of the pixels are white, in linear light about 21%
+///     let white = pixels.iter().filter(|p| p.r() == 1.0).count() as f32 / pixels.len() as f32;
+///     assert!((white - 0.5).abs() < 0.005);
+/// }
+/// ```
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum ErrorSpace {
+    /// The gamma encoded sRGB components
+    Srgb,
+    /// The linear sRGB components
+    #[default]
+    Linear,
+}
+
+impl ErrorSpace {
+    fn encode(self, c : f32) -> f32 {
+        match self {
+            ErrorSpace::Srgb => c,
+            ErrorSpace::Linear => srgb_to_linear(c),
+        }
+    }
+
+    fn decode(self, c : f32) -> f32 {
+        match self {
+            ErrorSpace::Srgb => c,
+            ErrorSpace::Linear => linear_to_srgb(c),
+        }
+    }
+}
+
+fn encode(space : ErrorSpace, c : RGB<f32>) -> [f32; 3] {
+    [space.encode(c.r()), space.encode(c.g()), space.encode(c.b())]
+}
+
+fn decode(space : ErrorSpace, c : [f32; 3]) -> RGB<f32> {
+    RGB::new(space.decode(c[0]), space.decode(c[1]), space.decode(c[2]))
+}
+
+fn sub(a : [f32; 3], b : [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a : [f32; 3], b : [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// The colors a pixel can take
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::dither::{Algorithm, Dither, Target};
+/// use xblend::image::ImageViewMut;
+/// use xblend::space::srgb_to_linear;
+/// // RGB<f32> to 8 bits, between the levels 127 and 128
+/// let v = 0.5 + 0.3 / 255.0;
+/// for algorithm in [Algorithm::FloydSteinberg, Algorithm::JarvisJudiceNinke, Algorithm::Bayer(3)] {
+///     let mut pixels = vec![rgb!(v,v,v); 64 * 64];
+///     Dither::new(algorithm)
+///         .apply(&mut ImageViewMut::from_slice(&mut pixels, 64, 64).unwrap(), Target::Depth(8, 8, 8));
+///     // every pixel converts to RGB<u8> without loss
+///     assert!(pixels.iter().all(|p| p.to_u8().to_f32() == *p));
+///     assert!(pixels.iter().all(|p| p.to_u8().r() == 127 || p.to_u8().r() == 128));
+///     let mean = pixels.iter().map(|p| srgb_to_linear(p.r())).sum::<f32>() / pixels.len() as f32;
+///     assert!((mean - srgb_to_linear(v)).abs() < 1e-4);
+/// }
+/// ```
+#[derive(Debug,Copy,Clone)]
+pub enum Target<'a> {
+    /// The bits of red, green and blue, clamped to 1..=16.
+    /// The components take the values ```k / (2^bits - 1)``` in sRGB.
+    Depth(u32, u32, u32),
+    /// The colors of a palette, the nearest one is taken with the metric of the palette
+    Palette(&'a Palette),
+}
+
+impl Target<'_> {
+    /// The nearest color of the target to an sRGB color, and its palette index
+    fn nearest(&self, color : RGB<f32>) -> (RGB<f32>, usize) {
+        match *self {
+            Target::Depth(r, g, b) => {
+                let level = |c : f32, bits : u32| {
+                    let max = ((1u32 << bits.clamp(1, 16)) - 1) as f32;
+                    (c.clamp(0.0, 1.0) * max).round() / max
+                };
+                (RGB::new(level(color.r(), r), level(color.g(), g), level(color.b(), b)), 0)
+            }
+            Target::Palette(palette) => {
+                let index = palette.nearest(color).expect("the palette is empty").index;
+                (palette.colors()[index], index)
+            }
+        }
+    }
+}
+
+/// A dithering algorithm with its settings
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct Dither {
+    algorithm : Algorithm,
+    space : ErrorSpace,
+    serpentine : bool,
+}
+
+impl Dither {
+    /// Create a dithering in [ErrorSpace::Linear] without serpentine scanning.
+    pub fn new(algorithm : Algorithm) -> Dither {
+        Dither{ algorithm, space : ErrorSpace::Linear, serpentine : false }
+    }
+
+    /// Set the space where the error is measured.
+    pub fn with_space(mut self, space : ErrorSpace) -> Dither {
+        self.space = space;
+        self
+    }
+
+    /// Scan every other row from right to left, which avoids the diagonal artifacts of error diffusion.
+    pub fn with_serpentine(mut self, serpentine : bool) -> Dither {
+        self.serpentine = serpentine;
+        self
+    }
+
+    /// Get the algorithm.
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Get the space where the error is measured.
+    pub fn space(&self) -> ErrorSpace {
+        self.space
+    }
+
+    /// Replace every pixel of an image by a dithered color of the target.
+    /// RGB&lt;u8&gt; and RGBA&lt;u8&gt; pixels are rounded, so with a depth of n bits ```value >> (8 - n)``` is the level.
+    /// # Panics
+    /// Panics if the palette of the target is empty.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::dither::{Algorithm, Dither, Target};
+    /// use xblend::image::ImageViewMut;
+    /// use xblend::space::srgb_to_linear;
+    /// // to RGB565
+    /// let original : Vec<RGBA<u8>> = (0..256).map(|v| rgba!(v as u8, 100, 200, 255)).collect();
+    /// // a value is exact when it is the rounded level of its upper bits
+    /// let exact = |v : u8, bits : u32| {
+    ///     let max = (1u32 << bits) - 1;
+    ///     v as u32 == ((v as u32 >> (8 - bits)) * 255 + max / 2) / max
+    /// };
+    /// let linear = |v : u8| srgb_to_linear(v as f32 / 255.0);
+    /// for algorithm in [Algorithm::Bayer(3), Algorithm::BlueNoise, Algorithm::FloydSteinberg] {
+    ///     let mut pixels = original.clone();
+    ///     Dither::new(algorithm)
+    ///         .apply(&mut ImageViewMut::from_slice(&mut pixels, 16, 16).unwrap(), Target::Depth(5, 6, 5));
+    ///     assert!(pixels.iter().all(|p| exact(p.r(), 5) && exact(p.g(), 6) && exact(p.b(), 5) && p.a() == 255));
+    ///     // the mean error in linear light stays near 0
+    ///     let channels : [fn(&RGBA<u8>) -> u8; 3] = [|p| p.r(), |p| p.g(), |p| p.b()];
+    ///     for channel in channels {
+    ///         let error : f32 = pixels.iter().zip(&original)
+    ///             .map(|(p, o)| linear(channel(p)) - linear(channel(o)))
+    ///             .sum();
+    ///         assert!((error / 256.0).abs() < 0.002);
+    ///     }
+    /// }
+    /// ```
+    pub fn apply<P : RgbPixel>(&self, image : &mut ImageViewMut<'_, P>, target : Target<'_>) {
+        let view = image.as_view();
+        let mut colors = Vec::with_capacity(view.width() * view.height());
+        self.run(&view, &target, |_, _, color, _| colors.push(color));
+        let mut colors = colors.into_iter();
+        for row in image.rows_mut() {
+            for p in row.iter_mut() {
+                let color = colors.next().unwrap();
+                *p = p.map_rgb(|_| color);
+            }
+        }
+    }
+
+    /// Dither an image to the indices of a palette, row by row.
+    /// # Panics
+    /// Panics if the palette is empty.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::dither::{Algorithm, Dither};
+    /// use xblend::image::ImageView;
+    /// use xblend::palette::{Metric, Palette};
+    /// let palette = Palette::new(vec![rgb!(0,0,0), rgb!(255,0,0), rgb!(255,255,0), rgb!(255,255,255)], Metric::DeltaEOk);
+    /// let pixels = vec![rgb!(255,128,0); 32 * 32];
+    /// let image = ImageView::from_slice(&pixels, 32, 32).unwrap();
+    /// for algorithm in [Algorithm::FloydSteinberg, Algorithm::Sierra, Algorithm::BlueNoise] {
+    ///     let indices = Dither::new(algorithm).with_serpentine(true).indices(&image, &palette);
+    ///     // in linear light the orange is 78% red and 22% yellow
+    ///     let red = indices.iter().filter(|&&i| i == 1).count();
+    ///     let yellow = indices.iter().filter(|&&i| i == 2).count();
+    ///     assert_eq!(red + yellow, indices.len());
+    ///     assert!((yellow as f32 / indices.len() as f32 - 0.216).abs() < 0.02);
+    /// }
+    /// ```
+    pub fn indices<P : RgbPixel>(&self, image : &ImageView<'_, P>, palette : &Palette) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(image.width() * image.height());
+        self.run(image, &Target::Palette(palette), |_, _, _, index| indices.push(index));
+        indices
+    }
+
+    /// Dither an image, calling ```emit(x, y, color, index)``` for every pixel in row order
+    fn run<P, F>(&self, image : &ImageView<'_, P>, target : &Target<'_>, mut emit : F)
+        where P : RgbPixel, F : FnMut(usize, usize, RGB<f32>, usize) {
+        let (width, height) = (image.width(), image.height());
+        let space = self.space;
+        let kernel = match self.algorithm.kernel() {
+            Some(kernel) => kernel,
+            None => {
+                for y in 0..height {
+                    for (x, p) in image.row(y).iter().enumerate() {
+                        let (color, index) = self.ordered(p.srgb(), x, y, target);
+                        emit(x, y, color, index);
+                    }
+                }
+                return;
+            }
+        };
+        let (weights, divisor) = kernel;
+        // the errors of the current row and the next two
+        let mut errors = vec![[[0f32; 3]; 3]; width];
+        let mut pixels = vec![(RGB::new(0.0, 0.0, 0.0), 0); width];
+        for y in 0..height {
+            let reverse = self.serpentine && y % 2 == 1;
+            let row = image.row(y);
+            for i in 0..width {
+                let x = if reverse { width - 1 - i } else { i };
+                let c = encode(space, row[x].srgb());
+                // a loose clamp keeps the error bounded when the target does not surround the color
+                let wanted = [0, 1, 2].map(|k| (c[k] + errors[x][0][k]).clamp(-0.5, 1.5));
+                let (color, index) = target.nearest(decode(space, wanted));
+                pixels[x] = (color, index);
+                let chosen = encode(space, color);
+                let error = [0, 1, 2].map(|k| (wanted[k] - chosen[k]) / divisor);
+                for &(dx, dy, w) in weights {
+                    let nx = if reverse { x as isize - dx } else { x as isize + dx };
+                    if nx >= 0 && (nx as usize) < width {
+                        let e = &mut errors[nx as usize][dy];
+                        for k in 0..3 {
+                            e[k] += error[k] * w;
+                        }
+                    }
+                }
+            }
+            for (x, &(color, index)) in pixels.iter().enumerate() {
+                emit(x, y, color, index);
+            }
+            for e in errors.iter_mut() {
+                *e = [e[1], e[2], [0.0; 3]];
+            }
+        }
+    }
+
+    /// The color of an ordered dithering
+    fn ordered(&self, color : RGB<f32>, x : usize, y : usize, target : &Target<'_>) -> (RGB<f32>, usize) {
+        let threshold = self.algorithm.threshold(x, y);
+        let space = self.space;
+        match *target {
+            Target::Depth(r, g, b) => {
+                // take the upper of the two nearest levels with the probability of its share of the light
+                let level = |c : f32, bits : u32| {
+                    let max = ((1u32 << bits.clamp(1, 16)) - 1) as f32;
+                    let lo = (c.clamp(0.0, 1.0) * max).floor().min(max - 1.0);
+                    let (low, high) = (lo / max, (lo + 1.0) / max);
+                    let share = (space.encode(c) - space.encode(low)) / (space.encode(high) - space.encode(low));
+                    if share > threshold { high } else { low }
+                };
+                (RGB::new(level(color.r(), r), level(color.g(), g), level(color.b(), b)), 0)
+            }
+            Target::Palette(palette) => {
+                // mix the nearest color with the color that brings the mix nearest,
+                // the second one is taken with the probability of its share
+                let (nearest, index) = target.nearest(color);
+                let x = encode(space, color);
+                let c = encode(space, nearest);
+                let mut best = (f32::INFINITY, 0.0, index);
+                for (j, &other) in palette.colors().iter().enumerate() {
+                    let d = sub(encode(space, other), c);
+                    let length2 = dot(d, d);
+                    if length2 == 0.0 {
+                        continue;
+                    }
+                    let share = (dot(sub(x, c), d) / length2).clamp(0.0, 1.0);
+                    let miss = sub(x, [0, 1, 2].map(|k| c[k] + d[k] * share));
+                    if share > 0.0 && dot(miss, miss) < best.0 {
+                        best = (dot(miss, miss), share, j);
+                    }
+                }
+                if best.1 > threshold { (palette.colors()[best.2], best.2) } else { (nearest, index) }
+            }
+        }
+    }
+}
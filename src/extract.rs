@@ -0,0 +1,202 @@
+//! # Color extraction
+//! Find the main colors of an image as [Swatch]es with their share of the pixels,
+//! and pick vibrant and muted swatches for theming like the Palette API of Android.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::extract::Extractor;
+//! let mut pixels = vec![rgba!(200,30,30,255); 60];
+//! pixels.extend(vec![rgba!(30,30,200,255); 30]);
+//! // the transparent pixels are ignored
+//! pixels.extend(vec![rgba!(0,255,0,10); 10]);
+//! let swatches = Extractor::new(5).swatches(&pixels);
+//! assert_eq!(swatches.len(), 2);
+//! assert_eq!(swatches[0].color, rgb!(200,30,30));
+//! assert!((swatches[0].share - 2.0 / 3.0).abs() < 1e-6);
+//! ```
+use crate::{RGB, RGBA};
+use crate::quantize::{Method, Quantizer};
+use crate::space::Hsl;
+
+/// A main color of an image
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub struct Swatch {
+    /// The average color of the swatch
+    pub color : RGB<u8>,
+    /// The number of pixels
+    pub population : usize,
+    /// The share of the pixels that are not ignored, in 0..=1
+    pub share : f32,
+}
+
+impl Swatch {
+    /// Get the HSL color.
+    pub fn hsl(&self) -> Hsl {
+        Hsl::from(self.color.to_f32())
+    }
+}
+
+/// How the swatches are sorted
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Preference {
+    /// By population
+    #[default]
+    Population,
+    /// By ```share * (0.25 + saturation)``` with the HSL saturation
+    Vibrant,
+    /// By ```share * (1.25 - saturation)``` with the HSL saturation
+    Muted,
+}
+
+/// Settings of an extraction
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct Extractor {
+    max_colors : usize,
+    min_alpha : u8,
+    method : Method,
+    preference : Preference,
+}
+
+impl Extractor {
+    /// Create an extractor of at most max_colors swatches, clamped to 1..=256,
+    /// which ignores pixels with an alpha below 128 and quantizes with median cut.
+    pub fn new(max_colors : usize) -> Extractor {
+        Extractor{ max_colors : max_colors.clamp(1, 256), min_alpha : 128, method : Method::MedianCut, preference : Preference::Population }
+    }
+
+    /// Ignore the pixels with an alpha below min_alpha.
+    pub fn with_min_alpha(mut self, min_alpha : u8) -> Extractor {
+        self.min_alpha = min_alpha;
+        self
+    }
+
+    /// Set the quantization method.
+    pub fn with_method(mut self, method : Method) -> Extractor {
+        self.method = method;
+        self
+    }
+
+    /// Set how the swatches are sorted.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::extract::{Extractor, Preference};
+    /// let mut pixels = vec![rgba!(120,120,130,255); 70];
+    /// pixels.extend(vec![rgba!(250,200,0,255); 30]);
+    /// let muted = Extractor::new(2).swatches(&pixels);
+    /// let vibrant = Extractor::new(2).with_preference(Preference::Vibrant).swatches(&pixels);
+    /// assert_eq!(muted[0].color, rgb!(120,120,130));
+    /// assert_eq!(vibrant[0].color, rgb!(250,200,0));
+    /// ```
+    pub fn with_preference(mut self, preference : Preference) -> Extractor {
+        self.preference = preference;
+        self
+    }
+
+    /// Get the maximum number of swatches.
+    pub fn max_colors(&self) -> usize {
+        self.max_colors
+    }
+
+    /// Find the swatches of an image, sorted by the preference and then by population.
+    /// The alpha of the pixels that are not ignored is dropped. An image without such pixels has no swatch.
+    pub fn swatches(&self, pixels : &[RGBA<u8>]) -> Vec<Swatch> {
+        let opaque : Vec<RGBA<u8>> = pixels.iter()
+            .filter(|p| p.a() >= self.min_alpha)
+            .map(|p| RGBA::from_rgb(&p.rgb(), 255))
+            .collect();
+        if opaque.is_empty() {
+            return Vec::new();
+        }
+        let quantized = Quantizer::new(self.max_colors, self.method).quantize(&opaque);
+        let total = opaque.len() as f32;
+        let mut swatches : Vec<Swatch> = quantized.palette.iter().zip(&quantized.counts)
+            .filter(|(_, &n)| n > 0)
+            .map(|(c, &population)| Swatch{ color : c.rgb(), population, share : population as f32 / total })
+            .collect();
+        let score = |s : &Swatch| match self.preference {
+            Preference::Population => s.share,
+            Preference::Vibrant => s.share * (0.25 + s.hsl().s),
+            Preference::Muted => s.share * (1.25 - s.hsl().s),
+        };
+        swatches.sort_by(|a, b| score(b).total_cmp(&score(a)).then(b.population.cmp(&a.population)));
+        swatches
+    }
+}
+
+/// A kind of swatch of the Palette API of Android
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Target {
+    LightVibrant,
+    Vibrant,
+    DarkVibrant,
+    LightMuted,
+    Muted,
+    DarkMuted,
+}
+
+impl Target {
+    /// The ranges and the targets of the HSL lightness and saturation, as (min, target, max)
+    fn ranges(self) -> ((f32, f32, f32), (f32, f32, f32)) {
+        const LIGHT : (f32, f32, f32) = (0.55, 0.74, 1.0);
+        const NORMAL : (f32, f32, f32) = (0.3, 0.5, 0.7);
+        const DARK : (f32, f32, f32) = (0.0, 0.26, 0.45);
+        const VIBRANT : (f32, f32, f32) = (0.35, 1.0, 1.0);
+        const MUTED : (f32, f32, f32) = (0.0, 0.3, 0.4);
+        match self {
+            Target::LightVibrant => (LIGHT, VIBRANT),
+            Target::Vibrant => (NORMAL, VIBRANT),
+            Target::DarkVibrant => (DARK, VIBRANT),
+            Target::LightMuted => (LIGHT, MUTED),
+            Target::Muted => (NORMAL, MUTED),
+            Target::DarkMuted => (DARK, MUTED),
+        }
+    }
+
+    /// The score of a swatch, or None if it is out of the ranges
+    fn score(self, swatch : &Swatch, max_population : usize) -> Option<f32> {
+        let ((l_min, l_target, l_max), (s_min, s_target, s_max)) = self.ranges();
+        let hsl = swatch.hsl();
+        if hsl.l < l_min || hsl.l > l_max || hsl.s < s_min || hsl.s > s_max {
+            return None;
+        }
+        Some(0.24 * (1.0 - (hsl.s - s_target).abs())
+            + 0.52 * (1.0 - (hsl.l - l_target).abs())
+            + 0.24 * swatch.population as f32 / max_population as f32)
+    }
+}
+
+/// Pick the best swatch of every target, a swatch is picked at most once in the order of the targets.
+/// The score weights the distance to the target saturation by 0.24, to the target lightness by 0.52,
+/// and the population by 0.24, like Android.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::extract::{pick, Extractor, Target};
+/// let mut pixels = vec![rgba!(30,60,200,255); 50];
+/// pixels.extend(vec![rgba!(240,180,190,255); 30]);
+/// pixels.extend(vec![rgba!(60,50,45,255); 20]);
+/// let swatches = Extractor::new(8).swatches(&pixels);
+/// let picked = pick(&swatches, &[Target::Vibrant, Target::LightVibrant, Target::DarkMuted, Target::LightMuted]);
+/// assert_eq!(picked[0].unwrap().color, rgb!(30,60,200));
+/// assert_eq!(picked[1].unwrap().color, rgb!(240,180,190));
+/// assert_eq!(picked[2].unwrap().color, rgb!(60,50,45));
+/// assert_eq!(picked[3], None);
+/// ```
+pub fn pick(swatches : &[Swatch], targets : &[Target]) -> Vec<Option<Swatch>> {
+    let max_population = swatches.iter().map(|s| s.population).max().unwrap_or(1);
+    let mut used = vec![false; swatches.len()];
+    targets.iter().map(|&target| {
+        let best = swatches.iter().enumerate()
+            .filter(|&(i, _)| !used[i])
+            .filter_map(|(i, s)| target.score(s, max_population).map(|score| (i, score)))
+            .fold(None, |best : Option<(usize, f32)>, c| match best {
+                Some(b) if b.1 >= c.1 => Some(b),
+                _ => Some(c),
+            });
+        best.map(|(i, _)| {
+            used[i] = true;
+            swatches[i]
+        })
+    }).collect()
+}
@@ -0,0 +1,487 @@
+//! # Gradients
+//! A [Gradient] is a list of color stops interpolated in a chosen [Space]. <br>
+//! Colors are in sRGB with a straight alpha and are interpolated premultiplied like CSS,
+//! so a fade to transparent does not pick up the color of the transparent stop.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::gradient::{Gradient, Space};
+//! let gradient = Gradient::new(Space::Srgb)
+//!     .with_stop(0.0, rgba!(1.0,0.0,0.0,1.0))
+//!     .with_stop(1.0, rgba!(0.0,0.0,1.0,0.0));
+//! assert_eq!(gradient.sample(0.5), rgba!(1.0,0.0,0.0,0.5));
+//! assert_eq!(gradient.sample(-1.0), rgba!(1.0,0.0,0.0,1.0));
+//! ```
+use crate::{RGB, RGBA};
+use crate::blend::{Blend, BlendMode};
+use crate::dither::bayer;
+use crate::image::{blend_row_with_opacity, ImageViewMut};
+use crate::space::{from_linear, to_linear, Oklab, Oklch};
+
+/// How hues are interpolated in a cylindrical space, as in CSS
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum HueMethod {
+    /// The arc of at most 180 degrees
+    #[default]
+    Shorter,
+    /// The arc of at least 180 degrees
+    Longer,
+    /// The arc where the hue increases
+    Increasing,
+    /// The arc where the hue decreases
+    Decreasing,
+}
+
+/// The color space where a gradient is interpolated
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Space {
+    /// The gamma encoded sRGB components
+    Srgb,
+    /// The linear sRGB components
+    LinearSrgb,
+    #[default]
+    Oklab,
+    Oklch(HueMethod),
+}
+
+/// The chroma under which an Oklch hue is powerless
+const ACHROMATIC : f32 = 1e-4;
+
+impl Space {
+    /// The components of a color in the space, the hue of Oklch is the last one
+    pub(crate) fn components(self, color : RGB<f32>) -> [f32; 3] {
+        match self {
+            Space::Srgb => [color.r(), color.g(), color.b()],
+            Space::LinearSrgb => {
+                let c = to_linear(color);
+                [c.r(), c.g(), c.b()]
+            }
+            Space::Oklab => {
+                let c = Oklab::from(color);
+                [c.l, c.a, c.b]
+            }
+            Space::Oklch(_) => {
+                let c = Oklch::from(color);
+                [c.l, c.c, if c.c < ACHROMATIC { f32::NAN } else { c.h }]
+            }
+        }
+    }
+
+    /// The color of components in the space
+    pub(crate) fn color(self, c : [f32; 3]) -> RGB<f32> {
+        match self {
+            Space::Srgb => RGB::new(c[0], c[1], c[2]),
+            Space::LinearSrgb => from_linear(RGB::new(c[0], c[1], c[2])),
+            Space::Oklab => RGB::from(Oklab{ l : c[0], a : c[1], b : c[2] }),
+            Space::Oklch(_) => RGB::from(Oklch{ l : c[0], c : c[1], h : if c[2].is_nan() { 0.0 } else { c[2] } }),
+        }
+    }
+
+    /// Interpolate two colors with premultiplied alpha, w in 0..=1
+    pub(crate) fn mix(self, (c0, a0) : ([f32; 3], f32), (c1, a1) : ([f32; 3], f32), w : f32) -> RGBA<f32> {
+        let lerp = |x : f32, y : f32| x + (y - x) * w;
+        let a = lerp(a0, a1);
+        let premultiplied = |x : f32, y : f32| if a > 0.0 { lerp(x * a0, y * a1) / a } else { lerp(x, y) };
+        let mut c = [premultiplied(c0[0], c1[0]), premultiplied(c0[1], c1[1]), premultiplied(c0[2], c1[2])];
+        if let Space::Oklch(method) = self {
+            c[2] = match (c0[2].is_nan(), c1[2].is_nan()) {
+                (true, true) => f32::NAN,
+                (true, false) => c1[2],
+                (false, true) => c0[2],
+                (false, false) => {
+                    let (h0, h1) = fix_hues(c0[2], c1[2], method);
+                    lerp(h0, h1).rem_euclid(360.0)
+                }
+            };
+        }
+        let rgb = self.color(c);
+        RGBA::new(rgb.r().clamp(0.0, 1.0), rgb.g().clamp(0.0, 1.0), rgb.b().clamp(0.0, 1.0), a.clamp(0.0, 1.0))
+    }
+}
+
+/// Adjust two hues in 0..360 so that the arc between them follows the method
+pub(crate) fn fix_hues(mut h0 : f32, mut h1 : f32, method : HueMethod) -> (f32, f32) {
+    let d = h1 - h0;
+    match method {
+        HueMethod::Shorter => {
+            if d > 180.0 { h0 += 360.0 } else if d < -180.0 { h1 += 360.0 }
+        }
+        HueMethod::Longer => {
+            if 0.0 < d && d < 180.0 { h0 += 360.0 } else if -180.0 < d && d <= 0.0 { h1 += 360.0 }
+        }
+        HueMethod::Increasing => if h1 < h0 { h1 += 360.0 },
+        HueMethod::Decreasing => if h0 < h1 { h0 += 360.0 },
+    }
+    (h0, h1)
+}
+
+/// A color at an offset of a gradient
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub struct Stop {
+    /// The offset, never smaller than the offset of the previous stop
+    pub offset : f32,
+    /// The color with a straight alpha
+    pub color : RGBA<f32>,
+    /// The offset where the gradient reaches the middle of this color and the next one
+    pub hint : Option<f32>,
+}
+
+/// Color stops and the space where they are interpolated
+#[derive(Debug,Clone,PartialEq)]
+pub struct Gradient {
+    stops : Vec<Stop>,
+    space : Space,
+    /// The components of every stop in the space and its alpha
+    points : Vec<([f32; 3], f32)>,
+}
+
+impl Gradient {
+    /// Create a gradient without stops.
+    pub fn new(space : Space) -> Gradient {
+        Gradient{ stops : Vec::new(), space, points : Vec::new() }
+    }
+
+    /// Add a stop after the last one.
+    /// Like CSS, an offset smaller than the offset of the previous stop is raised to it, which makes a hard edge.
+    pub fn with_stop<C : Into<RGBA<f32>>>(mut self, offset : f32, color : C) -> Gradient {
+        let color = color.into();
+        let offset = self.stops.last().map_or(offset, |s| offset.max(s.offset));
+        self.points.push((self.space.components(color.rgb()), color.a()));
+        self.stops.push(Stop{ offset, color, hint : None });
+        self
+    }
+
+    /// Set the midpoint between the last stop and the next one. <br>
+    /// The hint is clamped between the two stops when sampling.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::gradient::{Gradient, Space};
+    /// let gradient = Gradient::new(Space::Srgb)
+    ///     .with_stop(0.0, rgba!(0.0,0.0,0.0,1.0))
+    ///     .with_hint(0.25)
+    ///     .with_stop(1.0, rgba!(1.0,1.0,1.0,1.0));
+    /// assert_eq!(gradient.sample(0.25), rgba!(0.5,0.5,0.5,1.0));
+    /// assert!(gradient.sample(0.5).r() > 0.7);
+    /// ```
+    pub fn with_hint(mut self, offset : f32) -> Gradient {
+        if let Some(stop) = self.stops.last_mut() {
+            stop.hint = Some(offset);
+        }
+        self
+    }
+
+    /// Get the stops.
+    pub fn stops(&self) -> &[Stop] {
+        &self.stops
+    }
+
+    /// Get the interpolation space.
+    pub fn space(&self) -> Space {
+        self.space
+    }
+
+    /// Get the color at an offset.<br>
+    /// Before the first stop it is the first color, after the last stop the last color,
+    /// and transparent black if there is no stop. The result is clamped to the sRGB gamut.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::gradient::{Gradient, HueMethod, Space};
+    /// let red = rgba!(1.0,0.0,0.0,1.0);
+    /// let blue = rgba!(0.0,0.0,1.0,1.0);
+    /// // red to blue through magenta, or the other way around through green
+    /// let shorter = Gradient::new(Space::Oklch(HueMethod::Shorter)).with_stop(0.0, red).with_stop(1.0, blue);
+    /// let longer = Gradient::new(Space::Oklch(HueMethod::Longer)).with_stop(0.0, red).with_stop(1.0, blue);
+    /// assert!(shorter.sample(0.5).b() > shorter.sample(0.5).g());
+    /// assert!(longer.sample(0.5).g() > longer.sample(0.5).b());
+    /// // a hard edge at 0.5
+    /// let flag = Gradient::new(Space::Oklab)
+    ///     .with_stop(0.5, red)
+    ///     .with_stop(0.0, blue);
+    /// assert_eq!(flag.sample(0.49), red);
+    /// assert_eq!(flag.sample(0.5), blue);
+    /// ```
+    pub fn sample(&self, t : f32) -> RGBA<f32> {
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return RGBA::new(0.0, 0.0, 0.0, 0.0),
+        };
+        if t.is_nan() || t < first.offset {
+            return first.color;
+        }
+        // the stop after t, so the later stop of a hard edge wins at its offset
+        let next = self.stops.partition_point(|s| s.offset <= t);
+        if next == self.stops.len() {
+            return last.color;
+        }
+        let (s0, s1) = (&self.stops[next - 1], &self.stops[next]);
+        let mut w = (t - s0.offset) / (s1.offset - s0.offset);
+        if let Some(hint) = s0.hint {
+            let h = (hint - s0.offset) / (s1.offset - s0.offset);
+            w = if h <= 0.0 { 1.0 } else if h >= 1.0 { 0.0 } else { w.powf(0.5f32.ln() / h.ln()) };
+        }
+        self.space.mix(self.points[next - 1], self.points[next], w)
+    }
+
+    /// Sample n evenly spaced colors from offset 0 to 1, for a lookup table.<br>
+    /// A single color is sampled at 0.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::gradient::{Gradient, Space};
+    /// let gradient = Gradient::new(Space::LinearSrgb)
+    ///     .with_stop(0.0, rgba!(0,0,0,255))
+    ///     .with_stop(1.0, rgba!(255,255,255,255));
+    /// let colors : Vec<RGBA<u8>> = gradient.colors(3).into_iter().map(RGBA::from).collect();
+    /// assert_eq!(colors, vec![rgba!(0,0,0,255), rgba!(187,187,187,255), rgba!(255,255,255,255)]);
+    /// ```
+    pub fn colors(&self, n : usize) -> Vec<RGBA<f32>> {
+        let step = if n > 1 { 1.0 / (n - 1) as f32 } else { 0.0 };
+        (0..n).map(|i| self.sample(i as f32 * step)).collect()
+    }
+}
+
+/// How a gradient continues outside of the offsets 0..=1
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Spread {
+    /// Extend the colors at 0 and 1
+    #[default]
+    Pad,
+    /// Start again at 0 after 1
+    Repeat,
+    /// Go back and forth between 0 and 1
+    Reflect,
+}
+
+impl Spread {
+    fn apply(self, t : f32) -> f32 {
+        match self {
+            Spread::Pad => t.clamp(0.0, 1.0),
+            Spread::Repeat => t - t.floor(),
+            Spread::Reflect => {
+                let t = t.rem_euclid(2.0);
+                if t > 1.0 { 2.0 - t } else { t }
+            }
+        }
+    }
+}
+
+/// The geometry of a gradient, in pixel coordinates where the pixel ```(x, y)``` is centered at ```(x + 0.5, y + 0.5)```
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub enum Shape {
+    /// Offset 0 at ```start``` and 1 at ```end```, constant on the perpendicular lines
+    Linear{ start : (f32, f32), end : (f32, f32) },
+    /// Offset 0 on the start circle and 1 on the end circle, as in Canvas and SVG.
+    /// Every point takes the largest offset of the interpolated circles passing through it,
+    /// and is left transparent if there is none.
+    Radial{ start : (f32, f32), start_radius : f32, end : (f32, f32), end_radius : f32 },
+    /// Offset 0 to 1 clockwise around ```center```, starting at ```angle``` degrees from the top as in CSS
+    Conic{ center : (f32, f32), angle : f32 },
+}
+
+impl Shape {
+    /// Get the offset at a point before spreading, or None where the gradient is not painted.
+    /// ```
+    /// # extern crate xblend;
+    /// use xblend::gradient::Shape;
+    /// let linear = Shape::Linear{ start : (0.0, 0.0), end : (10.0, 0.0) };
+    /// assert_eq!(linear.offset(2.5, 7.0), Some(0.25));
+    /// let radial = Shape::Radial{ start : (0.0, 0.0), start_radius : 0.0, end : (0.0, 0.0), end_radius : 10.0 };
+    /// assert_eq!(radial.offset(6.0, 8.0), Some(1.0));
+    /// let conic = Shape::Conic{ center : (0.0, 0.0), angle : 0.0 };
+    /// // a quarter turn clockwise from the top, with y going down
+    /// assert_eq!(conic.offset(1.0, 0.0), Some(0.25));
+    /// // nothing is painted where the circles do not reach
+    /// let cone = Shape::Radial{ start : (0.0, 0.0), start_radius : 1.0, end : (10.0, 0.0), end_radius : 2.0 };
+    /// assert_eq!(cone.offset(0.0, 10.0), None);
+    /// ```
+    pub fn offset(&self, x : f32, y : f32) -> Option<f32> {
+        match *self {
+            Shape::Linear{ start, end } => {
+                let d = (end.0 - start.0, end.1 - start.1);
+                let len2 = d.0 * d.0 + d.1 * d.1;
+                if len2 == 0.0 {
+                    return None;
+                }
+                Some(((x - start.0) * d.0 + (y - start.1) * d.1) / len2)
+            }
+            Shape::Radial{ start, start_radius, end, end_radius } => {
+                // solve |p - c(w)| = r(w) for the circle c(w), r(w) at offset w:
+                // a w^2 - 2 b w + c = 0
+                let cd = (end.0 - start.0, end.1 - start.1);
+                let dr = end_radius - start_radius;
+                let pd = (x - start.0, y - start.1);
+                let a = cd.0 * cd.0 + cd.1 * cd.1 - dr * dr;
+                let b = pd.0 * cd.0 + pd.1 * cd.1 + start_radius * dr;
+                let c = pd.0 * pd.0 + pd.1 * pd.1 - start_radius * start_radius;
+                let valid = |w : f32| start_radius + w * dr >= 0.0;
+                if a.abs() <= f32::EPSILON * (cd.0 * cd.0 + cd.1 * cd.1 + dr * dr) {
+                    if b == 0.0 {
+                        return None;
+                    }
+                    let w = c / (2.0 * b);
+                    return if valid(w) { Some(w) } else { None };
+                }
+                let disc = b * b - a * c;
+                if disc < 0.0 {
+                    return None;
+                }
+                let (w0, w1) = ((b + disc.sqrt()) / a, (b - disc.sqrt()) / a);
+                let (high, low) = if w0 > w1 { (w0, w1) } else { (w1, w0) };
+                [high, low].iter().copied().find(|&w| valid(w))
+            }
+            Shape::Conic{ center, angle } => {
+                let turn = (x - center.0).atan2(center.1 - y).to_degrees() - angle;
+                Some((turn / 360.0).rem_euclid(1.0))
+            }
+        }
+    }
+}
+
+/// A pixel a gradient can be rasterized into
+pub trait GradientPixel : Blend {
+    /// Convert a premultiplied color, with a dither offset in -0.5..0.5 of a quantization step.
+    fn from_premultiplied(color : RGBA<f32>, dither : f32) -> Self;
+}
+
+impl GradientPixel for RGBA<f32>{
+    fn from_premultiplied(color : RGBA<f32>, _ : f32) -> Self {
+        color
+    }
+}
+
+impl GradientPixel for RGBA<u8>{
+    fn from_premultiplied(color : RGBA<f32>, dither : f32) -> Self {
+        let quantize = |v : f32| (v * 255.0 + dither).round().clamp(0.0, 255.0) as u8;
+        let a = quantize(color.a());
+        // keep the color premultiplied
+        RGBA::new(quantize(color.r()).min(a), quantize(color.g()).min(a), quantize(color.b()).min(a), a)
+    }
+}
+
+/// The number of colors of the lookup table of a [Paint]
+const LUT_SIZE : usize = 1024;
+
+/// A gradient ready to fill images with a [Shape] and a [Spread]. <br>
+/// The gradient is sampled into a lookup table of premultiplied colors, interpolated linearly.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::gradient::{Gradient, Paint, Shape, Space, Spread};
+/// use xblend::image::ImageViewMut;
+/// let gradient = Gradient::new(Space::Srgb)
+///     .with_stop(0.0, rgba!(0,0,0,255))
+///     .with_stop(1.0, rgba!(255,255,255,255));
+/// let paint = Paint::new(&gradient, Shape::Linear{ start : (0.0, 0.0), end : (4.0, 0.0) })
+///     .spread(Spread::Reflect);
+/// let mut pixels = vec![rgba!(0,0,0,0); 8];
+/// paint.fill(&mut ImageViewMut::from_slice(&mut pixels, 8, 1).unwrap());
+/// let gray : Vec<u8> = pixels.iter().map(|p| p.r()).collect();
+/// assert_eq!(gray, vec![32, 96, 159, 223, 223, 159, 96, 32]);
+/// ```
+#[derive(Debug,Clone,PartialEq)]
+pub struct Paint {
+    lut : Vec<RGBA<f32>>,
+    shape : Shape,
+    spread : Spread,
+    dither : bool,
+}
+
+impl Paint {
+    /// Create a paint with [Spread::Pad] and without dithering.
+    pub fn new(gradient : &Gradient, shape : Shape) -> Paint {
+        let lut = gradient.colors(LUT_SIZE).into_iter()
+            .map(|c| RGBA::new(c.r() * c.a(), c.g() * c.a(), c.b() * c.a(), c.a()))
+            .collect();
+        Paint{ lut, shape, spread : Spread::Pad, dither : false }
+    }
+
+    /// Set the spread mode. A conic gradient covers the offsets 0..1 only, so it ignores it.
+    pub fn spread(mut self, spread : Spread) -> Paint {
+        self.spread = spread;
+        self
+    }
+
+    /// Enable the ordered dithering of RGBA&lt;u8&gt; pixels to avoid banding.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::gradient::{Gradient, Paint, Shape, Space};
+    /// use xblend::image::ImageViewMut;
+    /// // a gradient too flat for 8 bits
+    /// let gradient = Gradient::new(Space::Srgb)
+    ///     .with_stop(0.0, rgba!(100,100,100,255))
+    ///     .with_stop(1.0, rgba!(101,101,101,255));
+    /// let paint = Paint::new(&gradient, Shape::Linear{ start : (0.0, 0.0), end : (64.0, 0.0) }).dither(true);
+    /// let mut pixels = vec![rgba!(0,0,0,0); 64 * 8];
+    /// paint.fill(&mut ImageViewMut::from_slice(&mut pixels, 64, 8).unwrap());
+    /// // the share of lighter pixels grows along the gradient
+    /// let light = |x0 : usize| (0..8).flat_map(|y| (x0..x0 + 16).map(move |x| (x, y)))
+    ///     .filter(|&(x, y)| pixels[y * 64 + x].r() == 101).count();
+    /// assert!(light(0) < light(16) && light(16) < light(32) && light(32) < light(48));
+    /// ```
+    pub fn dither(mut self, dither : bool) -> Paint {
+        self.dither = dither;
+        self
+    }
+
+    /// Get the shape.
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+
+    /// Get the premultiplied color at a point, transparent where the gradient is not painted.
+    pub fn color_at(&self, x : f32, y : f32) -> RGBA<f32> {
+        let t = match self.shape.offset(x, y) {
+            Some(t) if t.is_finite() => self.spread.apply(t),
+            _ => return RGBA::new(0.0, 0.0, 0.0, 0.0),
+        };
+        let pos = t * (LUT_SIZE - 1) as f32;
+        let i = (pos as usize).min(LUT_SIZE - 2);
+        let w = pos - i as f32;
+        let (c0, c1) = (self.lut[i], self.lut[i + 1]);
+        let lerp = |x : f32, y : f32| x + (y - x) * w;
+        RGBA::new(lerp(c0.r(), c1.r()), lerp(c0.g(), c1.g()), lerp(c0.b(), c1.b()), lerp(c0.a(), c1.a()))
+    }
+
+    /// Write a row of the gradient at the pixel row y
+    fn row<P : GradientPixel>(&self, y : usize, row : &mut [P]) {
+        for (x, p) in row.iter_mut().enumerate() {
+            let dither = if self.dither { (bayer(3, x % 8, y % 8) as f32 + 0.5) / 64.0 - 0.5 } else { 0.0 };
+            *p = P::from_premultiplied(self.color_at(x as f32 + 0.5, y as f32 + 0.5), dither);
+        }
+    }
+
+    /// Replace the pixels of an image with the gradient.
+    pub fn fill<P : GradientPixel>(&self, image : &mut ImageViewMut<'_, P>) {
+        for (y, row) in image.rows_mut().enumerate() {
+            self.row(y, row);
+        }
+    }
+
+    /// Composite the gradient onto an image with a blend mode and a constant opacity.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::blend::{BlendMode, Multiply};
+    /// use xblend::gradient::{Gradient, Paint, Shape, Space};
+    /// use xblend::image::ImageViewMut;
+    /// let gradient = Gradient::new(Space::Oklab).with_stop(0.0, rgba!(1.0,0.5,0.0,1.0));
+    /// let paint = Paint::new(&gradient, Shape::Conic{ center : (1.0, 1.0), angle : 0.0 });
+    /// let mut pixels = vec![rgba!(0.5,0.5,0.5,1.0); 4];
+    /// paint.fill_blend(&mut ImageViewMut::from_slice(&mut pixels, 2, 2).unwrap(), BlendMode::Multiply, 1.0);
+    /// let close = |a : f32, b : f32| (a - b).abs() < 1e-5;
+    /// assert!(pixels.iter().all(|p| close(p.r(), 0.5) && close(p.g(), 0.25) && close(p.b(), 0.0)));
+    /// ```
+    pub fn fill_blend<P : GradientPixel>(&self, image : &mut ImageViewMut<'_, P>, mode : BlendMode, opacity : f32) {
+        let width = image.width();
+        let mut src = Vec::with_capacity(width);
+        for (y, row) in image.rows_mut().enumerate() {
+            src.clear();
+            src.resize(width, P::from_premultiplied(RGBA::new(0.0, 0.0, 0.0, 0.0), 0.0));
+            self.row(y, &mut src);
+            blend_row_with_opacity(&src, row, mode, opacity);
+        }
+    }
+}
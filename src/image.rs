@@ -0,0 +1,491 @@
+//! # Images and buffer-level blending
+//! [ImageView] and [ImageViewMut] describe a strided 2-D region of a pixel buffer. <br>
+//! [blend_row] and [blend_image] blend whole rows or images with a [BlendMode]. <br>
+//! [RgbPixel] is implemented by the pixel types that the color operations of the other modules accept. <br>
+//! With the ```rayon``` feature, the ```par_*``` functions split the work across threads by bands of rows
+//! and give exactly the same result as the serial ones.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::blend::{BlendMode, SrcOver};
+//! use xblend::image::{ImageView, ImageViewMut, blend_image};
+//! let src = vec![rgba!(0.5,0.0,0.0,0.5); 4];
+//! // a 2x2 region inside a 3x2 buffer
+//! let mut dst = vec![rgba!(0.0,0.0,1.0,1.0); 6];
+//! let src_view = ImageView::from_slice(&src, 2, 2).unwrap();
+//! let mut dst_view = ImageViewMut::new(&mut dst, 2, 2, 3).unwrap();
+//! blend_image(&src_view, &mut dst_view, BlendMode::SrcOver, 1.0);
+//! let blended = rgba!(0.5,0.0,0.0,0.5).src_over(rgba!(0.0,0.0,1.0,1.0));
+//! assert_eq!(dst[0], blended);
+//! assert_eq!(dst[2], rgba!(0.0,0.0,1.0,1.0));
+//! assert_eq!(dst[4], blended);
+//! ```
+use std::fmt;
+use crate::{RGB, RGBA};
+use crate::blend::{Blend, BlendMode};
+
+/// The reason why a buffer can not be viewed as an image.
+#[derive(Debug,Copy,Clone,PartialEq,Eq)]
+pub enum ImageError {
+    /// The stride is smaller than the width.
+    Stride{ width : usize, stride : usize },
+    /// The buffer is too small for the width, height and stride.
+    BufferSize{ required : usize, len : usize },
+    /// The number of pixels for the width, height and stride overflows usize.
+    Overflow{ width : usize, height : usize, stride : usize },
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::Stride{ width, stride } =>
+                write!(f, "stride {} is smaller than width {}", stride, width),
+            ImageError::BufferSize{ required, len } =>
+                write!(f, "buffer of {} pixels is too small, {} are required", len, required),
+            ImageError::Overflow{ width, height, stride } =>
+                write!(f, "{}x{} pixels with stride {} overflow usize", width, height, stride),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+fn required_len(width : usize, height : usize, stride : usize, len : usize) -> Result<usize, ImageError> {
+    if stride < width {
+        return Err(ImageError::Stride{ width, stride });
+    }
+    let required = if height == 0 {
+        0
+    } else {
+        stride.checked_mul(height - 1).and_then(|r| r.checked_add(width))
+            .ok_or(ImageError::Overflow{ width, height, stride })?
+    };
+    if len < required {
+        return Err(ImageError::BufferSize{ required, len });
+    }
+    Ok(required)
+}
+
+/// A read-only strided view of a pixel buffer.
+/// Row ```y``` starts at ```y * stride``` and contains ```width``` pixels.
+#[derive(Debug,Copy,Clone)]
+pub struct ImageView<'a, P>{
+    pixels : &'a [P],
+    width : usize,
+    height : usize,
+    stride : usize,
+}
+
+impl<'a, P> ImageView<'a, P>{
+    /// Create a view with an explicit stride in pixels.
+    /// ```
+    /// # extern crate xblend;
+    /// use xblend::image::{ImageError, ImageView};
+    /// let pixels = [0u8; 12];
+    /// assert!(ImageView::new(&pixels, 4, 3, 4).is_ok());
+    /// assert_eq!(ImageView::new(&pixels, 4, 3, 5).unwrap_err(), ImageError::BufferSize{ required : 14, len : 12 });
+    /// let huge = ImageView::new(&pixels, 4, 3, usize::MAX / 2 + 1);
+    /// assert_eq!(huge.unwrap_err(), ImageError::Overflow{ width : 4, height : 3, stride : usize::MAX / 2 + 1 });
+    /// ```
+    pub fn new(pixels : &'a [P], width : usize, height : usize, stride : usize) -> Result<Self, ImageError> {
+        let required = required_len(width, height, stride, pixels.len())?;
+        Ok(ImageView{ pixels : &pixels[..required], width, height, stride })
+    }
+
+    /// Create a view of a tightly packed buffer.
+    pub fn from_slice(pixels : &'a [P], width : usize, height : usize) -> Result<Self, ImageError> {
+        Self::new(pixels, width, height, width)
+    }
+
+    /// Get the width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Get the height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Get the distance between two rows in pixels.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Get the pixels of row ```y```.
+    pub fn row(&self, y : usize) -> &'a [P] {
+        assert!(y < self.height, "row {} out of range", y);
+        let start = y * self.stride;
+        &self.pixels[start..start + self.width]
+    }
+
+    /// Iterate over all rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [P]> + '_ {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// Get the pixel at (x,y).
+    pub fn get(&self, x : usize, y : usize) -> Option<&'a P> {
+        if x < self.width && y < self.height {
+            self.pixels.get(y * self.stride + x)
+        } else {
+            None
+        }
+    }
+}
+
+/// A mutable strided view of a pixel buffer.
+/// Row ```y``` starts at ```y * stride``` and contains ```width``` pixels.
+#[derive(Debug)]
+pub struct ImageViewMut<'a, P>{
+    pixels : &'a mut [P],
+    width : usize,
+    height : usize,
+    stride : usize,
+}
+
+impl<'a, P> ImageViewMut<'a, P>{
+    /// Create a view with an explicit stride in pixels.
+    pub fn new(pixels : &'a mut [P], width : usize, height : usize, stride : usize) -> Result<Self, ImageError> {
+        let required = required_len(width, height, stride, pixels.len())?;
+        Ok(ImageViewMut{ pixels : &mut pixels[..required], width, height, stride })
+    }
+
+    /// Create a view of a tightly packed buffer.
+    pub fn from_slice(pixels : &'a mut [P], width : usize, height : usize) -> Result<Self, ImageError> {
+        Self::new(pixels, width, height, width)
+    }
+
+    /// Get the width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Get the height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Get the distance between two rows in pixels.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Reborrow as a read-only view.
+    pub fn as_view(&self) -> ImageView<'_, P> {
+        ImageView{ pixels : self.pixels, width : self.width, height : self.height, stride : self.stride }
+    }
+
+    /// Get the pixels of row ```y```.
+    pub fn row(&self, y : usize) -> &[P] {
+        assert!(y < self.height, "row {} out of range", y);
+        let start = y * self.stride;
+        &self.pixels[start..start + self.width]
+    }
+
+    /// Get the mutable pixels of row ```y```.
+    pub fn row_mut(&mut self, y : usize) -> &mut [P] {
+        assert!(y < self.height, "row {} out of range", y);
+        let start = y * self.stride;
+        &mut self.pixels[start..start + self.width]
+    }
+
+    /// Iterate over all mutable rows from top to bottom.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [P]> + '_ {
+        let width = self.width;
+        self.pixels.chunks_mut(self.stride.max(1))
+            .take(self.height)
+            .map(move |row| &mut row[..width])
+    }
+
+    /// Get the mutable pixel at (x,y).
+    pub fn get_mut(&mut self, x : usize, y : usize) -> Option<&mut P> {
+        if x < self.width && y < self.height {
+            self.pixels.get_mut(y * self.stride + x)
+        } else {
+            None
+        }
+    }
+}
+
+/// A pixel type whose color can be transformed, the alpha is kept
+pub trait RgbPixel : Copy {
+    /// Replace the color by ```f(color)``` where the color is gamma encoded sRGB in 0..=1.
+    fn map_rgb<F : Fn(RGB<f32>) -> RGB<f32>>(self, f : F) -> Self;
+
+    /// Get the color as gamma encoded sRGB in 0..=1.
+    fn srgb(self) -> RGB<f32>;
+
+    /// Get the straight alpha in 0..=1, 1 for pixels without alpha.
+    fn alpha(self) -> f32 {
+        1.0
+    }
+
+    /// Replace the color by ```f(color)``` if the pixel stores u8 channels, None otherwise.
+    /// It lets the operations on channels use lookup tables.
+    fn map_rgb_u8<F : Fn(RGB<u8>) -> RGB<u8>>(self, f : F) -> Option<Self> {
+        let _ = f;
+        None
+    }
+}
+
+fn to_u8(color : RGB<f32>) -> RGB<u8> {
+    let round = |c : f32| (c.clamp(0.0, 1.0) * 255.0 + 0.5) as u8;
+    RGB::new(round(color.r()), round(color.g()), round(color.b()))
+}
+
+impl RgbPixel for RGB<f32>{
+    fn map_rgb<F : Fn(RGB<f32>) -> RGB<f32>>(self, f : F) -> Self {
+        f(self)
+    }
+
+    fn srgb(self) -> RGB<f32> {
+        self
+    }
+}
+
+impl RgbPixel for RGB<u8>{
+    fn map_rgb<F : Fn(RGB<f32>) -> RGB<f32>>(self, f : F) -> Self {
+        to_u8(f(self.to_f32()))
+    }
+
+    fn srgb(self) -> RGB<f32> {
+        self.to_f32()
+    }
+
+    fn map_rgb_u8<F : Fn(RGB<u8>) -> RGB<u8>>(self, f : F) -> Option<Self> {
+        Some(f(self))
+    }
+}
+
+impl RgbPixel for RGBA<f32>{
+    fn map_rgb<F : Fn(RGB<f32>) -> RGB<f32>>(self, f : F) -> Self {
+        RGBA::from_rgb(&f(self.rgb()), self.a())
+    }
+
+    fn srgb(self) -> RGB<f32> {
+        self.rgb()
+    }
+
+    fn alpha(self) -> f32 {
+        self.a()
+    }
+}
+
+impl RgbPixel for RGBA<u8>{
+    fn map_rgb<F : Fn(RGB<f32>) -> RGB<f32>>(self, f : F) -> Self {
+        RGBA::from_rgb(&to_u8(f(self.rgb().to_f32())), self.a())
+    }
+
+    fn srgb(self) -> RGB<f32> {
+        self.rgb().to_f32()
+    }
+
+    fn alpha(self) -> f32 {
+        self.a() as f32 / 255.0
+    }
+
+    fn map_rgb_u8<F : Fn(RGB<u8>) -> RGB<u8>>(self, f : F) -> Option<Self> {
+        Some(RGBA::from_rgb(&f(self.rgb()), self.a()))
+    }
+}
+
+/// Blend a row of ```src``` pixels onto ```dst``` in place. <br>
+/// ```dst[i] = src[i].blend(dst[i], mode)```
+/// # Panics
+/// Panics if the rows have different lengths.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::blend::*;
+/// use xblend::image::blend_row;
+/// let src = [rgba!(0.2,0.4,0.6,0.5), rgba!(1.0,0.5,0.0,1.0), rgba!(0.0,0.0,0.0,0.0)];
+/// let dst = [rgba!(0.5,0.5,0.5,1.0), rgba!(0.1,0.2,0.3,0.4), rgba!(0.3,0.3,0.3,0.3)];
+/// type F = fn(RGBA<f32>, RGBA<f32>) -> RGBA<f32>;
+/// let modes : [(BlendMode, F); 16] = [
+///     (BlendMode::Clear, Clear::clear), (BlendMode::Src, Src::src),
+///     (BlendMode::Dst, Dst::dst), (BlendMode::SrcOver, SrcOver::src_over),
+///     (BlendMode::DstOver, DstOver::dst_over), (BlendMode::SrcIn, SrcIn::src_in),
+///     (BlendMode::DstIn, DstIn::dst_in), (BlendMode::SrcOut, SrcOut::src_out),
+///     (BlendMode::DstOut, DstOut::dst_out), (BlendMode::SrcATop, SrcATop::src_atop),
+///     (BlendMode::DstATop, DstATop::dst_atop), (BlendMode::Xor, Xor::xor),
+///     (BlendMode::Darken, Darken::darken), (BlendMode::Lighten, Lighten::lighten),
+///     (BlendMode::Multiply, Multiply::multiply), (BlendMode::Screen, Screen::screen),
+/// ];
+/// for &(mode, f) in &modes {
+///     let mut out = dst;
+///     blend_row(&src, &mut out, mode);
+///     for i in 0..3 {
+///         assert_eq!(out[i], f(src[i], dst[i]));
+///     }
+/// }
+/// ```
+pub fn blend_row<P : Blend>(src : &[P], dst : &mut [P], mode : BlendMode) {
+    assert_eq!(src.len(), dst.len(), "rows have different lengths");
+    P::blend_slice(src, dst, mode);
+}
+
+/// Blend a row of ```src``` pixels onto ```dst``` after fading ```src``` by a constant opacity. <br>
+/// ```dst[i] = src[i].fade(opacity).blend(dst[i], mode)```
+/// # Panics
+/// Panics if the rows have different lengths.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::blend::{Blend, BlendMode, SrcOver};
+/// use xblend::image::blend_row_with_opacity;
+/// let src = [rgba!(200,100,0,255); 2];
+/// let mut dst = [rgba!(0,0,255,255); 2];
+/// blend_row_with_opacity(&src, &mut dst, BlendMode::SrcOver, 0.5);
+/// assert_eq!(dst[1], src[1].fade(0.5).blend(rgba!(0,0,255,255), BlendMode::SrcOver));
+/// ```
+pub fn blend_row_with_opacity<P : Blend>(src : &[P], dst : &mut [P], mode : BlendMode, opacity : f32) {
+    if opacity >= 1.0 || src.is_empty() {
+        return blend_row(src, dst, mode);
+    }
+    assert_eq!(src.len(), dst.len(), "rows have different lengths");
+    //fade a small chunk at a time so the blending itself can use P::blend_slice
+    let mut faded = [src[0]; 64];
+    for (s, d) in src.chunks(64).zip(dst.chunks_mut(64)) {
+        let faded = &mut faded[..s.len()];
+        for (f, s) in faded.iter_mut().zip(s) {
+            *f = s.fade(opacity);
+        }
+        P::blend_slice(faded, d, mode);
+    }
+}
+
+/// Blend the ```src``` image onto the ```dst``` image row by row with a constant opacity.
+/// # Panics
+/// Panics if the images have different sizes.
+pub fn blend_image<P : Blend>(src : &ImageView<'_, P>, dst : &mut ImageViewMut<'_, P>, mode : BlendMode, opacity : f32) {
+    assert!(src.width() == dst.width() && src.height() == dst.height(), "images have different sizes");
+    for (y, row) in dst.rows_mut().enumerate() {
+        blend_row_with_opacity(src.row(y), row, mode, opacity);
+    }
+}
+
+/// Write ```f(src pixel)``` into every pixel of ```dst```.
+/// # Panics
+/// Panics if the images have different sizes.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::image::{ImageView, ImageViewMut, map_image};
+/// let src = [rgba!(255,0,0,255), rgba!(0,0,255,255)];
+/// let mut dst = [rgb!(0,0,0); 2];
+/// map_image(&ImageView::from_slice(&src, 2, 1).unwrap(),
+///           &mut ImageViewMut::from_slice(&mut dst, 2, 1).unwrap(),
+///           |c| c.rgb());
+/// assert_eq!(dst, [rgb!(255,0,0), rgb!(0,0,255)]);
+/// ```
+pub fn map_image<S : Copy, D, F>(src : &ImageView<'_, S>, dst : &mut ImageViewMut<'_, D>, f : F)
+    where F : Fn(S) -> D {
+    assert!(src.width() == dst.width() && src.height() == dst.height(), "images have different sizes");
+    for (y, row) in dst.rows_mut().enumerate() {
+        map_row(src.row(y), row, &f);
+    }
+}
+
+/// Replace every pixel of ```image``` by ```f(pixel)```.
+pub fn map_image_in_place<P : Copy, F>(image : &mut ImageViewMut<'_, P>, f : F)
+    where F : Fn(P) -> P {
+    for row in image.rows_mut() {
+        for p in row.iter_mut() {
+            *p = f(*p);
+        }
+    }
+}
+
+/// Convert every pixel of ```src``` into the pixel type of ```dst```, e.g. RGBA&lt;u8&gt; to RGBA&lt;f32&gt;.
+/// # Panics
+/// Panics if the images have different sizes.
+pub fn convert_image<S : Copy, D : From<S>>(src : &ImageView<'_, S>, dst : &mut ImageViewMut<'_, D>) {
+    map_image(src, dst, D::from)
+}
+
+fn map_row<S : Copy, D, F : Fn(S) -> D>(src : &[S], dst : &mut [D], f : &F) {
+    for (s, d) in src.iter().zip(dst.iter_mut()) {
+        *d = f(*s);
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod par {
+    use rayon::prelude::*;
+    use crate::blend::{Blend, BlendMode};
+    use super::{ImageView, ImageViewMut, blend_row_with_opacity, map_row};
+
+    /// The number of pixels processed by one task
+    const BAND_PIXELS : usize = 1 << 14;
+
+    /// Call ```f(y, row)``` for every row, splitting the image into bands of rows processed in parallel.
+    /// Every row is computed independently, so the result does not depend on the splitting.
+    fn for_each_row<P : Send, F>(image : &mut ImageViewMut<'_, P>, f : F)
+        where F : Fn(usize, &mut [P]) + Sync {
+        let width = image.width;
+        let height = image.height;
+        let stride = image.stride.max(1);
+        let band_rows = (BAND_PIXELS / width.max(1)).max(1);
+        // a single row may have any stride
+        image.pixels.par_chunks_mut(stride.saturating_mul(band_rows))
+            .enumerate()
+            .for_each(|(band, pixels)| {
+                let first = band * band_rows;
+                for (i, row) in pixels.chunks_mut(stride).enumerate().take(height.saturating_sub(first)) {
+                    f(first + i, &mut row[..width]);
+                }
+            });
+    }
+
+    /// The parallel version of [blend_image](super::blend_image).
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::blend::BlendMode;
+    /// use xblend::image::{ImageView, ImageViewMut, blend_image, par_blend_image};
+    /// let src : Vec<RGBA<f32>> = (0..300 * 200).map(|i| rgba!((i % 7) as f32 / 7.0, 0.1, 0.2, 0.5)).collect();
+    /// let mut serial = vec![rgba!(0.2,0.4,0.6,1.0); 300 * 200];
+    /// let mut parallel = serial.clone();
+    /// let view = ImageView::from_slice(&src, 300, 200).unwrap();
+    /// blend_image(&view, &mut ImageViewMut::from_slice(&mut serial, 300, 200).unwrap(), BlendMode::Screen, 0.7);
+    /// par_blend_image(&view, &mut ImageViewMut::from_slice(&mut parallel, 300, 200).unwrap(), BlendMode::Screen, 0.7);
+    /// assert_eq!(serial, parallel);
+    /// ```
+    /// # Panics
+    /// Panics if the images have different sizes.
+    pub fn par_blend_image<P : Blend + Send + Sync>(src : &ImageView<'_, P>, dst : &mut ImageViewMut<'_, P>, mode : BlendMode, opacity : f32) {
+        assert!(src.width() == dst.width() && src.height() == dst.height(), "images have different sizes");
+        for_each_row(dst, |y, row| blend_row_with_opacity(src.row(y), row, mode, opacity));
+    }
+
+    /// The parallel version of [map_image](super::map_image).
+    /// # Panics
+    /// Panics if the images have different sizes.
+    pub fn par_map_image<S, D, F>(src : &ImageView<'_, S>, dst : &mut ImageViewMut<'_, D>, f : F)
+        where S : Copy + Sync, D : Send, F : Fn(S) -> D + Sync {
+        assert!(src.width() == dst.width() && src.height() == dst.height(), "images have different sizes");
+        for_each_row(dst, |y, row| map_row(src.row(y), row, &f));
+    }
+
+    /// The parallel version of [map_image_in_place](super::map_image_in_place).
+    pub fn par_map_image_in_place<P, F>(image : &mut ImageViewMut<'_, P>, f : F)
+        where P : Copy + Send, F : Fn(P) -> P + Sync {
+        for_each_row(image, |_, row| {
+            for p in row.iter_mut() {
+                *p = f(*p);
+            }
+        });
+    }
+
+    /// The parallel version of [convert_image](super::convert_image).
+    /// # Panics
+    /// Panics if the images have different sizes.
+    pub fn par_convert_image<S, D>(src : &ImageView<'_, S>, dst : &mut ImageViewMut<'_, D>)
+        where S : Copy + Sync, D : From<S> + Send {
+        par_map_image(src, dst, D::from)
+    }
+}
+
+#[cfg(feature = "rayon")]
+pub use par::*;
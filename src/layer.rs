@@ -0,0 +1,446 @@
+//! # Layer stacks
+//! A [LayerStack] flattens a stack of [Layer]s and nested [Group]s into one premultiplied
+//! RGBA&lt;f32&gt; buffer, from the bottom of the stack to the top. <br>
+//! The modes with a blend function (Darken, Lighten, Multiply, Screen) follow the W3C compositing formula
+//! ```co = cs * (1 - ab) + cb * (1 - as) + as * ab * B(Cb, Cs)``` <br>
+//! with [BlendMode::blend_color], so opaque pixels give the same result as [Blend::blend].
+//! Darken and Lighten pick the whole color by its gray value, unlike the per-channel W3C modes.
+//! The other modes use the Porter-Duff operators in [blend](crate::blend).
+//! Opacity, masks and clipping scale the coverage of a layer, so outside of its coverage
+//! the backdrop is left untouched whatever the mode is.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::blend::BlendMode;
+//! use xblend::layer::{Layer, LayerStack};
+//! let mut stack = LayerStack::new(2, 1);
+//! stack.push(Layer::new(vec![rgba!(1.0,1.0,1.0,1.0); 2], 2, 1));
+//! let mut red = Layer::new(vec![rgba!(1.0,0.0,0.0,1.0)], 1, 1);
+//! red.offset = (1, 0);
+//! red.opacity = 0.5;
+//! stack.push(red);
+//! assert_eq!(stack.flatten(), vec![rgba!(1.0,1.0,1.0,1.0), rgba!(1.0,0.5,0.5,1.0)]);
+//! ```
+use crate::{RGB, RGBA};
+use crate::blend::{Blend, BlendMode};
+
+/// A layer with its own premultiplied pixel buffer.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::layer::{Layer, LayerStack};
+/// // the base only covers the left pixel
+/// let base = Layer::new(vec![rgba!(0.0,0.0,1.0,1.0), rgba!(0.0,0.0,0.0,0.0)], 2, 1);
+/// let mut clipped = Layer::new(vec![rgba!(1.0,0.0,0.0,1.0); 2], 2, 1).with_mask(vec![0.5, 1.0]);
+/// clipped.clip = true;
+/// let mut stack = LayerStack::new(2, 1);
+/// stack.push(base);
+/// stack.push(clipped);
+/// assert_eq!(stack.flatten(), vec![rgba!(0.5,0.0,0.5,1.0), rgba!(0.0,0.0,0.0,0.0)]);
+/// ```
+#[derive(Debug,Clone,PartialEq)]
+pub struct Layer {
+    pixels : Vec<RGBA<f32>>,
+    mask : Option<Vec<f32>>,
+    width : usize,
+    height : usize,
+    /// The position of the top left pixel on the canvas.
+    pub offset : (i32, i32),
+    /// The constant opacity in 0..=1.
+    pub opacity : f32,
+    /// The mode used to blend the layer with the layers below.
+    pub mode : BlendMode,
+    /// Hidden layers are skipped.
+    pub visible : bool,
+    /// Clip the layer to the coverage of the nearest unclipped layer below.
+    pub clip : bool,
+}
+
+impl Layer {
+    /// Create a visible, opaque SrcOver layer at (0,0).
+    /// # Panics
+    /// Panics if ```pixels.len() != width * height```.
+    pub fn new(pixels : Vec<RGBA<f32>>, width : usize, height : usize) -> Layer {
+        assert_eq!(pixels.len(), width * height, "layer buffer has a wrong size");
+        Layer{
+            pixels,
+            mask : None,
+            width,
+            height,
+            offset : (0, 0),
+            opacity : 1.0,
+            mode : BlendMode::SrcOver,
+            visible : true,
+            clip : false,
+        }
+    }
+
+    /// Set a mask with one coverage value in 0..=1 for every pixel of the layer.
+    /// # Panics
+    /// Panics if ```mask.len() != width * height```.
+    pub fn with_mask(mut self, mask : Vec<f32>) -> Layer {
+        assert_eq!(mask.len(), self.width * self.height, "mask has a wrong size");
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Remove the mask.
+    pub fn remove_mask(&mut self) -> Option<Vec<f32>> {
+        self.mask.take()
+    }
+
+    /// Get the width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Get the height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Get the pixels.
+    pub fn pixels(&self) -> &[RGBA<f32>] {
+        &self.pixels
+    }
+
+    /// Get the mutable pixels.
+    pub fn pixels_mut(&mut self) -> &mut [RGBA<f32>] {
+        &mut self.pixels
+    }
+
+    /// Get the mask.
+    pub fn mask(&self) -> Option<&[f32]> {
+        self.mask.as_deref()
+    }
+}
+
+/// A group of nodes composited together.
+#[derive(Debug,Clone,PartialEq)]
+pub struct Group {
+    /// The children from bottom to top.
+    pub children : Vec<Node>,
+    /// The constant opacity in 0..=1.
+    pub opacity : f32,
+    /// The mode used to blend an isolated group with the layers below.
+    pub mode : BlendMode,
+    /// Hidden groups are skipped.
+    pub visible : bool,
+    /// Clip the group to the coverage of the nearest unclipped layer below.
+    pub clip : bool,
+    /// An isolated group is flattened on a transparent backdrop and then blended as one layer.
+    /// Otherwise the group passes through: the children are blended directly onto the layers
+    /// below and the result is interpolated with the layers below by the opacity, clipping included. <br>
+    /// This is the non-isolated, non-knockout group of PDF painted with the Normal mode
+    /// and the opacity as constant alpha, see [Group::pass_through].
+    /// The mode of a pass-through group is not used.
+    pub isolated : bool,
+}
+
+impl Group {
+    /// Create a visible, opaque, isolated SrcOver group.
+    pub fn new(children : Vec<Node>) -> Group {
+        Group{
+            children,
+            opacity : 1.0,
+            mode : BlendMode::SrcOver,
+            visible : true,
+            clip : false,
+            isolated : true,
+        }
+    }
+
+    /// Create a visible, opaque pass-through group.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::blend::BlendMode;
+    /// use xblend::layer::{Group, Layer, LayerStack};
+    /// use xblend::pdf;
+    /// // a half transparent group of a multiplied layer
+    /// let mut layer = Layer::new(vec![rgba!(0.25,0.25,0.0,0.5)], 1, 1);
+    /// layer.mode = BlendMode::Multiply;
+    /// let mut group = Group::pass_through(vec![layer.into()]);
+    /// group.opacity = 0.5;
+    /// let mut stack = LayerStack::new(1, 1);
+    /// stack.push(group);
+    /// let mut canvas = vec![rgba!(0.2,0.4,0.8,1.0)];
+    /// stack.flatten_onto(&mut canvas);
+    /// // the same group in the PDF model
+    /// let mut object = pdf::Element::object(pdf::Object::new(vec![rgb!(0.5,0.5,0.0)], vec![1.0], vec![0.5]));
+    /// object.mode = pdf::BlendMode::Multiply;
+    /// let mut element = pdf::Element::group(pdf::Group::new(vec![object]));
+    /// element.opacity = 0.5;
+    /// let mut page = vec![rgba!(0.2,0.4,0.8,1.0)];
+    /// pdf::Group::new(vec![element]).render(&mut page);
+    /// for (a, b) in [(canvas[0].r(), page[0].r()), (canvas[0].g(), page[0].g()), (canvas[0].b(), page[0].b())] {
+    ///     assert!((a - b).abs() < 1e-6);
+    /// }
+    /// assert!((canvas[0].b() - 0.6).abs() < 1e-6);
+    /// ```
+    pub fn pass_through(children : Vec<Node>) -> Group {
+        Group{ isolated : false, ..Group::new(children) }
+    }
+}
+
+/// A node of a layer stack.
+#[derive(Debug,Clone,PartialEq)]
+pub enum Node {
+    Layer(Layer),
+    Group(Group),
+}
+
+impl Node {
+    fn visible(&self) -> bool {
+        match self {
+            Node::Layer(layer) => layer.visible,
+            Node::Group(group) => group.visible,
+        }
+    }
+
+    fn clip(&self) -> bool {
+        match self {
+            Node::Layer(layer) => layer.clip,
+            Node::Group(group) => group.clip,
+        }
+    }
+}
+
+impl From<Layer> for Node {
+    fn from(layer : Layer) -> Node {
+        Node::Layer(layer)
+    }
+}
+
+impl From<Group> for Node {
+    fn from(group : Group) -> Node {
+        Node::Group(group)
+    }
+}
+
+/// A stack of layers and groups flattened on a canvas of a fixed size.
+#[derive(Debug,Clone,PartialEq)]
+pub struct LayerStack {
+    width : usize,
+    height : usize,
+    /// The nodes from bottom to top.
+    pub nodes : Vec<Node>,
+}
+
+impl LayerStack {
+    /// Create an empty stack.
+    pub fn new(width : usize, height : usize) -> LayerStack {
+        LayerStack{ width, height, nodes : Vec::new() }
+    }
+
+    /// Get the width of the canvas in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Get the height of the canvas in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Push a layer or a group on the top of the stack.
+    pub fn push<N : Into<Node>>(&mut self, node : N) {
+        self.nodes.push(node.into());
+    }
+
+    /// Flatten the stack on a transparent canvas.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::blend::{Blend, BlendMode};
+    /// use xblend::layer::{Layer, LayerStack};
+    /// # fn close(a : RGBA<f32>, b : RGBA<f32>) -> bool {
+    /// #     [a.r() - b.r(), a.g() - b.g(), a.b() - b.b(), a.a() - b.a()].iter().all(|d| d.abs() < 1e-6)
+    /// # }
+    /// // opaque layers give the same result as Blend::blend
+    /// let base = rgba!(0.9,0.1,0.1,1.0);
+    /// let top = rgba!(0.2,0.3,0.4,1.0);
+    /// for &mode in &[BlendMode::Darken, BlendMode::Lighten, BlendMode::Multiply, BlendMode::Screen, BlendMode::Xor] {
+    ///     let mut layer = Layer::new(vec![top], 1, 1);
+    ///     layer.mode = mode;
+    ///     let mut stack = LayerStack::new(1, 1);
+    ///     stack.push(Layer::new(vec![base], 1, 1));
+    ///     stack.push(layer);
+    ///     assert!(close(stack.flatten()[0], top.blend(base, mode)));
+    /// }
+    /// ```
+    pub fn flatten(&self) -> Vec<RGBA<f32>> {
+        let mut canvas = vec![RGBA::new(0.0, 0.0, 0.0, 0.0); self.width * self.height];
+        self.flatten_onto(&mut canvas);
+        canvas
+    }
+
+    /// Flatten the stack onto a premultiplied backdrop of the canvas size.
+    /// # Panics
+    /// Panics if ```backdrop.len() != width * height```.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::blend::BlendMode;
+    /// use xblend::layer::{Group, Layer, LayerStack};
+    /// let gray = || Layer::new(vec![rgba!(0.5,0.5,0.5,1.0)], 1, 1);
+    /// let mut multiply = gray();
+    /// multiply.mode = BlendMode::Multiply;
+    /// // a pass-through group blends its children with the backdrop
+    /// let mut stack = LayerStack::new(1, 1);
+    /// stack.push(Group::pass_through(vec![multiply.clone().into()]));
+    /// let mut canvas = vec![rgba!(0.5,0.5,0.5,1.0)];
+    /// stack.flatten_onto(&mut canvas);
+    /// assert_eq!(canvas, vec![rgba!(0.25,0.25,0.25,1.0)]);
+    /// // an isolated group multiplies with nothing, then covers the backdrop
+    /// let mut stack = LayerStack::new(1, 1);
+    /// stack.push(Group::new(vec![multiply.into()]));
+    /// let mut canvas = vec![rgba!(0.2,0.2,0.2,1.0)];
+    /// stack.flatten_onto(&mut canvas);
+    /// assert_eq!(canvas, vec![rgba!(0.5,0.5,0.5,1.0)]);
+    /// ```
+    pub fn flatten_onto(&self, backdrop : &mut [RGBA<f32>]) {
+        assert_eq!(backdrop.len(), self.width * self.height, "backdrop has a wrong size");
+        let canvas = Canvas{ width : self.width, height : self.height };
+        canvas.composite_nodes(&self.nodes, backdrop);
+    }
+}
+
+fn lerp(a : RGBA<f32>, b : RGBA<f32>, t : f32) -> RGBA<f32> {
+    RGBA::new(
+        a.r() + (b.r() - a.r()) * t,
+        a.g() + (b.g() - a.g()) * t,
+        a.b() + (b.b() - a.b()) * t,
+        a.a() + (b.a() - a.a()) * t,
+    )
+}
+
+/// Composite a premultiplied ```src``` onto ```dst``` with full coverage
+pub(crate) fn composite(src : RGBA<f32>, dst : RGBA<f32>, mode : BlendMode) -> RGBA<f32> {
+    if !mode.has_blend_function() {
+        return src.blend(dst, mode);
+    }
+    let sa = src.a();
+    let da = dst.a();
+    let b = if sa > 0.0 && da > 0.0 {
+        mode.blend_color(
+            RGB::new(dst.r() / da, dst.g() / da, dst.b() / da),
+            RGB::new(src.r() / sa, src.g() / sa, src.b() / sa),
+        )
+    } else {
+        RGB::new(0.0, 0.0, 0.0)
+    };
+    let channel = |cs : f32, cb : f32, b : f32| cs * (1.0 - da) + cb * (1.0 - sa) + sa * da * b;
+    RGBA::new(
+        channel(src.r(), dst.r(), b.r()),
+        channel(src.g(), dst.g(), b.g()),
+        channel(src.b(), dst.b(), b.b()),
+        sa + da * (1.0 - sa),
+    )
+}
+
+/// The size of the canvas shared by every node
+#[derive(Copy,Clone)]
+struct Canvas {
+    width : usize,
+    height : usize,
+}
+
+impl Canvas {
+    fn transparent(self) -> Vec<RGBA<f32>> {
+        vec![RGBA::new(0.0, 0.0, 0.0, 0.0); self.width * self.height]
+    }
+
+    /// Call ```f(canvas index, layer index)``` for every pixel of the layer inside the canvas
+    fn for_each_pixel<F : FnMut(usize, usize)>(self, layer : &Layer, mut f : F) {
+        let (ox, oy) = (layer.offset.0 as i64, layer.offset.1 as i64);
+        for ly in 0..layer.height {
+            let y = oy + ly as i64;
+            if y < 0 || y >= self.height as i64 {
+                continue;
+            }
+            for lx in 0..layer.width {
+                let x = ox + lx as i64;
+                if x < 0 || x >= self.width as i64 {
+                    continue;
+                }
+                f(y as usize * self.width + x as usize, ly * layer.width + lx);
+            }
+        }
+    }
+
+    fn composite_nodes(self, nodes : &[Node], canvas : &mut [RGBA<f32>]) {
+        // the coverage of the current clipping base
+        let mut base : Option<Vec<f32>> = None;
+        for (i, node) in nodes.iter().enumerate() {
+            if !node.clip() {
+                let clipped_above = nodes.get(i + 1).is_some_and(|next| next.clip());
+                base = if clipped_above { Some(self.coverage(node)) } else { None };
+            }
+            let clip = if node.clip() { base.as_deref() } else { None };
+            if !node.visible() {
+                continue;
+            }
+            match node {
+                Node::Layer(layer) => self.composite_layer(layer, clip, canvas),
+                Node::Group(group) => self.composite_group(group, clip, canvas),
+            }
+        }
+    }
+
+    fn composite_layer(self, layer : &Layer, clip : Option<&[f32]>, canvas : &mut [RGBA<f32>]) {
+        self.for_each_pixel(layer, |c, l| {
+            let mut coverage = layer.opacity;
+            if let Some(mask) = &layer.mask {
+                coverage *= mask[l];
+            }
+            if let Some(clip) = clip {
+                coverage *= clip[c];
+            }
+            if coverage > 0.0 {
+                canvas[c] = lerp(canvas[c], composite(layer.pixels[l], canvas[c], layer.mode), coverage);
+            }
+        });
+    }
+
+    fn composite_group(self, group : &Group, clip : Option<&[f32]>, canvas : &mut [RGBA<f32>]) {
+        let coverage = |c : usize| group.opacity * clip.map_or(1.0, |clip| clip[c]);
+        if group.isolated {
+            let mut inner = self.transparent();
+            self.composite_nodes(&group.children, &mut inner);
+            for (c, (dst, src)) in canvas.iter_mut().zip(inner).enumerate() {
+                *dst = lerp(*dst, composite(src, *dst, group.mode), coverage(c));
+            }
+        } else {
+            let backdrop = canvas.to_vec();
+            self.composite_nodes(&group.children, canvas);
+            for (c, (dst, before)) in canvas.iter_mut().zip(backdrop).enumerate() {
+                *dst = lerp(before, *dst, coverage(c));
+            }
+        }
+    }
+
+    /// The alpha that a node contributes on its own, used as a clipping base
+    fn coverage(self, node : &Node) -> Vec<f32> {
+        let mut coverage = vec![0.0; self.width * self.height];
+        if !node.visible() {
+            return coverage;
+        }
+        match node {
+            Node::Layer(layer) => {
+                self.for_each_pixel(layer, |c, l| {
+                    let mask = layer.mask.as_ref().map_or(1.0, |mask| mask[l]);
+                    coverage[c] = layer.pixels[l].a() * layer.opacity * mask;
+                });
+            }
+            Node::Group(group) => {
+                let mut inner = self.transparent();
+                self.composite_nodes(&group.children, &mut inner);
+                for (c, p) in coverage.iter_mut().zip(inner) {
+                    *c = p.a() * group.opacity;
+                }
+            }
+        }
+        coverage
+    }
+}
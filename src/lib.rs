@@ -42,7 +42,28 @@
 //! ```
 mod rgba;
 mod rgb;
+mod literal;
+mod simd;
+pub mod adjust;
 pub mod blend;
+pub mod cast;
+pub mod contrast;
+pub mod css;
+pub mod cvd;
+pub mod difference;
+pub mod dither;
+pub mod extract;
+pub mod gradient;
+pub mod image;
+pub mod layer;
+pub mod mix;
+pub mod named;
+pub mod palette;
+pub mod pdf;
+pub mod quantize;
+pub mod space;
+pub mod stats;
+pub mod tween;
 
 /// A marker that represents the type of the inner value of RGB/RGBA
 pub trait InnerType : Copy {}
@@ -51,4 +72,6 @@ impl InnerType for f32{}
 
 pub use rgba::*;
 pub use rgb::*;
+#[doc(hidden)]
+pub use literal::Literal as __Literal;
 
@@ -0,0 +1,66 @@
+//! Compile-time parsing of the literals in rgb! and rgba!
+use crate::{RGB, RGBA};
+
+/// A literal passed to rgb! or rgba!, evaluated in a const item so that an invalid literal fails the build.
+pub struct Literal<T>(pub T);
+
+impl Literal<u32>{
+    pub const fn rgb(self) -> RGB<u8>{
+        RGB::from_u32(self.0)
+    }
+
+    pub const fn rgba(self) -> RGBA<u8>{
+        RGBA::from_u32(self.0)
+    }
+}
+
+impl Literal<&str>{
+    pub const fn rgb(self) -> RGB<u8>{
+        let (c, has_alpha) = parse_hex(self.0);
+        if has_alpha {
+            panic!("an RGB color literal must not have an alpha component");
+        }
+        RGB::new(c[0], c[1], c[2])
+    }
+
+    pub const fn rgba(self) -> RGBA<u8>{
+        let (c, _) = parse_hex(self.0);
+        RGBA::new(c[0], c[1], c[2], c[3])
+    }
+}
+
+const fn hex_digit(b : u8) -> u8 {
+    match b {
+        b'0'..=b'9' => b - b'0',
+        b'a'..=b'f' => b - b'a' + 10,
+        b'A'..=b'F' => b - b'A' + 10,
+        _ => panic!("invalid hex digit in a color literal"),
+    }
+}
+
+/// Parse ```#rgb```, ```#rgba```, ```#rrggbb``` or ```#rrggbbaa```,
+/// and return the components and whether the alpha was given
+const fn parse_hex(s : &str) -> ([u8; 4], bool) {
+    let s = s.as_bytes();
+    if s.is_empty() || s[0] != b'#' {
+        panic!("a color literal must start with '#'");
+    }
+    let mut c = [0, 0, 0, 255];
+    let mut i = 0;
+    match s.len() {
+        4 | 5 => {
+            while i + 1 < s.len() {
+                c[i] = hex_digit(s[i + 1]) * 17;
+                i += 1;
+            }
+        }
+        7 | 9 => {
+            while 2 * i + 1 < s.len() {
+                c[i] = hex_digit(s[2 * i + 1]) << 4 | hex_digit(s[2 * i + 2]);
+                i += 1;
+            }
+        }
+        _ => panic!("a color literal must have 3, 4, 6 or 8 hex digits"),
+    }
+    (c, s.len() == 5 || s.len() == 9)
+}
@@ -0,0 +1,197 @@
+//! # Color interpolation
+//! Interpolate between colors in a [Space] of the [gradient](crate::gradient) module. <br>
+//! Colors have a straight alpha and are interpolated premultiplied, like CSS does.
+//! Interpolating in Oklab or Oklch avoids the dark and gray midpoints of sRGB.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::gradient::Space;
+//! use xblend::mix::mix;
+//! let yellow = rgba!(1.0,1.0,0.0,1.0);
+//! let blue = rgba!(0.0,0.0,1.0,1.0);
+//! // a gray in sRGB, a lighter color in Oklab
+//! let srgb = mix(yellow, blue, 0.5, Space::Srgb);
+//! assert_eq!(srgb, rgba!(0.5,0.5,0.5,1.0));
+//! let oklab = mix(yellow, blue, 0.5, Space::Oklab);
+//! assert!(oklab.r() + oklab.g() + oklab.b() > 1.5);
+//! ```
+use crate::{RGB, RGBA};
+use crate::gradient::{fix_hues, Space};
+
+impl RGB<f32>{
+    /// Interpolate every component linearly, ```t = 0``` gives ```self```.
+    pub fn lerp(&self, other : &RGB<f32>, t : f32) -> RGB<f32> {
+        let lerp = |a : f32, b : f32| a + (b - a) * t;
+        RGB::new(lerp(self.r(), other.r()), lerp(self.g(), other.g()), lerp(self.b(), other.b()))
+    }
+}
+
+impl RGB<u8>{
+    /// Interpolate every component linearly and round, ```t = 0``` gives ```self```.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// assert_eq!(rgb!(0,100,255).lerp(&rgb!(255,200,0), 0.5), rgb!(128,150,128));
+    /// ```
+    pub fn lerp(&self, other : &RGB<u8>, t : f32) -> RGB<u8> {
+        let lerp = |a : u8, b : u8| (a as f32 + (b as f32 - a as f32) * t).round().clamp(0.0, 255.0) as u8;
+        RGB::new(lerp(self.r(), other.r()), lerp(self.g(), other.g()), lerp(self.b(), other.b()))
+    }
+}
+
+impl RGBA<f32>{
+    /// Interpolate every component linearly, alpha included, ```t = 0``` gives ```self```. <br>
+    /// On premultiplied colors this is a premultiplied interpolation,
+    /// on straight colors use [mix] instead.
+    pub fn lerp(&self, other : &RGBA<f32>, t : f32) -> RGBA<f32> {
+        let lerp = |a : f32, b : f32| a + (b - a) * t;
+        RGBA::new(lerp(self.r(), other.r()), lerp(self.g(), other.g()), lerp(self.b(), other.b()), lerp(self.a(), other.a()))
+    }
+}
+
+impl RGBA<u8>{
+    /// Interpolate every component linearly and round, alpha included, ```t = 0``` gives ```self```.
+    pub fn lerp(&self, other : &RGBA<u8>, t : f32) -> RGBA<u8> {
+        let lerp = |a : u8, b : u8| (a as f32 + (b as f32 - a as f32) * t).round().clamp(0.0, 255.0) as u8;
+        RGBA::new(lerp(self.r(), other.r()), lerp(self.g(), other.g()), lerp(self.b(), other.b()), lerp(self.a(), other.a()))
+    }
+}
+
+/// Interpolate two colors in a space with premultiplied alpha, ```t = 0``` gives ```a```. <br>
+/// The result is clamped to the sRGB gamut.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::gradient::Space;
+/// use xblend::mix::mix;
+/// // the transparent white does not lighten the red
+/// let c = mix(rgba!(255,0,0,255), rgba!(255,255,255,0), 0.5, Space::Srgb);
+/// assert_eq!(c, rgba!(1.0,0.0,0.0,0.5));
+/// ```
+pub fn mix<A : Into<RGBA<f32>>, B : Into<RGBA<f32>>>(a : A, b : B, t : f32, space : Space) -> RGBA<f32> {
+    let (a, b) = (a.into(), b.into());
+    space.mix((space.components(a.rgb()), a.a()), (space.components(b.rgb()), b.a()), t)
+}
+
+/// Mix two colors like CSS ```color-mix()```, with optional percentages in 0..=100. <br>
+/// A missing percentage is 100 minus the other one, or 50 if both are missing.
+/// Percentages are scaled so that they sum to 100, and if their sum was below 100
+/// the alpha of the result is multiplied by it. <br>
+/// Return None if a percentage is out of range or both are 0.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::gradient::Space;
+/// use xblend::mix::{color_mix, mix};
+/// let red = rgba!(1.0,0.0,0.0,1.0);
+/// let blue = rgba!(0.0,0.0,1.0,1.0);
+/// // color-mix(in oklab, red 25%, blue)
+/// assert_eq!(color_mix(red, Some(25.0), blue, None, Space::Oklab), Some(mix(red, blue, 0.75, Space::Oklab)));
+/// // color-mix(in srgb, red 30%, blue 30%) is half transparent
+/// assert_eq!(color_mix(red, Some(30.0), blue, Some(30.0), Space::Srgb), Some(rgba!(0.5,0.0,0.5,0.6)));
+/// assert_eq!(color_mix(red, Some(0.0), blue, Some(0.0), Space::Srgb), None);
+/// ```
+pub fn color_mix<A, B>(a : A, pa : Option<f32>, b : B, pb : Option<f32>, space : Space) -> Option<RGBA<f32>>
+    where A : Into<RGBA<f32>>, B : Into<RGBA<f32>> {
+    let (pa, pb) = match (pa, pb) {
+        (None, None) => (50.0, 50.0),
+        (Some(pa), None) => (pa, 100.0 - pa),
+        (None, Some(pb)) => (100.0 - pb, pb),
+        (Some(pa), Some(pb)) => (pa, pb),
+    };
+    if !(0.0..=100.0).contains(&pa) || !(0.0..=100.0).contains(&pb) || pa + pb == 0.0 {
+        return None;
+    }
+    let sum = pa + pb;
+    let color = mix(a, b, pb / sum, space);
+    let alpha = if sum < 100.0 { color.a() * sum / 100.0 } else { color.a() };
+    Some(RGBA::new(color.r(), color.g(), color.b(), alpha))
+}
+
+/// The uniform Catmull-Rom basis between v1 and v2
+fn catmull_rom_basis(t : f32, [v0, v1, v2, v3] : [f32; 4]) -> f32 {
+    let (t2, t3) = (t * t, t * t * t);
+    0.5 * (2.0 * v1 + (v2 - v0) * t + (2.0 * v0 - 5.0 * v1 + 4.0 * v2 - v3) * t2 + (3.0 * v1 - v0 - 3.0 * v2 + v3) * t3)
+}
+
+/// The uniform cubic B-spline basis
+fn b_spline_basis(t : f32, [v0, v1, v2, v3] : [f32; 4]) -> f32 {
+    let (t2, t3) = (t * t, t * t * t);
+    ((1.0 - 3.0 * t + 3.0 * t2 - t3) * v0 + (4.0 - 6.0 * t2 + 3.0 * t3) * v1
+        + (1.0 + 3.0 * t + 3.0 * t2 - 3.0 * t3) * v2 + t3 * v3) / 6.0
+}
+
+/// Interpolate evenly spaced colors with a cubic basis.
+/// The points before the first color and after the last one are extrapolated linearly.
+fn spline<C : Copy + Into<RGBA<f32>>>(colors : &[C], t : f32, space : Space, basis : fn(f32, [f32; 4]) -> f32) -> RGBA<f32> {
+    match colors.len() {
+        0 => return RGBA::new(0.0, 0.0, 0.0, 0.0),
+        1 => return colors[0].into(),
+        _ => {}
+    }
+    // premultiplied components and alpha, the hue is not premultiplied
+    let cylindrical = matches!(space, Space::Oklch(_));
+    let mut points : Vec<[f32; 4]> = colors.iter().map(|&c| {
+        let c = c.into();
+        let [x, y, z] = space.components(c.rgb());
+        [x * c.a(), y * c.a(), if cylindrical { z } else { z * c.a() }, c.a()]
+    }).collect();
+    if let Space::Oklch(method) = space {
+        // a powerless hue takes the hue of the nearest color that has one
+        let hues : Vec<(usize, f32)> = points.iter().enumerate().filter(|p| !p.1[2].is_nan()).map(|(i, p)| (i, p[2])).collect();
+        for (i, p) in points.iter_mut().enumerate() {
+            if p[2].is_nan() {
+                p[2] = hues.iter().min_by_key(|h| h.0.abs_diff(i)).map_or(0.0, |h| h.1);
+            }
+        }
+        for i in 1..points.len() {
+            let (h0, h1) = fix_hues(points[i - 1][2].rem_euclid(360.0), points[i][2], method);
+            points[i][2] = points[i - 1][2] + h1 - h0;
+        }
+    }
+    let n = points.len() - 1;
+    let t = t.clamp(0.0, 1.0) * n as f32;
+    let i = (t as usize).min(n - 1);
+    let (p1, p2) = (points[i], points[i + 1]);
+    let p0 = if i > 0 { points[i - 1] } else { [0, 1, 2, 3].map(|k| 2.0 * p1[k] - p2[k]) };
+    let p3 = if i + 2 <= n { points[i + 2] } else { [0, 1, 2, 3].map(|k| 2.0 * p2[k] - p1[k]) };
+    let v = [0, 1, 2, 3].map(|k| basis(t - i as f32, [p0[k], p1[k], p2[k], p3[k]]));
+    let a = v[3].clamp(0.0, 1.0);
+    let unpremultiply = |x : f32| if a > 0.0 { x / a } else { 0.0 };
+    let hue = if cylindrical { v[2].rem_euclid(360.0) } else { unpremultiply(v[2]) };
+    let rgb = space.color([unpremultiply(v[0]), unpremultiply(v[1]), hue]);
+    RGBA::new(rgb.r().clamp(0.0, 1.0), rgb.g().clamp(0.0, 1.0), rgb.b().clamp(0.0, 1.0), a)
+}
+
+/// Interpolate evenly spaced colors with a Catmull-Rom spline, which passes through every color.
+/// ```t``` goes from 0 at the first color to 1 at the last one.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::gradient::Space;
+/// use xblend::mix::catmull_rom;
+/// let colors = [rgba!(255,0,0,255), rgba!(255,255,0,255), rgba!(0,0,255,255)];
+/// let close = |a : RGBA<f32>, b : RGBA<f32>| (a.r() - b.r()).abs() + (a.g() - b.g()).abs() + (a.b() - b.b()).abs() < 1e-4;
+/// assert!(close(catmull_rom(&colors, 0.5, Space::Oklab), rgba!(1.0,1.0,0.0,1.0)));
+/// assert!(close(catmull_rom(&colors, 1.0, Space::Oklab), rgba!(0.0,0.0,1.0,1.0)));
+/// ```
+pub fn catmull_rom<C : Copy + Into<RGBA<f32>>>(colors : &[C], t : f32, space : Space) -> RGBA<f32> {
+    spline(colors, t, space, catmull_rom_basis)
+}
+
+/// Interpolate evenly spaced colors with a uniform cubic B-spline,
+/// which is smoother and passes through the first and the last colors only.
+/// ```t``` goes from 0 at the first color to 1 at the last one.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::gradient::Space;
+/// use xblend::mix::b_spline;
+/// let colors = [rgba!(0,0,0,255), rgba!(255,255,255,255), rgba!(0,0,0,255)];
+/// let middle = b_spline(&colors, 0.5, Space::Srgb);
+/// assert!((middle.r() - 2.0 / 3.0).abs() < 1e-6);
+/// assert_eq!(b_spline(&colors, 0.0, Space::Srgb), rgba!(0.0,0.0,0.0,1.0));
+/// ```
+pub fn b_spline<C : Copy + Into<RGBA<f32>>>(colors : &[C], t : f32, space : Space) -> RGBA<f32> {
+    spline(colors, t, space, b_spline_basis)
+}
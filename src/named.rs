@@ -0,0 +1,35 @@
+//! # Named colors
+//! Constants for the [CSS](css) and [X11](x11) color names, with case-insensitive lookup by name
+//! and reverse lookup from a color to its name.
+use std::sync::OnceLock;
+use crate::{RGB, RGBA};
+use crate::palette::{Metric, Palette};
+
+pub mod css;
+pub mod x11;
+
+fn lookup(colors : &[(&str, RGBA<u8>)], name : &str) -> Option<RGBA<u8>> {
+    colors.iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|&(_, color)| color)
+}
+
+fn name_of(colors : &'static [(&'static str, RGBA<u8>)], color : RGBA<u8>) -> Option<&'static str> {
+    colors.iter()
+        .find(|&&(_, c)| c == color)
+        .map(|&(n, _)| n)
+}
+
+/// The palettes of a color set for every metric, built on the first query
+type Palettes = [OnceLock<Palette>; 3];
+
+fn nearest(colors : &'static [(&'static str, RGBA<u8>)], palettes : &Palettes, color : RGB<f32>, metric : Metric) -> (&'static str, RGBA<u8>) {
+    let slot = match metric {
+        Metric::Rgb => 0,
+        Metric::DeltaE2000 => 1,
+        Metric::DeltaEOk => 2,
+    };
+    let palette = palettes[slot].get_or_init(|| Palette::new(colors.iter().map(|(_, c)| c.rgb()), metric));
+    let nearest = palette.nearest(color).expect("color sets are not empty");
+    colors[nearest.index]
+}
@@ -0,0 +1,638 @@
+//! The 148 named colors of CSS Color Level 4.<br>
+//! SVG 1.1 uses the same keywords without ```rebeccapurple```.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::named::css;
+//! assert_eq!(css::REBECCA_PURPLE, rgba!("#663399"));
+//! assert_eq!(css::lookup("DarkSlateGray"), Some(css::DARK_SLATE_GRAY));
+//! assert_eq!(css::name_of(rgba!(255,0,0,255)), Some("red"));
+//! // aqua and cyan are the same color, the first name in alphabetical order is returned
+//! assert_eq!(css::name_of(css::CYAN), Some("aqua"));
+//! assert_eq!(css::name_of(rgba!(255,0,0,128)), None);
+//! ```
+//! The nearest name of any color:
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::named::css;
+//! use xblend::palette::Metric;
+//! assert_eq!(css::nearest(rgb!(250,10,5), Metric::DeltaEOk).0, "red");
+//! assert_eq!(css::nearest(rgb!(0x00FFFF), Metric::DeltaE2000), ("aqua", css::CYAN));
+//! ```
+use std::sync::OnceLock;
+use crate::{RGB, RGBA};
+use crate::palette::Metric;
+
+/// Find a color by name, ignoring the case.
+pub fn lookup(name : &str) -> Option<RGBA<u8>> {
+    super::lookup(COLORS, name)
+}
+
+/// Find the name of a color, the color must be opaque and match exactly.
+pub fn name_of(color : RGBA<u8>) -> Option<&'static str> {
+    super::name_of(COLORS, color)
+}
+
+static PALETTES : super::Palettes = [OnceLock::new(), OnceLock::new(), OnceLock::new()];
+
+/// Find the nearest named color with a metric, the first name in alphabetical order wins a tie.
+pub fn nearest<C : Into<RGB<f32>>>(color : C, metric : Metric) -> (&'static str, RGBA<u8>) {
+    super::nearest(COLORS, &PALETTES, color.into(), metric)
+}
+
+/// ```aliceblue``` ```#F0F8FF```
+pub const ALICE_BLUE : RGBA<u8> = RGBA::from_u32(0xF0F8FFFF);
+
+/// ```antiquewhite``` ```#FAEBD7```
+pub const ANTIQUE_WHITE : RGBA<u8> = RGBA::from_u32(0xFAEBD7FF);
+
+/// ```aqua``` ```#00FFFF```
+pub const AQUA : RGBA<u8> = RGBA::from_u32(0x00FFFFFF);
+
+/// ```aquamarine``` ```#7FFFD4```
+pub const AQUAMARINE : RGBA<u8> = RGBA::from_u32(0x7FFFD4FF);
+
+/// ```azure``` ```#F0FFFF```
+pub const AZURE : RGBA<u8> = RGBA::from_u32(0xF0FFFFFF);
+
+/// ```beige``` ```#F5F5DC```
+pub const BEIGE : RGBA<u8> = RGBA::from_u32(0xF5F5DCFF);
+
+/// ```bisque``` ```#FFE4C4```
+pub const BISQUE : RGBA<u8> = RGBA::from_u32(0xFFE4C4FF);
+
+/// ```black``` ```#000000```
+pub const BLACK : RGBA<u8> = RGBA::from_u32(0x000000FF);
+
+/// ```blanchedalmond``` ```#FFEBCD```
+pub const BLANCHED_ALMOND : RGBA<u8> = RGBA::from_u32(0xFFEBCDFF);
+
+/// ```blue``` ```#0000FF```
+pub const BLUE : RGBA<u8> = RGBA::from_u32(0x0000FFFF);
+
+/// ```blueviolet``` ```#8A2BE2```
+pub const BLUE_VIOLET : RGBA<u8> = RGBA::from_u32(0x8A2BE2FF);
+
+/// ```brown``` ```#A52A2A```
+pub const BROWN : RGBA<u8> = RGBA::from_u32(0xA52A2AFF);
+
+/// ```burlywood``` ```#DEB887```
+pub const BURLYWOOD : RGBA<u8> = RGBA::from_u32(0xDEB887FF);
+
+/// ```cadetblue``` ```#5F9EA0```
+pub const CADET_BLUE : RGBA<u8> = RGBA::from_u32(0x5F9EA0FF);
+
+/// ```chartreuse``` ```#7FFF00```
+pub const CHARTREUSE : RGBA<u8> = RGBA::from_u32(0x7FFF00FF);
+
+/// ```chocolate``` ```#D2691E```
+pub const CHOCOLATE : RGBA<u8> = RGBA::from_u32(0xD2691EFF);
+
+/// ```coral``` ```#FF7F50```
+pub const CORAL : RGBA<u8> = RGBA::from_u32(0xFF7F50FF);
+
+/// ```cornflowerblue``` ```#6495ED```
+pub const CORNFLOWER_BLUE : RGBA<u8> = RGBA::from_u32(0x6495EDFF);
+
+/// ```cornsilk``` ```#FFF8DC```
+pub const CORNSILK : RGBA<u8> = RGBA::from_u32(0xFFF8DCFF);
+
+/// ```crimson``` ```#DC143C```
+pub const CRIMSON : RGBA<u8> = RGBA::from_u32(0xDC143CFF);
+
+/// ```cyan``` ```#00FFFF```
+pub const CYAN : RGBA<u8> = RGBA::from_u32(0x00FFFFFF);
+
+/// ```darkblue``` ```#00008B```
+pub const DARK_BLUE : RGBA<u8> = RGBA::from_u32(0x00008BFF);
+
+/// ```darkcyan``` ```#008B8B```
+pub const DARK_CYAN : RGBA<u8> = RGBA::from_u32(0x008B8BFF);
+
+/// ```darkgoldenrod``` ```#B8860B```
+pub const DARK_GOLDENROD : RGBA<u8> = RGBA::from_u32(0xB8860BFF);
+
+/// ```darkgray``` ```#A9A9A9```
+pub const DARK_GRAY : RGBA<u8> = RGBA::from_u32(0xA9A9A9FF);
+
+/// ```darkgreen``` ```#006400```
+pub const DARK_GREEN : RGBA<u8> = RGBA::from_u32(0x006400FF);
+
+/// ```darkgrey``` ```#A9A9A9```
+pub const DARK_GREY : RGBA<u8> = RGBA::from_u32(0xA9A9A9FF);
+
+/// ```darkkhaki``` ```#BDB76B```
+pub const DARK_KHAKI : RGBA<u8> = RGBA::from_u32(0xBDB76BFF);
+
+/// ```darkmagenta``` ```#8B008B```
+pub const DARK_MAGENTA : RGBA<u8> = RGBA::from_u32(0x8B008BFF);
+
+/// ```darkolivegreen``` ```#556B2F```
+pub const DARK_OLIVE_GREEN : RGBA<u8> = RGBA::from_u32(0x556B2FFF);
+
+/// ```darkorange``` ```#FF8C00```
+pub const DARK_ORANGE : RGBA<u8> = RGBA::from_u32(0xFF8C00FF);
+
+/// ```darkorchid``` ```#9932CC```
+pub const DARK_ORCHID : RGBA<u8> = RGBA::from_u32(0x9932CCFF);
+
+/// ```darkred``` ```#8B0000```
+pub const DARK_RED : RGBA<u8> = RGBA::from_u32(0x8B0000FF);
+
+/// ```darksalmon``` ```#E9967A```
+pub const DARK_SALMON : RGBA<u8> = RGBA::from_u32(0xE9967AFF);
+
+/// ```darkseagreen``` ```#8FBC8F```
+pub const DARK_SEA_GREEN : RGBA<u8> = RGBA::from_u32(0x8FBC8FFF);
+
+/// ```darkslateblue``` ```#483D8B```
+pub const DARK_SLATE_BLUE : RGBA<u8> = RGBA::from_u32(0x483D8BFF);
+
+/// ```darkslategray``` ```#2F4F4F```
+pub const DARK_SLATE_GRAY : RGBA<u8> = RGBA::from_u32(0x2F4F4FFF);
+
+/// ```darkslategrey``` ```#2F4F4F```
+pub const DARK_SLATE_GREY : RGBA<u8> = RGBA::from_u32(0x2F4F4FFF);
+
+/// ```darkturquoise``` ```#00CED1```
+pub const DARK_TURQUOISE : RGBA<u8> = RGBA::from_u32(0x00CED1FF);
+
+/// ```darkviolet``` ```#9400D3```
+pub const DARK_VIOLET : RGBA<u8> = RGBA::from_u32(0x9400D3FF);
+
+/// ```deeppink``` ```#FF1493```
+pub const DEEP_PINK : RGBA<u8> = RGBA::from_u32(0xFF1493FF);
+
+/// ```deepskyblue``` ```#00BFFF```
+pub const DEEP_SKY_BLUE : RGBA<u8> = RGBA::from_u32(0x00BFFFFF);
+
+/// ```dimgray``` ```#696969```
+pub const DIM_GRAY : RGBA<u8> = RGBA::from_u32(0x696969FF);
+
+/// ```dimgrey``` ```#696969```
+pub const DIM_GREY : RGBA<u8> = RGBA::from_u32(0x696969FF);
+
+/// ```dodgerblue``` ```#1E90FF```
+pub const DODGER_BLUE : RGBA<u8> = RGBA::from_u32(0x1E90FFFF);
+
+/// ```firebrick``` ```#B22222```
+pub const FIREBRICK : RGBA<u8> = RGBA::from_u32(0xB22222FF);
+
+/// ```floralwhite``` ```#FFFAF0```
+pub const FLORAL_WHITE : RGBA<u8> = RGBA::from_u32(0xFFFAF0FF);
+
+/// ```forestgreen``` ```#228B22```
+pub const FOREST_GREEN : RGBA<u8> = RGBA::from_u32(0x228B22FF);
+
+/// ```fuchsia``` ```#FF00FF```
+pub const FUCHSIA : RGBA<u8> = RGBA::from_u32(0xFF00FFFF);
+
+/// ```gainsboro``` ```#DCDCDC```
+pub const GAINSBORO : RGBA<u8> = RGBA::from_u32(0xDCDCDCFF);
+
+/// ```ghostwhite``` ```#F8F8FF```
+pub const GHOST_WHITE : RGBA<u8> = RGBA::from_u32(0xF8F8FFFF);
+
+/// ```gold``` ```#FFD700```
+pub const GOLD : RGBA<u8> = RGBA::from_u32(0xFFD700FF);
+
+/// ```goldenrod``` ```#DAA520```
+pub const GOLDENROD : RGBA<u8> = RGBA::from_u32(0xDAA520FF);
+
+/// ```gray``` ```#808080```
+pub const GRAY : RGBA<u8> = RGBA::from_u32(0x808080FF);
+
+/// ```green``` ```#008000```
+pub const GREEN : RGBA<u8> = RGBA::from_u32(0x008000FF);
+
+/// ```greenyellow``` ```#ADFF2F```
+pub const GREEN_YELLOW : RGBA<u8> = RGBA::from_u32(0xADFF2FFF);
+
+/// ```grey``` ```#808080```
+pub const GREY : RGBA<u8> = RGBA::from_u32(0x808080FF);
+
+/// ```honeydew``` ```#F0FFF0```
+pub const HONEYDEW : RGBA<u8> = RGBA::from_u32(0xF0FFF0FF);
+
+/// ```hotpink``` ```#FF69B4```
+pub const HOT_PINK : RGBA<u8> = RGBA::from_u32(0xFF69B4FF);
+
+/// ```indianred``` ```#CD5C5C```
+pub const INDIAN_RED : RGBA<u8> = RGBA::from_u32(0xCD5C5CFF);
+
+/// ```indigo``` ```#4B0082```
+pub const INDIGO : RGBA<u8> = RGBA::from_u32(0x4B0082FF);
+
+/// ```ivory``` ```#FFFFF0```
+pub const IVORY : RGBA<u8> = RGBA::from_u32(0xFFFFF0FF);
+
+/// ```khaki``` ```#F0E68C```
+pub const KHAKI : RGBA<u8> = RGBA::from_u32(0xF0E68CFF);
+
+/// ```lavender``` ```#E6E6FA```
+pub const LAVENDER : RGBA<u8> = RGBA::from_u32(0xE6E6FAFF);
+
+/// ```lavenderblush``` ```#FFF0F5```
+pub const LAVENDER_BLUSH : RGBA<u8> = RGBA::from_u32(0xFFF0F5FF);
+
+/// ```lawngreen``` ```#7CFC00```
+pub const LAWN_GREEN : RGBA<u8> = RGBA::from_u32(0x7CFC00FF);
+
+/// ```lemonchiffon``` ```#FFFACD```
+pub const LEMON_CHIFFON : RGBA<u8> = RGBA::from_u32(0xFFFACDFF);
+
+/// ```lightblue``` ```#ADD8E6```
+pub const LIGHT_BLUE : RGBA<u8> = RGBA::from_u32(0xADD8E6FF);
+
+/// ```lightcoral``` ```#F08080```
+pub const LIGHT_CORAL : RGBA<u8> = RGBA::from_u32(0xF08080FF);
+
+/// ```lightcyan``` ```#E0FFFF```
+pub const LIGHT_CYAN : RGBA<u8> = RGBA::from_u32(0xE0FFFFFF);
+
+/// ```lightgoldenrodyellow``` ```#FAFAD2```
+pub const LIGHT_GOLDENROD_YELLOW : RGBA<u8> = RGBA::from_u32(0xFAFAD2FF);
+
+/// ```lightgray``` ```#D3D3D3```
+pub const LIGHT_GRAY : RGBA<u8> = RGBA::from_u32(0xD3D3D3FF);
+
+/// ```lightgreen``` ```#90EE90```
+pub const LIGHT_GREEN : RGBA<u8> = RGBA::from_u32(0x90EE90FF);
+
+/// ```lightgrey``` ```#D3D3D3```
+pub const LIGHT_GREY : RGBA<u8> = RGBA::from_u32(0xD3D3D3FF);
+
+/// ```lightpink``` ```#FFB6C1```
+pub const LIGHT_PINK : RGBA<u8> = RGBA::from_u32(0xFFB6C1FF);
+
+/// ```lightsalmon``` ```#FFA07A```
+pub const LIGHT_SALMON : RGBA<u8> = RGBA::from_u32(0xFFA07AFF);
+
+/// ```lightseagreen``` ```#20B2AA```
+pub const LIGHT_SEA_GREEN : RGBA<u8> = RGBA::from_u32(0x20B2AAFF);
+
+/// ```lightskyblue``` ```#87CEFA```
+pub const LIGHT_SKY_BLUE : RGBA<u8> = RGBA::from_u32(0x87CEFAFF);
+
+/// ```lightslategray``` ```#778899```
+pub const LIGHT_SLATE_GRAY : RGBA<u8> = RGBA::from_u32(0x778899FF);
+
+/// ```lightslategrey``` ```#778899```
+pub const LIGHT_SLATE_GREY : RGBA<u8> = RGBA::from_u32(0x778899FF);
+
+/// ```lightsteelblue``` ```#B0C4DE```
+pub const LIGHT_STEEL_BLUE : RGBA<u8> = RGBA::from_u32(0xB0C4DEFF);
+
+/// ```lightyellow``` ```#FFFFE0```
+pub const LIGHT_YELLOW : RGBA<u8> = RGBA::from_u32(0xFFFFE0FF);
+
+/// ```lime``` ```#00FF00```
+pub const LIME : RGBA<u8> = RGBA::from_u32(0x00FF00FF);
+
+/// ```limegreen``` ```#32CD32```
+pub const LIME_GREEN : RGBA<u8> = RGBA::from_u32(0x32CD32FF);
+
+/// ```linen``` ```#FAF0E6```
+pub const LINEN : RGBA<u8> = RGBA::from_u32(0xFAF0E6FF);
+
+/// ```magenta``` ```#FF00FF```
+pub const MAGENTA : RGBA<u8> = RGBA::from_u32(0xFF00FFFF);
+
+/// ```maroon``` ```#800000```
+pub const MAROON : RGBA<u8> = RGBA::from_u32(0x800000FF);
+
+/// ```mediumaquamarine``` ```#66CDAA```
+pub const MEDIUM_AQUAMARINE : RGBA<u8> = RGBA::from_u32(0x66CDAAFF);
+
+/// ```mediumblue``` ```#0000CD```
+pub const MEDIUM_BLUE : RGBA<u8> = RGBA::from_u32(0x0000CDFF);
+
+/// ```mediumorchid``` ```#BA55D3```
+pub const MEDIUM_ORCHID : RGBA<u8> = RGBA::from_u32(0xBA55D3FF);
+
+/// ```mediumpurple``` ```#9370DB```
+pub const MEDIUM_PURPLE : RGBA<u8> = RGBA::from_u32(0x9370DBFF);
+
+/// ```mediumseagreen``` ```#3CB371```
+pub const MEDIUM_SEA_GREEN : RGBA<u8> = RGBA::from_u32(0x3CB371FF);
+
+/// ```mediumslateblue``` ```#7B68EE```
+pub const MEDIUM_SLATE_BLUE : RGBA<u8> = RGBA::from_u32(0x7B68EEFF);
+
+/// ```mediumspringgreen``` ```#00FA9A```
+pub const MEDIUM_SPRING_GREEN : RGBA<u8> = RGBA::from_u32(0x00FA9AFF);
+
+/// ```mediumturquoise``` ```#48D1CC```
+pub const MEDIUM_TURQUOISE : RGBA<u8> = RGBA::from_u32(0x48D1CCFF);
+
+/// ```mediumvioletred``` ```#C71585```
+pub const MEDIUM_VIOLET_RED : RGBA<u8> = RGBA::from_u32(0xC71585FF);
+
+/// ```midnightblue``` ```#191970```
+pub const MIDNIGHT_BLUE : RGBA<u8> = RGBA::from_u32(0x191970FF);
+
+/// ```mintcream``` ```#F5FFFA```
+pub const MINT_CREAM : RGBA<u8> = RGBA::from_u32(0xF5FFFAFF);
+
+/// ```mistyrose``` ```#FFE4E1```
+pub const MISTY_ROSE : RGBA<u8> = RGBA::from_u32(0xFFE4E1FF);
+
+/// ```moccasin``` ```#FFE4B5```
+pub const MOCCASIN : RGBA<u8> = RGBA::from_u32(0xFFE4B5FF);
+
+/// ```navajowhite``` ```#FFDEAD```
+pub const NAVAJO_WHITE : RGBA<u8> = RGBA::from_u32(0xFFDEADFF);
+
+/// ```navy``` ```#000080```
+pub const NAVY : RGBA<u8> = RGBA::from_u32(0x000080FF);
+
+/// ```oldlace``` ```#FDF5E6```
+pub const OLD_LACE : RGBA<u8> = RGBA::from_u32(0xFDF5E6FF);
+
+/// ```olive``` ```#808000```
+pub const OLIVE : RGBA<u8> = RGBA::from_u32(0x808000FF);
+
+/// ```olivedrab``` ```#6B8E23```
+pub const OLIVE_DRAB : RGBA<u8> = RGBA::from_u32(0x6B8E23FF);
+
+/// ```orange``` ```#FFA500```
+pub const ORANGE : RGBA<u8> = RGBA::from_u32(0xFFA500FF);
+
+/// ```orangered``` ```#FF4500```
+pub const ORANGE_RED : RGBA<u8> = RGBA::from_u32(0xFF4500FF);
+
+/// ```orchid``` ```#DA70D6```
+pub const ORCHID : RGBA<u8> = RGBA::from_u32(0xDA70D6FF);
+
+/// ```palegoldenrod``` ```#EEE8AA```
+pub const PALE_GOLDENROD : RGBA<u8> = RGBA::from_u32(0xEEE8AAFF);
+
+/// ```palegreen``` ```#98FB98```
+pub const PALE_GREEN : RGBA<u8> = RGBA::from_u32(0x98FB98FF);
+
+/// ```paleturquoise``` ```#AFEEEE```
+pub const PALE_TURQUOISE : RGBA<u8> = RGBA::from_u32(0xAFEEEEFF);
+
+/// ```palevioletred``` ```#DB7093```
+pub const PALE_VIOLET_RED : RGBA<u8> = RGBA::from_u32(0xDB7093FF);
+
+/// ```papayawhip``` ```#FFEFD5```
+pub const PAPAYA_WHIP : RGBA<u8> = RGBA::from_u32(0xFFEFD5FF);
+
+/// ```peachpuff``` ```#FFDAB9```
+pub const PEACH_PUFF : RGBA<u8> = RGBA::from_u32(0xFFDAB9FF);
+
+/// ```peru``` ```#CD853F```
+pub const PERU : RGBA<u8> = RGBA::from_u32(0xCD853FFF);
+
+/// ```pink``` ```#FFC0CB```
+pub const PINK : RGBA<u8> = RGBA::from_u32(0xFFC0CBFF);
+
+/// ```plum``` ```#DDA0DD```
+pub const PLUM : RGBA<u8> = RGBA::from_u32(0xDDA0DDFF);
+
+/// ```powderblue``` ```#B0E0E6```
+pub const POWDER_BLUE : RGBA<u8> = RGBA::from_u32(0xB0E0E6FF);
+
+/// ```purple``` ```#800080```
+pub const PURPLE : RGBA<u8> = RGBA::from_u32(0x800080FF);
+
+/// ```rebeccapurple``` ```#663399```
+pub const REBECCA_PURPLE : RGBA<u8> = RGBA::from_u32(0x663399FF);
+
+/// ```red``` ```#FF0000```
+pub const RED : RGBA<u8> = RGBA::from_u32(0xFF0000FF);
+
+/// ```rosybrown``` ```#BC8F8F```
+pub const ROSY_BROWN : RGBA<u8> = RGBA::from_u32(0xBC8F8FFF);
+
+/// ```royalblue``` ```#4169E1```
+pub const ROYAL_BLUE : RGBA<u8> = RGBA::from_u32(0x4169E1FF);
+
+/// ```saddlebrown``` ```#8B4513```
+pub const SADDLE_BROWN : RGBA<u8> = RGBA::from_u32(0x8B4513FF);
+
+/// ```salmon``` ```#FA8072```
+pub const SALMON : RGBA<u8> = RGBA::from_u32(0xFA8072FF);
+
+/// ```sandybrown``` ```#F4A460```
+pub const SANDY_BROWN : RGBA<u8> = RGBA::from_u32(0xF4A460FF);
+
+/// ```seagreen``` ```#2E8B57```
+pub const SEA_GREEN : RGBA<u8> = RGBA::from_u32(0x2E8B57FF);
+
+/// ```seashell``` ```#FFF5EE```
+pub const SEASHELL : RGBA<u8> = RGBA::from_u32(0xFFF5EEFF);
+
+/// ```sienna``` ```#A0522D```
+pub const SIENNA : RGBA<u8> = RGBA::from_u32(0xA0522DFF);
+
+/// ```silver``` ```#C0C0C0```
+pub const SILVER : RGBA<u8> = RGBA::from_u32(0xC0C0C0FF);
+
+/// ```skyblue``` ```#87CEEB```
+pub const SKY_BLUE : RGBA<u8> = RGBA::from_u32(0x87CEEBFF);
+
+/// ```slateblue``` ```#6A5ACD```
+pub const SLATE_BLUE : RGBA<u8> = RGBA::from_u32(0x6A5ACDFF);
+
+/// ```slategray``` ```#708090```
+pub const SLATE_GRAY : RGBA<u8> = RGBA::from_u32(0x708090FF);
+
+/// ```slategrey``` ```#708090```
+pub const SLATE_GREY : RGBA<u8> = RGBA::from_u32(0x708090FF);
+
+/// ```snow``` ```#FFFAFA```
+pub const SNOW : RGBA<u8> = RGBA::from_u32(0xFFFAFAFF);
+
+/// ```springgreen``` ```#00FF7F```
+pub const SPRING_GREEN : RGBA<u8> = RGBA::from_u32(0x00FF7FFF);
+
+/// ```steelblue``` ```#4682B4```
+pub const STEEL_BLUE : RGBA<u8> = RGBA::from_u32(0x4682B4FF);
+
+/// ```tan``` ```#D2B48C```
+pub const TAN : RGBA<u8> = RGBA::from_u32(0xD2B48CFF);
+
+/// ```teal``` ```#008080```
+pub const TEAL : RGBA<u8> = RGBA::from_u32(0x008080FF);
+
+/// ```thistle``` ```#D8BFD8```
+pub const THISTLE : RGBA<u8> = RGBA::from_u32(0xD8BFD8FF);
+
+/// ```tomato``` ```#FF6347```
+pub const TOMATO : RGBA<u8> = RGBA::from_u32(0xFF6347FF);
+
+/// ```turquoise``` ```#40E0D0```
+pub const TURQUOISE : RGBA<u8> = RGBA::from_u32(0x40E0D0FF);
+
+/// ```violet``` ```#EE82EE```
+pub const VIOLET : RGBA<u8> = RGBA::from_u32(0xEE82EEFF);
+
+/// ```wheat``` ```#F5DEB3```
+pub const WHEAT : RGBA<u8> = RGBA::from_u32(0xF5DEB3FF);
+
+/// ```white``` ```#FFFFFF```
+pub const WHITE : RGBA<u8> = RGBA::from_u32(0xFFFFFFFF);
+
+/// ```whitesmoke``` ```#F5F5F5```
+pub const WHITE_SMOKE : RGBA<u8> = RGBA::from_u32(0xF5F5F5FF);
+
+/// ```yellow``` ```#FFFF00```
+pub const YELLOW : RGBA<u8> = RGBA::from_u32(0xFFFF00FF);
+
+/// ```yellowgreen``` ```#9ACD32```
+pub const YELLOW_GREEN : RGBA<u8> = RGBA::from_u32(0x9ACD32FF);
+
+/// All the CSS named colors sorted by name
+pub static COLORS : &[(&str, RGBA<u8>)] = &[
+    ("aliceblue", ALICE_BLUE),
+    ("antiquewhite", ANTIQUE_WHITE),
+    ("aqua", AQUA),
+    ("aquamarine", AQUAMARINE),
+    ("azure", AZURE),
+    ("beige", BEIGE),
+    ("bisque", BISQUE),
+    ("black", BLACK),
+    ("blanchedalmond", BLANCHED_ALMOND),
+    ("blue", BLUE),
+    ("blueviolet", BLUE_VIOLET),
+    ("brown", BROWN),
+    ("burlywood", BURLYWOOD),
+    ("cadetblue", CADET_BLUE),
+    ("chartreuse", CHARTREUSE),
+    ("chocolate", CHOCOLATE),
+    ("coral", CORAL),
+    ("cornflowerblue", CORNFLOWER_BLUE),
+    ("cornsilk", CORNSILK),
+    ("crimson", CRIMSON),
+    ("cyan", CYAN),
+    ("darkblue", DARK_BLUE),
+    ("darkcyan", DARK_CYAN),
+    ("darkgoldenrod", DARK_GOLDENROD),
+    ("darkgray", DARK_GRAY),
+    ("darkgreen", DARK_GREEN),
+    ("darkgrey", DARK_GREY),
+    ("darkkhaki", DARK_KHAKI),
+    ("darkmagenta", DARK_MAGENTA),
+    ("darkolivegreen", DARK_OLIVE_GREEN),
+    ("darkorange", DARK_ORANGE),
+    ("darkorchid", DARK_ORCHID),
+    ("darkred", DARK_RED),
+    ("darksalmon", DARK_SALMON),
+    ("darkseagreen", DARK_SEA_GREEN),
+    ("darkslateblue", DARK_SLATE_BLUE),
+    ("darkslategray", DARK_SLATE_GRAY),
+    ("darkslategrey", DARK_SLATE_GREY),
+    ("darkturquoise", DARK_TURQUOISE),
+    ("darkviolet", DARK_VIOLET),
+    ("deeppink", DEEP_PINK),
+    ("deepskyblue", DEEP_SKY_BLUE),
+    ("dimgray", DIM_GRAY),
+    ("dimgrey", DIM_GREY),
+    ("dodgerblue", DODGER_BLUE),
+    ("firebrick", FIREBRICK),
+    ("floralwhite", FLORAL_WHITE),
+    ("forestgreen", FOREST_GREEN),
+    ("fuchsia", FUCHSIA),
+    ("gainsboro", GAINSBORO),
+    ("ghostwhite", GHOST_WHITE),
+    ("gold", GOLD),
+    ("goldenrod", GOLDENROD),
+    ("gray", GRAY),
+    ("green", GREEN),
+    ("greenyellow", GREEN_YELLOW),
+    ("grey", GREY),
+    ("honeydew", HONEYDEW),
+    ("hotpink", HOT_PINK),
+    ("indianred", INDIAN_RED),
+    ("indigo", INDIGO),
+    ("ivory", IVORY),
+    ("khaki", KHAKI),
+    ("lavender", LAVENDER),
+    ("lavenderblush", LAVENDER_BLUSH),
+    ("lawngreen", LAWN_GREEN),
+    ("lemonchiffon", LEMON_CHIFFON),
+    ("lightblue", LIGHT_BLUE),
+    ("lightcoral", LIGHT_CORAL),
+    ("lightcyan", LIGHT_CYAN),
+    ("lightgoldenrodyellow", LIGHT_GOLDENROD_YELLOW),
+    ("lightgray", LIGHT_GRAY),
+    ("lightgreen", LIGHT_GREEN),
+    ("lightgrey", LIGHT_GREY),
+    ("lightpink", LIGHT_PINK),
+    ("lightsalmon", LIGHT_SALMON),
+    ("lightseagreen", LIGHT_SEA_GREEN),
+    ("lightskyblue", LIGHT_SKY_BLUE),
+    ("lightslategray", LIGHT_SLATE_GRAY),
+    ("lightslategrey", LIGHT_SLATE_GREY),
+    ("lightsteelblue", LIGHT_STEEL_BLUE),
+    ("lightyellow", LIGHT_YELLOW),
+    ("lime", LIME),
+    ("limegreen", LIME_GREEN),
+    ("linen", LINEN),
+    ("magenta", MAGENTA),
+    ("maroon", MAROON),
+    ("mediumaquamarine", MEDIUM_AQUAMARINE),
+    ("mediumblue", MEDIUM_BLUE),
+    ("mediumorchid", MEDIUM_ORCHID),
+    ("mediumpurple", MEDIUM_PURPLE),
+    ("mediumseagreen", MEDIUM_SEA_GREEN),
+    ("mediumslateblue", MEDIUM_SLATE_BLUE),
+    ("mediumspringgreen", MEDIUM_SPRING_GREEN),
+    ("mediumturquoise", MEDIUM_TURQUOISE),
+    ("mediumvioletred", MEDIUM_VIOLET_RED),
+    ("midnightblue", MIDNIGHT_BLUE),
+    ("mintcream", MINT_CREAM),
+    ("mistyrose", MISTY_ROSE),
+    ("moccasin", MOCCASIN),
+    ("navajowhite", NAVAJO_WHITE),
+    ("navy", NAVY),
+    ("oldlace", OLD_LACE),
+    ("olive", OLIVE),
+    ("olivedrab", OLIVE_DRAB),
+    ("orange", ORANGE),
+    ("orangered", ORANGE_RED),
+    ("orchid", ORCHID),
+    ("palegoldenrod", PALE_GOLDENROD),
+    ("palegreen", PALE_GREEN),
+    ("paleturquoise", PALE_TURQUOISE),
+    ("palevioletred", PALE_VIOLET_RED),
+    ("papayawhip", PAPAYA_WHIP),
+    ("peachpuff", PEACH_PUFF),
+    ("peru", PERU),
+    ("pink", PINK),
+    ("plum", PLUM),
+    ("powderblue", POWDER_BLUE),
+    ("purple", PURPLE),
+    ("rebeccapurple", REBECCA_PURPLE),
+    ("red", RED),
+    ("rosybrown", ROSY_BROWN),
+    ("royalblue", ROYAL_BLUE),
+    ("saddlebrown", SADDLE_BROWN),
+    ("salmon", SALMON),
+    ("sandybrown", SANDY_BROWN),
+    ("seagreen", SEA_GREEN),
+    ("seashell", SEASHELL),
+    ("sienna", SIENNA),
+    ("silver", SILVER),
+    ("skyblue", SKY_BLUE),
+    ("slateblue", SLATE_BLUE),
+    ("slategray", SLATE_GRAY),
+    ("slategrey", SLATE_GREY),
+    ("snow", SNOW),
+    ("springgreen", SPRING_GREEN),
+    ("steelblue", STEEL_BLUE),
+    ("tan", TAN),
+    ("teal", TEAL),
+    ("thistle", THISTLE),
+    ("tomato", TOMATO),
+    ("turquoise", TURQUOISE),
+    ("violet", VIOLET),
+    ("wheat", WHEAT),
+    ("white", WHITE),
+    ("whitesmoke", WHITE_SMOKE),
+    ("yellow", YELLOW),
+    ("yellowgreen", YELLOW_GREEN),
+];
@@ -0,0 +1,2668 @@
+//! The colors of the X11 ```rgb.txt```.<br>
+//! Some names have a different color than in CSS: ```gray```, ```green```, ```maroon``` and ```purple```.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::named::{css, x11};
+//! assert_eq!(x11::lookup("light goldenrod 2"), Some(x11::LIGHT_GOLDENROD_2));
+//! assert_eq!(x11::GRAY_50, rgba!("#7f7f7f"));
+//! assert_ne!(x11::GREEN, css::GREEN);
+//! assert_eq!(x11::name_of(rgba!(0x00FF00FF)), Some("green"));
+//! ```
+use std::sync::OnceLock;
+use crate::{RGB, RGBA};
+use crate::palette::Metric;
+
+/// Find a color by name, ignoring the case and the spaces.
+pub fn lookup(name : &str) -> Option<RGBA<u8>> {
+    let name : String = name.chars().filter(|c| !c.is_whitespace()).collect();
+    super::lookup(COLORS, &name)
+}
+
+/// Find the name of a color, the color must be opaque and match exactly.
+pub fn name_of(color : RGBA<u8>) -> Option<&'static str> {
+    super::name_of(COLORS, color)
+}
+
+static PALETTES : super::Palettes = [OnceLock::new(), OnceLock::new(), OnceLock::new()];
+
+/// Find the nearest named color with a metric, the first name in alphabetical order wins a tie.
+pub fn nearest<C : Into<RGB<f32>>>(color : C, metric : Metric) -> (&'static str, RGBA<u8>) {
+    super::nearest(COLORS, &PALETTES, color.into(), metric)
+}
+
+/// ```aliceblue``` ```#F0F8FF```
+pub const ALICE_BLUE : RGBA<u8> = RGBA::from_u32(0xF0F8FFFF);
+
+/// ```antiquewhite``` ```#FAEBD7```
+pub const ANTIQUE_WHITE : RGBA<u8> = RGBA::from_u32(0xFAEBD7FF);
+
+/// ```antiquewhite1``` ```#FFEFDB```
+pub const ANTIQUE_WHITE_1 : RGBA<u8> = RGBA::from_u32(0xFFEFDBFF);
+
+/// ```antiquewhite2``` ```#EEDFCC```
+pub const ANTIQUE_WHITE_2 : RGBA<u8> = RGBA::from_u32(0xEEDFCCFF);
+
+/// ```antiquewhite3``` ```#CDC0B0```
+pub const ANTIQUE_WHITE_3 : RGBA<u8> = RGBA::from_u32(0xCDC0B0FF);
+
+/// ```antiquewhite4``` ```#8B8378```
+pub const ANTIQUE_WHITE_4 : RGBA<u8> = RGBA::from_u32(0x8B8378FF);
+
+/// ```aquamarine``` ```#7FFFD4```
+pub const AQUAMARINE : RGBA<u8> = RGBA::from_u32(0x7FFFD4FF);
+
+/// ```aquamarine1``` ```#7FFFD4```
+pub const AQUAMARINE_1 : RGBA<u8> = RGBA::from_u32(0x7FFFD4FF);
+
+/// ```aquamarine2``` ```#76EEC6```
+pub const AQUAMARINE_2 : RGBA<u8> = RGBA::from_u32(0x76EEC6FF);
+
+/// ```aquamarine3``` ```#66CDAA```
+pub const AQUAMARINE_3 : RGBA<u8> = RGBA::from_u32(0x66CDAAFF);
+
+/// ```aquamarine4``` ```#458B74```
+pub const AQUAMARINE_4 : RGBA<u8> = RGBA::from_u32(0x458B74FF);
+
+/// ```azure``` ```#F0FFFF```
+pub const AZURE : RGBA<u8> = RGBA::from_u32(0xF0FFFFFF);
+
+/// ```azure1``` ```#F0FFFF```
+pub const AZURE_1 : RGBA<u8> = RGBA::from_u32(0xF0FFFFFF);
+
+/// ```azure2``` ```#E0EEEE```
+pub const AZURE_2 : RGBA<u8> = RGBA::from_u32(0xE0EEEEFF);
+
+/// ```azure3``` ```#C1CDCD```
+pub const AZURE_3 : RGBA<u8> = RGBA::from_u32(0xC1CDCDFF);
+
+/// ```azure4``` ```#838B8B```
+pub const AZURE_4 : RGBA<u8> = RGBA::from_u32(0x838B8BFF);
+
+/// ```beige``` ```#F5F5DC```
+pub const BEIGE : RGBA<u8> = RGBA::from_u32(0xF5F5DCFF);
+
+/// ```bisque``` ```#FFE4C4```
+pub const BISQUE : RGBA<u8> = RGBA::from_u32(0xFFE4C4FF);
+
+/// ```bisque1``` ```#FFE4C4```
+pub const BISQUE_1 : RGBA<u8> = RGBA::from_u32(0xFFE4C4FF);
+
+/// ```bisque2``` ```#EED5B7```
+pub const BISQUE_2 : RGBA<u8> = RGBA::from_u32(0xEED5B7FF);
+
+/// ```bisque3``` ```#CDB79E```
+pub const BISQUE_3 : RGBA<u8> = RGBA::from_u32(0xCDB79EFF);
+
+/// ```bisque4``` ```#8B7D6B```
+pub const BISQUE_4 : RGBA<u8> = RGBA::from_u32(0x8B7D6BFF);
+
+/// ```black``` ```#000000```
+pub const BLACK : RGBA<u8> = RGBA::from_u32(0x000000FF);
+
+/// ```blanchedalmond``` ```#FFEBCD```
+pub const BLANCHED_ALMOND : RGBA<u8> = RGBA::from_u32(0xFFEBCDFF);
+
+/// ```blue``` ```#0000FF```
+pub const BLUE : RGBA<u8> = RGBA::from_u32(0x0000FFFF);
+
+/// ```blue1``` ```#0000FF```
+pub const BLUE_1 : RGBA<u8> = RGBA::from_u32(0x0000FFFF);
+
+/// ```blue2``` ```#0000EE```
+pub const BLUE_2 : RGBA<u8> = RGBA::from_u32(0x0000EEFF);
+
+/// ```blue3``` ```#0000CD```
+pub const BLUE_3 : RGBA<u8> = RGBA::from_u32(0x0000CDFF);
+
+/// ```blue4``` ```#00008B```
+pub const BLUE_4 : RGBA<u8> = RGBA::from_u32(0x00008BFF);
+
+/// ```blueviolet``` ```#8A2BE2```
+pub const BLUE_VIOLET : RGBA<u8> = RGBA::from_u32(0x8A2BE2FF);
+
+/// ```brown``` ```#A52A2A```
+pub const BROWN : RGBA<u8> = RGBA::from_u32(0xA52A2AFF);
+
+/// ```brown1``` ```#FF4040```
+pub const BROWN_1 : RGBA<u8> = RGBA::from_u32(0xFF4040FF);
+
+/// ```brown2``` ```#EE3B3B```
+pub const BROWN_2 : RGBA<u8> = RGBA::from_u32(0xEE3B3BFF);
+
+/// ```brown3``` ```#CD3333```
+pub const BROWN_3 : RGBA<u8> = RGBA::from_u32(0xCD3333FF);
+
+/// ```brown4``` ```#8B2323```
+pub const BROWN_4 : RGBA<u8> = RGBA::from_u32(0x8B2323FF);
+
+/// ```burlywood``` ```#DEB887```
+pub const BURLYWOOD : RGBA<u8> = RGBA::from_u32(0xDEB887FF);
+
+/// ```burlywood1``` ```#FFD39B```
+pub const BURLYWOOD_1 : RGBA<u8> = RGBA::from_u32(0xFFD39BFF);
+
+/// ```burlywood2``` ```#EEC591```
+pub const BURLYWOOD_2 : RGBA<u8> = RGBA::from_u32(0xEEC591FF);
+
+/// ```burlywood3``` ```#CDAA7D```
+pub const BURLYWOOD_3 : RGBA<u8> = RGBA::from_u32(0xCDAA7DFF);
+
+/// ```burlywood4``` ```#8B7355```
+pub const BURLYWOOD_4 : RGBA<u8> = RGBA::from_u32(0x8B7355FF);
+
+/// ```cadetblue``` ```#5F9EA0```
+pub const CADET_BLUE : RGBA<u8> = RGBA::from_u32(0x5F9EA0FF);
+
+/// ```cadetblue1``` ```#98F5FF```
+pub const CADET_BLUE_1 : RGBA<u8> = RGBA::from_u32(0x98F5FFFF);
+
+/// ```cadetblue2``` ```#8EE5EE```
+pub const CADET_BLUE_2 : RGBA<u8> = RGBA::from_u32(0x8EE5EEFF);
+
+/// ```cadetblue3``` ```#7AC5CD```
+pub const CADET_BLUE_3 : RGBA<u8> = RGBA::from_u32(0x7AC5CDFF);
+
+/// ```cadetblue4``` ```#53868B```
+pub const CADET_BLUE_4 : RGBA<u8> = RGBA::from_u32(0x53868BFF);
+
+/// ```chartreuse``` ```#7FFF00```
+pub const CHARTREUSE : RGBA<u8> = RGBA::from_u32(0x7FFF00FF);
+
+/// ```chartreuse1``` ```#7FFF00```
+pub const CHARTREUSE_1 : RGBA<u8> = RGBA::from_u32(0x7FFF00FF);
+
+/// ```chartreuse2``` ```#76EE00```
+pub const CHARTREUSE_2 : RGBA<u8> = RGBA::from_u32(0x76EE00FF);
+
+/// ```chartreuse3``` ```#66CD00```
+pub const CHARTREUSE_3 : RGBA<u8> = RGBA::from_u32(0x66CD00FF);
+
+/// ```chartreuse4``` ```#458B00```
+pub const CHARTREUSE_4 : RGBA<u8> = RGBA::from_u32(0x458B00FF);
+
+/// ```chocolate``` ```#D2691E```
+pub const CHOCOLATE : RGBA<u8> = RGBA::from_u32(0xD2691EFF);
+
+/// ```chocolate1``` ```#FF7F24```
+pub const CHOCOLATE_1 : RGBA<u8> = RGBA::from_u32(0xFF7F24FF);
+
+/// ```chocolate2``` ```#EE7621```
+pub const CHOCOLATE_2 : RGBA<u8> = RGBA::from_u32(0xEE7621FF);
+
+/// ```chocolate3``` ```#CD661D```
+pub const CHOCOLATE_3 : RGBA<u8> = RGBA::from_u32(0xCD661DFF);
+
+/// ```chocolate4``` ```#8B4513```
+pub const CHOCOLATE_4 : RGBA<u8> = RGBA::from_u32(0x8B4513FF);
+
+/// ```coral``` ```#FF7F50```
+pub const CORAL : RGBA<u8> = RGBA::from_u32(0xFF7F50FF);
+
+/// ```coral1``` ```#FF7256```
+pub const CORAL_1 : RGBA<u8> = RGBA::from_u32(0xFF7256FF);
+
+/// ```coral2``` ```#EE6A50```
+pub const CORAL_2 : RGBA<u8> = RGBA::from_u32(0xEE6A50FF);
+
+/// ```coral3``` ```#CD5B45```
+pub const CORAL_3 : RGBA<u8> = RGBA::from_u32(0xCD5B45FF);
+
+/// ```coral4``` ```#8B3E2F```
+pub const CORAL_4 : RGBA<u8> = RGBA::from_u32(0x8B3E2FFF);
+
+/// ```cornflowerblue``` ```#6495ED```
+pub const CORNFLOWER_BLUE : RGBA<u8> = RGBA::from_u32(0x6495EDFF);
+
+/// ```cornsilk``` ```#FFF8DC```
+pub const CORNSILK : RGBA<u8> = RGBA::from_u32(0xFFF8DCFF);
+
+/// ```cornsilk1``` ```#FFF8DC```
+pub const CORNSILK_1 : RGBA<u8> = RGBA::from_u32(0xFFF8DCFF);
+
+/// ```cornsilk2``` ```#EEE8CD```
+pub const CORNSILK_2 : RGBA<u8> = RGBA::from_u32(0xEEE8CDFF);
+
+/// ```cornsilk3``` ```#CDC8B1```
+pub const CORNSILK_3 : RGBA<u8> = RGBA::from_u32(0xCDC8B1FF);
+
+/// ```cornsilk4``` ```#8B8878```
+pub const CORNSILK_4 : RGBA<u8> = RGBA::from_u32(0x8B8878FF);
+
+/// ```cyan``` ```#00FFFF```
+pub const CYAN : RGBA<u8> = RGBA::from_u32(0x00FFFFFF);
+
+/// ```cyan1``` ```#00FFFF```
+pub const CYAN_1 : RGBA<u8> = RGBA::from_u32(0x00FFFFFF);
+
+/// ```cyan2``` ```#00EEEE```
+pub const CYAN_2 : RGBA<u8> = RGBA::from_u32(0x00EEEEFF);
+
+/// ```cyan3``` ```#00CDCD```
+pub const CYAN_3 : RGBA<u8> = RGBA::from_u32(0x00CDCDFF);
+
+/// ```cyan4``` ```#008B8B```
+pub const CYAN_4 : RGBA<u8> = RGBA::from_u32(0x008B8BFF);
+
+/// ```darkblue``` ```#00008B```
+pub const DARK_BLUE : RGBA<u8> = RGBA::from_u32(0x00008BFF);
+
+/// ```darkcyan``` ```#008B8B```
+pub const DARK_CYAN : RGBA<u8> = RGBA::from_u32(0x008B8BFF);
+
+/// ```darkgoldenrod``` ```#B8860B```
+pub const DARK_GOLDENROD : RGBA<u8> = RGBA::from_u32(0xB8860BFF);
+
+/// ```darkgoldenrod1``` ```#FFB90F```
+pub const DARK_GOLDENROD_1 : RGBA<u8> = RGBA::from_u32(0xFFB90FFF);
+
+/// ```darkgoldenrod2``` ```#EEAD0E```
+pub const DARK_GOLDENROD_2 : RGBA<u8> = RGBA::from_u32(0xEEAD0EFF);
+
+/// ```darkgoldenrod3``` ```#CD950C```
+pub const DARK_GOLDENROD_3 : RGBA<u8> = RGBA::from_u32(0xCD950CFF);
+
+/// ```darkgoldenrod4``` ```#8B6508```
+pub const DARK_GOLDENROD_4 : RGBA<u8> = RGBA::from_u32(0x8B6508FF);
+
+/// ```darkgray``` ```#A9A9A9```
+pub const DARK_GRAY : RGBA<u8> = RGBA::from_u32(0xA9A9A9FF);
+
+/// ```darkgreen``` ```#006400```
+pub const DARK_GREEN : RGBA<u8> = RGBA::from_u32(0x006400FF);
+
+/// ```darkgrey``` ```#A9A9A9```
+pub const DARK_GREY : RGBA<u8> = RGBA::from_u32(0xA9A9A9FF);
+
+/// ```darkkhaki``` ```#BDB76B```
+pub const DARK_KHAKI : RGBA<u8> = RGBA::from_u32(0xBDB76BFF);
+
+/// ```darkmagenta``` ```#8B008B```
+pub const DARK_MAGENTA : RGBA<u8> = RGBA::from_u32(0x8B008BFF);
+
+/// ```darkolivegreen``` ```#556B2F```
+pub const DARK_OLIVE_GREEN : RGBA<u8> = RGBA::from_u32(0x556B2FFF);
+
+/// ```darkolivegreen1``` ```#CAFF70```
+pub const DARK_OLIVE_GREEN_1 : RGBA<u8> = RGBA::from_u32(0xCAFF70FF);
+
+/// ```darkolivegreen2``` ```#BCEE68```
+pub const DARK_OLIVE_GREEN_2 : RGBA<u8> = RGBA::from_u32(0xBCEE68FF);
+
+/// ```darkolivegreen3``` ```#A2CD5A```
+pub const DARK_OLIVE_GREEN_3 : RGBA<u8> = RGBA::from_u32(0xA2CD5AFF);
+
+/// ```darkolivegreen4``` ```#6E8B3D```
+pub const DARK_OLIVE_GREEN_4 : RGBA<u8> = RGBA::from_u32(0x6E8B3DFF);
+
+/// ```darkorange``` ```#FF8C00```
+pub const DARK_ORANGE : RGBA<u8> = RGBA::from_u32(0xFF8C00FF);
+
+/// ```darkorange1``` ```#FF7F00```
+pub const DARK_ORANGE_1 : RGBA<u8> = RGBA::from_u32(0xFF7F00FF);
+
+/// ```darkorange2``` ```#EE7600```
+pub const DARK_ORANGE_2 : RGBA<u8> = RGBA::from_u32(0xEE7600FF);
+
+/// ```darkorange3``` ```#CD6600```
+pub const DARK_ORANGE_3 : RGBA<u8> = RGBA::from_u32(0xCD6600FF);
+
+/// ```darkorange4``` ```#8B4500```
+pub const DARK_ORANGE_4 : RGBA<u8> = RGBA::from_u32(0x8B4500FF);
+
+/// ```darkorchid``` ```#9932CC```
+pub const DARK_ORCHID : RGBA<u8> = RGBA::from_u32(0x9932CCFF);
+
+/// ```darkorchid1``` ```#BF3EFF```
+pub const DARK_ORCHID_1 : RGBA<u8> = RGBA::from_u32(0xBF3EFFFF);
+
+/// ```darkorchid2``` ```#B23AEE```
+pub const DARK_ORCHID_2 : RGBA<u8> = RGBA::from_u32(0xB23AEEFF);
+
+/// ```darkorchid3``` ```#9A32CD```
+pub const DARK_ORCHID_3 : RGBA<u8> = RGBA::from_u32(0x9A32CDFF);
+
+/// ```darkorchid4``` ```#68228B```
+pub const DARK_ORCHID_4 : RGBA<u8> = RGBA::from_u32(0x68228BFF);
+
+/// ```darkred``` ```#8B0000```
+pub const DARK_RED : RGBA<u8> = RGBA::from_u32(0x8B0000FF);
+
+/// ```darksalmon``` ```#E9967A```
+pub const DARK_SALMON : RGBA<u8> = RGBA::from_u32(0xE9967AFF);
+
+/// ```darkseagreen``` ```#8FBC8F```
+pub const DARK_SEA_GREEN : RGBA<u8> = RGBA::from_u32(0x8FBC8FFF);
+
+/// ```darkseagreen1``` ```#C1FFC1```
+pub const DARK_SEA_GREEN_1 : RGBA<u8> = RGBA::from_u32(0xC1FFC1FF);
+
+/// ```darkseagreen2``` ```#B4EEB4```
+pub const DARK_SEA_GREEN_2 : RGBA<u8> = RGBA::from_u32(0xB4EEB4FF);
+
+/// ```darkseagreen3``` ```#9BCD9B```
+pub const DARK_SEA_GREEN_3 : RGBA<u8> = RGBA::from_u32(0x9BCD9BFF);
+
+/// ```darkseagreen4``` ```#698B69```
+pub const DARK_SEA_GREEN_4 : RGBA<u8> = RGBA::from_u32(0x698B69FF);
+
+/// ```darkslateblue``` ```#483D8B```
+pub const DARK_SLATE_BLUE : RGBA<u8> = RGBA::from_u32(0x483D8BFF);
+
+/// ```darkslategray``` ```#2F4F4F```
+pub const DARK_SLATE_GRAY : RGBA<u8> = RGBA::from_u32(0x2F4F4FFF);
+
+/// ```darkslategray1``` ```#97FFFF```
+pub const DARK_SLATE_GRAY_1 : RGBA<u8> = RGBA::from_u32(0x97FFFFFF);
+
+/// ```darkslategray2``` ```#8DEEEE```
+pub const DARK_SLATE_GRAY_2 : RGBA<u8> = RGBA::from_u32(0x8DEEEEFF);
+
+/// ```darkslategray3``` ```#79CDCD```
+pub const DARK_SLATE_GRAY_3 : RGBA<u8> = RGBA::from_u32(0x79CDCDFF);
+
+/// ```darkslategray4``` ```#528B8B```
+pub const DARK_SLATE_GRAY_4 : RGBA<u8> = RGBA::from_u32(0x528B8BFF);
+
+/// ```darkslategrey``` ```#2F4F4F```
+pub const DARK_SLATE_GREY : RGBA<u8> = RGBA::from_u32(0x2F4F4FFF);
+
+/// ```darkturquoise``` ```#00CED1```
+pub const DARK_TURQUOISE : RGBA<u8> = RGBA::from_u32(0x00CED1FF);
+
+/// ```darkviolet``` ```#9400D3```
+pub const DARK_VIOLET : RGBA<u8> = RGBA::from_u32(0x9400D3FF);
+
+/// ```debianred``` ```#D70751```
+pub const DEBIAN_RED : RGBA<u8> = RGBA::from_u32(0xD70751FF);
+
+/// ```deeppink``` ```#FF1493```
+pub const DEEP_PINK : RGBA<u8> = RGBA::from_u32(0xFF1493FF);
+
+/// ```deeppink1``` ```#FF1493```
+pub const DEEP_PINK_1 : RGBA<u8> = RGBA::from_u32(0xFF1493FF);
+
+/// ```deeppink2``` ```#EE1289```
+pub const DEEP_PINK_2 : RGBA<u8> = RGBA::from_u32(0xEE1289FF);
+
+/// ```deeppink3``` ```#CD1076```
+pub const DEEP_PINK_3 : RGBA<u8> = RGBA::from_u32(0xCD1076FF);
+
+/// ```deeppink4``` ```#8B0A50```
+pub const DEEP_PINK_4 : RGBA<u8> = RGBA::from_u32(0x8B0A50FF);
+
+/// ```deepskyblue``` ```#00BFFF```
+pub const DEEP_SKY_BLUE : RGBA<u8> = RGBA::from_u32(0x00BFFFFF);
+
+/// ```deepskyblue1``` ```#00BFFF```
+pub const DEEP_SKY_BLUE_1 : RGBA<u8> = RGBA::from_u32(0x00BFFFFF);
+
+/// ```deepskyblue2``` ```#00B2EE```
+pub const DEEP_SKY_BLUE_2 : RGBA<u8> = RGBA::from_u32(0x00B2EEFF);
+
+/// ```deepskyblue3``` ```#009ACD```
+pub const DEEP_SKY_BLUE_3 : RGBA<u8> = RGBA::from_u32(0x009ACDFF);
+
+/// ```deepskyblue4``` ```#00688B```
+pub const DEEP_SKY_BLUE_4 : RGBA<u8> = RGBA::from_u32(0x00688BFF);
+
+/// ```dimgray``` ```#696969```
+pub const DIM_GRAY : RGBA<u8> = RGBA::from_u32(0x696969FF);
+
+/// ```dimgrey``` ```#696969```
+pub const DIM_GREY : RGBA<u8> = RGBA::from_u32(0x696969FF);
+
+/// ```dodgerblue``` ```#1E90FF```
+pub const DODGER_BLUE : RGBA<u8> = RGBA::from_u32(0x1E90FFFF);
+
+/// ```dodgerblue1``` ```#1E90FF```
+pub const DODGER_BLUE_1 : RGBA<u8> = RGBA::from_u32(0x1E90FFFF);
+
+/// ```dodgerblue2``` ```#1C86EE```
+pub const DODGER_BLUE_2 : RGBA<u8> = RGBA::from_u32(0x1C86EEFF);
+
+/// ```dodgerblue3``` ```#1874CD```
+pub const DODGER_BLUE_3 : RGBA<u8> = RGBA::from_u32(0x1874CDFF);
+
+/// ```dodgerblue4``` ```#104E8B```
+pub const DODGER_BLUE_4 : RGBA<u8> = RGBA::from_u32(0x104E8BFF);
+
+/// ```firebrick``` ```#B22222```
+pub const FIREBRICK : RGBA<u8> = RGBA::from_u32(0xB22222FF);
+
+/// ```firebrick1``` ```#FF3030```
+pub const FIREBRICK_1 : RGBA<u8> = RGBA::from_u32(0xFF3030FF);
+
+/// ```firebrick2``` ```#EE2C2C```
+pub const FIREBRICK_2 : RGBA<u8> = RGBA::from_u32(0xEE2C2CFF);
+
+/// ```firebrick3``` ```#CD2626```
+pub const FIREBRICK_3 : RGBA<u8> = RGBA::from_u32(0xCD2626FF);
+
+/// ```firebrick4``` ```#8B1A1A```
+pub const FIREBRICK_4 : RGBA<u8> = RGBA::from_u32(0x8B1A1AFF);
+
+/// ```floralwhite``` ```#FFFAF0```
+pub const FLORAL_WHITE : RGBA<u8> = RGBA::from_u32(0xFFFAF0FF);
+
+/// ```forestgreen``` ```#228B22```
+pub const FOREST_GREEN : RGBA<u8> = RGBA::from_u32(0x228B22FF);
+
+/// ```gainsboro``` ```#DCDCDC```
+pub const GAINSBORO : RGBA<u8> = RGBA::from_u32(0xDCDCDCFF);
+
+/// ```ghostwhite``` ```#F8F8FF```
+pub const GHOST_WHITE : RGBA<u8> = RGBA::from_u32(0xF8F8FFFF);
+
+/// ```gold``` ```#FFD700```
+pub const GOLD : RGBA<u8> = RGBA::from_u32(0xFFD700FF);
+
+/// ```gold1``` ```#FFD700```
+pub const GOLD_1 : RGBA<u8> = RGBA::from_u32(0xFFD700FF);
+
+/// ```gold2``` ```#EEC900```
+pub const GOLD_2 : RGBA<u8> = RGBA::from_u32(0xEEC900FF);
+
+/// ```gold3``` ```#CDAD00```
+pub const GOLD_3 : RGBA<u8> = RGBA::from_u32(0xCDAD00FF);
+
+/// ```gold4``` ```#8B7500```
+pub const GOLD_4 : RGBA<u8> = RGBA::from_u32(0x8B7500FF);
+
+/// ```goldenrod``` ```#DAA520```
+pub const GOLDENROD : RGBA<u8> = RGBA::from_u32(0xDAA520FF);
+
+/// ```goldenrod1``` ```#FFC125```
+pub const GOLDENROD_1 : RGBA<u8> = RGBA::from_u32(0xFFC125FF);
+
+/// ```goldenrod2``` ```#EEB422```
+pub const GOLDENROD_2 : RGBA<u8> = RGBA::from_u32(0xEEB422FF);
+
+/// ```goldenrod3``` ```#CD9B1D```
+pub const GOLDENROD_3 : RGBA<u8> = RGBA::from_u32(0xCD9B1DFF);
+
+/// ```goldenrod4``` ```#8B6914```
+pub const GOLDENROD_4 : RGBA<u8> = RGBA::from_u32(0x8B6914FF);
+
+/// ```gray``` ```#BEBEBE```
+pub const GRAY : RGBA<u8> = RGBA::from_u32(0xBEBEBEFF);
+
+/// ```gray0``` ```#000000```
+pub const GRAY_0 : RGBA<u8> = RGBA::from_u32(0x000000FF);
+
+/// ```gray1``` ```#030303```
+pub const GRAY_1 : RGBA<u8> = RGBA::from_u32(0x030303FF);
+
+/// ```gray10``` ```#1A1A1A```
+pub const GRAY_10 : RGBA<u8> = RGBA::from_u32(0x1A1A1AFF);
+
+/// ```gray100``` ```#FFFFFF```
+pub const GRAY_100 : RGBA<u8> = RGBA::from_u32(0xFFFFFFFF);
+
+/// ```gray11``` ```#1C1C1C```
+pub const GRAY_11 : RGBA<u8> = RGBA::from_u32(0x1C1C1CFF);
+
+/// ```gray12``` ```#1F1F1F```
+pub const GRAY_12 : RGBA<u8> = RGBA::from_u32(0x1F1F1FFF);
+
+/// ```gray13``` ```#212121```
+pub const GRAY_13 : RGBA<u8> = RGBA::from_u32(0x212121FF);
+
+/// ```gray14``` ```#242424```
+pub const GRAY_14 : RGBA<u8> = RGBA::from_u32(0x242424FF);
+
+/// ```gray15``` ```#262626```
+pub const GRAY_15 : RGBA<u8> = RGBA::from_u32(0x262626FF);
+
+/// ```gray16``` ```#292929```
+pub const GRAY_16 : RGBA<u8> = RGBA::from_u32(0x292929FF);
+
+/// ```gray17``` ```#2B2B2B```
+pub const GRAY_17 : RGBA<u8> = RGBA::from_u32(0x2B2B2BFF);
+
+/// ```gray18``` ```#2E2E2E```
+pub const GRAY_18 : RGBA<u8> = RGBA::from_u32(0x2E2E2EFF);
+
+/// ```gray19``` ```#303030```
+pub const GRAY_19 : RGBA<u8> = RGBA::from_u32(0x303030FF);
+
+/// ```gray2``` ```#050505```
+pub const GRAY_2 : RGBA<u8> = RGBA::from_u32(0x050505FF);
+
+/// ```gray20``` ```#333333```
+pub const GRAY_20 : RGBA<u8> = RGBA::from_u32(0x333333FF);
+
+/// ```gray21``` ```#363636```
+pub const GRAY_21 : RGBA<u8> = RGBA::from_u32(0x363636FF);
+
+/// ```gray22``` ```#383838```
+pub const GRAY_22 : RGBA<u8> = RGBA::from_u32(0x383838FF);
+
+/// ```gray23``` ```#3B3B3B```
+pub const GRAY_23 : RGBA<u8> = RGBA::from_u32(0x3B3B3BFF);
+
+/// ```gray24``` ```#3D3D3D```
+pub const GRAY_24 : RGBA<u8> = RGBA::from_u32(0x3D3D3DFF);
+
+/// ```gray25``` ```#404040```
+pub const GRAY_25 : RGBA<u8> = RGBA::from_u32(0x404040FF);
+
+/// ```gray26``` ```#424242```
+pub const GRAY_26 : RGBA<u8> = RGBA::from_u32(0x424242FF);
+
+/// ```gray27``` ```#454545```
+pub const GRAY_27 : RGBA<u8> = RGBA::from_u32(0x454545FF);
+
+/// ```gray28``` ```#474747```
+pub const GRAY_28 : RGBA<u8> = RGBA::from_u32(0x474747FF);
+
+/// ```gray29``` ```#4A4A4A```
+pub const GRAY_29 : RGBA<u8> = RGBA::from_u32(0x4A4A4AFF);
+
+/// ```gray3``` ```#080808```
+pub const GRAY_3 : RGBA<u8> = RGBA::from_u32(0x080808FF);
+
+/// ```gray30``` ```#4D4D4D```
+pub const GRAY_30 : RGBA<u8> = RGBA::from_u32(0x4D4D4DFF);
+
+/// ```gray31``` ```#4F4F4F```
+pub const GRAY_31 : RGBA<u8> = RGBA::from_u32(0x4F4F4FFF);
+
+/// ```gray32``` ```#525252```
+pub const GRAY_32 : RGBA<u8> = RGBA::from_u32(0x525252FF);
+
+/// ```gray33``` ```#545454```
+pub const GRAY_33 : RGBA<u8> = RGBA::from_u32(0x545454FF);
+
+/// ```gray34``` ```#575757```
+pub const GRAY_34 : RGBA<u8> = RGBA::from_u32(0x575757FF);
+
+/// ```gray35``` ```#595959```
+pub const GRAY_35 : RGBA<u8> = RGBA::from_u32(0x595959FF);
+
+/// ```gray36``` ```#5C5C5C```
+pub const GRAY_36 : RGBA<u8> = RGBA::from_u32(0x5C5C5CFF);
+
+/// ```gray37``` ```#5E5E5E```
+pub const GRAY_37 : RGBA<u8> = RGBA::from_u32(0x5E5E5EFF);
+
+/// ```gray38``` ```#616161```
+pub const GRAY_38 : RGBA<u8> = RGBA::from_u32(0x616161FF);
+
+/// ```gray39``` ```#636363```
+pub const GRAY_39 : RGBA<u8> = RGBA::from_u32(0x636363FF);
+
+/// ```gray4``` ```#0A0A0A```
+pub const GRAY_4 : RGBA<u8> = RGBA::from_u32(0x0A0A0AFF);
+
+/// ```gray40``` ```#666666```
+pub const GRAY_40 : RGBA<u8> = RGBA::from_u32(0x666666FF);
+
+/// ```gray41``` ```#696969```
+pub const GRAY_41 : RGBA<u8> = RGBA::from_u32(0x696969FF);
+
+/// ```gray42``` ```#6B6B6B```
+pub const GRAY_42 : RGBA<u8> = RGBA::from_u32(0x6B6B6BFF);
+
+/// ```gray43``` ```#6E6E6E```
+pub const GRAY_43 : RGBA<u8> = RGBA::from_u32(0x6E6E6EFF);
+
+/// ```gray44``` ```#707070```
+pub const GRAY_44 : RGBA<u8> = RGBA::from_u32(0x707070FF);
+
+/// ```gray45``` ```#737373```
+pub const GRAY_45 : RGBA<u8> = RGBA::from_u32(0x737373FF);
+
+/// ```gray46``` ```#757575```
+pub const GRAY_46 : RGBA<u8> = RGBA::from_u32(0x757575FF);
+
+/// ```gray47``` ```#787878```
+pub const GRAY_47 : RGBA<u8> = RGBA::from_u32(0x787878FF);
+
+/// ```gray48``` ```#7A7A7A```
+pub const GRAY_48 : RGBA<u8> = RGBA::from_u32(0x7A7A7AFF);
+
+/// ```gray49``` ```#7D7D7D```
+pub const GRAY_49 : RGBA<u8> = RGBA::from_u32(0x7D7D7DFF);
+
+/// ```gray5``` ```#0D0D0D```
+pub const GRAY_5 : RGBA<u8> = RGBA::from_u32(0x0D0D0DFF);
+
+/// ```gray50``` ```#7F7F7F```
+pub const GRAY_50 : RGBA<u8> = RGBA::from_u32(0x7F7F7FFF);
+
+/// ```gray51``` ```#828282```
+pub const GRAY_51 : RGBA<u8> = RGBA::from_u32(0x828282FF);
+
+/// ```gray52``` ```#858585```
+pub const GRAY_52 : RGBA<u8> = RGBA::from_u32(0x858585FF);
+
+/// ```gray53``` ```#878787```
+pub const GRAY_53 : RGBA<u8> = RGBA::from_u32(0x878787FF);
+
+/// ```gray54``` ```#8A8A8A```
+pub const GRAY_54 : RGBA<u8> = RGBA::from_u32(0x8A8A8AFF);
+
+/// ```gray55``` ```#8C8C8C```
+pub const GRAY_55 : RGBA<u8> = RGBA::from_u32(0x8C8C8CFF);
+
+/// ```gray56``` ```#8F8F8F```
+pub const GRAY_56 : RGBA<u8> = RGBA::from_u32(0x8F8F8FFF);
+
+/// ```gray57``` ```#919191```
+pub const GRAY_57 : RGBA<u8> = RGBA::from_u32(0x919191FF);
+
+/// ```gray58``` ```#949494```
+pub const GRAY_58 : RGBA<u8> = RGBA::from_u32(0x949494FF);
+
+/// ```gray59``` ```#969696```
+pub const GRAY_59 : RGBA<u8> = RGBA::from_u32(0x969696FF);
+
+/// ```gray6``` ```#0F0F0F```
+pub const GRAY_6 : RGBA<u8> = RGBA::from_u32(0x0F0F0FFF);
+
+/// ```gray60``` ```#999999```
+pub const GRAY_60 : RGBA<u8> = RGBA::from_u32(0x999999FF);
+
+/// ```gray61``` ```#9C9C9C```
+pub const GRAY_61 : RGBA<u8> = RGBA::from_u32(0x9C9C9CFF);
+
+/// ```gray62``` ```#9E9E9E```
+pub const GRAY_62 : RGBA<u8> = RGBA::from_u32(0x9E9E9EFF);
+
+/// ```gray63``` ```#A1A1A1```
+pub const GRAY_63 : RGBA<u8> = RGBA::from_u32(0xA1A1A1FF);
+
+/// ```gray64``` ```#A3A3A3```
+pub const GRAY_64 : RGBA<u8> = RGBA::from_u32(0xA3A3A3FF);
+
+/// ```gray65``` ```#A6A6A6```
+pub const GRAY_65 : RGBA<u8> = RGBA::from_u32(0xA6A6A6FF);
+
+/// ```gray66``` ```#A8A8A8```
+pub const GRAY_66 : RGBA<u8> = RGBA::from_u32(0xA8A8A8FF);
+
+/// ```gray67``` ```#ABABAB```
+pub const GRAY_67 : RGBA<u8> = RGBA::from_u32(0xABABABFF);
+
+/// ```gray68``` ```#ADADAD```
+pub const GRAY_68 : RGBA<u8> = RGBA::from_u32(0xADADADFF);
+
+/// ```gray69``` ```#B0B0B0```
+pub const GRAY_69 : RGBA<u8> = RGBA::from_u32(0xB0B0B0FF);
+
+/// ```gray7``` ```#121212```
+pub const GRAY_7 : RGBA<u8> = RGBA::from_u32(0x121212FF);
+
+/// ```gray70``` ```#B3B3B3```
+pub const GRAY_70 : RGBA<u8> = RGBA::from_u32(0xB3B3B3FF);
+
+/// ```gray71``` ```#B5B5B5```
+pub const GRAY_71 : RGBA<u8> = RGBA::from_u32(0xB5B5B5FF);
+
+/// ```gray72``` ```#B8B8B8```
+pub const GRAY_72 : RGBA<u8> = RGBA::from_u32(0xB8B8B8FF);
+
+/// ```gray73``` ```#BABABA```
+pub const GRAY_73 : RGBA<u8> = RGBA::from_u32(0xBABABAFF);
+
+/// ```gray74``` ```#BDBDBD```
+pub const GRAY_74 : RGBA<u8> = RGBA::from_u32(0xBDBDBDFF);
+
+/// ```gray75``` ```#BFBFBF```
+pub const GRAY_75 : RGBA<u8> = RGBA::from_u32(0xBFBFBFFF);
+
+/// ```gray76``` ```#C2C2C2```
+pub const GRAY_76 : RGBA<u8> = RGBA::from_u32(0xC2C2C2FF);
+
+/// ```gray77``` ```#C4C4C4```
+pub const GRAY_77 : RGBA<u8> = RGBA::from_u32(0xC4C4C4FF);
+
+/// ```gray78``` ```#C7C7C7```
+pub const GRAY_78 : RGBA<u8> = RGBA::from_u32(0xC7C7C7FF);
+
+/// ```gray79``` ```#C9C9C9```
+pub const GRAY_79 : RGBA<u8> = RGBA::from_u32(0xC9C9C9FF);
+
+/// ```gray8``` ```#141414```
+pub const GRAY_8 : RGBA<u8> = RGBA::from_u32(0x141414FF);
+
+/// ```gray80``` ```#CCCCCC```
+pub const GRAY_80 : RGBA<u8> = RGBA::from_u32(0xCCCCCCFF);
+
+/// ```gray81``` ```#CFCFCF```
+pub const GRAY_81 : RGBA<u8> = RGBA::from_u32(0xCFCFCFFF);
+
+/// ```gray82``` ```#D1D1D1```
+pub const GRAY_82 : RGBA<u8> = RGBA::from_u32(0xD1D1D1FF);
+
+/// ```gray83``` ```#D4D4D4```
+pub const GRAY_83 : RGBA<u8> = RGBA::from_u32(0xD4D4D4FF);
+
+/// ```gray84``` ```#D6D6D6```
+pub const GRAY_84 : RGBA<u8> = RGBA::from_u32(0xD6D6D6FF);
+
+/// ```gray85``` ```#D9D9D9```
+pub const GRAY_85 : RGBA<u8> = RGBA::from_u32(0xD9D9D9FF);
+
+/// ```gray86``` ```#DBDBDB```
+pub const GRAY_86 : RGBA<u8> = RGBA::from_u32(0xDBDBDBFF);
+
+/// ```gray87``` ```#DEDEDE```
+pub const GRAY_87 : RGBA<u8> = RGBA::from_u32(0xDEDEDEFF);
+
+/// ```gray88``` ```#E0E0E0```
+pub const GRAY_88 : RGBA<u8> = RGBA::from_u32(0xE0E0E0FF);
+
+/// ```gray89``` ```#E3E3E3```
+pub const GRAY_89 : RGBA<u8> = RGBA::from_u32(0xE3E3E3FF);
+
+/// ```gray9``` ```#171717```
+pub const GRAY_9 : RGBA<u8> = RGBA::from_u32(0x171717FF);
+
+/// ```gray90``` ```#E5E5E5```
+pub const GRAY_90 : RGBA<u8> = RGBA::from_u32(0xE5E5E5FF);
+
+/// ```gray91``` ```#E8E8E8```
+pub const GRAY_91 : RGBA<u8> = RGBA::from_u32(0xE8E8E8FF);
+
+/// ```gray92``` ```#EBEBEB```
+pub const GRAY_92 : RGBA<u8> = RGBA::from_u32(0xEBEBEBFF);
+
+/// ```gray93``` ```#EDEDED```
+pub const GRAY_93 : RGBA<u8> = RGBA::from_u32(0xEDEDEDFF);
+
+/// ```gray94``` ```#F0F0F0```
+pub const GRAY_94 : RGBA<u8> = RGBA::from_u32(0xF0F0F0FF);
+
+/// ```gray95``` ```#F2F2F2```
+pub const GRAY_95 : RGBA<u8> = RGBA::from_u32(0xF2F2F2FF);
+
+/// ```gray96``` ```#F5F5F5```
+pub const GRAY_96 : RGBA<u8> = RGBA::from_u32(0xF5F5F5FF);
+
+/// ```gray97``` ```#F7F7F7```
+pub const GRAY_97 : RGBA<u8> = RGBA::from_u32(0xF7F7F7FF);
+
+/// ```gray98``` ```#FAFAFA```
+pub const GRAY_98 : RGBA<u8> = RGBA::from_u32(0xFAFAFAFF);
+
+/// ```gray99``` ```#FCFCFC```
+pub const GRAY_99 : RGBA<u8> = RGBA::from_u32(0xFCFCFCFF);
+
+/// ```green``` ```#00FF00```
+pub const GREEN : RGBA<u8> = RGBA::from_u32(0x00FF00FF);
+
+/// ```green1``` ```#00FF00```
+pub const GREEN_1 : RGBA<u8> = RGBA::from_u32(0x00FF00FF);
+
+/// ```green2``` ```#00EE00```
+pub const GREEN_2 : RGBA<u8> = RGBA::from_u32(0x00EE00FF);
+
+/// ```green3``` ```#00CD00```
+pub const GREEN_3 : RGBA<u8> = RGBA::from_u32(0x00CD00FF);
+
+/// ```green4``` ```#008B00```
+pub const GREEN_4 : RGBA<u8> = RGBA::from_u32(0x008B00FF);
+
+/// ```greenyellow``` ```#ADFF2F```
+pub const GREEN_YELLOW : RGBA<u8> = RGBA::from_u32(0xADFF2FFF);
+
+/// ```grey``` ```#BEBEBE```
+pub const GREY : RGBA<u8> = RGBA::from_u32(0xBEBEBEFF);
+
+/// ```grey0``` ```#000000```
+pub const GREY_0 : RGBA<u8> = RGBA::from_u32(0x000000FF);
+
+/// ```grey1``` ```#030303```
+pub const GREY_1 : RGBA<u8> = RGBA::from_u32(0x030303FF);
+
+/// ```grey10``` ```#1A1A1A```
+pub const GREY_10 : RGBA<u8> = RGBA::from_u32(0x1A1A1AFF);
+
+/// ```grey100``` ```#FFFFFF```
+pub const GREY_100 : RGBA<u8> = RGBA::from_u32(0xFFFFFFFF);
+
+/// ```grey11``` ```#1C1C1C```
+pub const GREY_11 : RGBA<u8> = RGBA::from_u32(0x1C1C1CFF);
+
+/// ```grey12``` ```#1F1F1F```
+pub const GREY_12 : RGBA<u8> = RGBA::from_u32(0x1F1F1FFF);
+
+/// ```grey13``` ```#212121```
+pub const GREY_13 : RGBA<u8> = RGBA::from_u32(0x212121FF);
+
+/// ```grey14``` ```#242424```
+pub const GREY_14 : RGBA<u8> = RGBA::from_u32(0x242424FF);
+
+/// ```grey15``` ```#262626```
+pub const GREY_15 : RGBA<u8> = RGBA::from_u32(0x262626FF);
+
+/// ```grey16``` ```#292929```
+pub const GREY_16 : RGBA<u8> = RGBA::from_u32(0x292929FF);
+
+/// ```grey17``` ```#2B2B2B```
+pub const GREY_17 : RGBA<u8> = RGBA::from_u32(0x2B2B2BFF);
+
+/// ```grey18``` ```#2E2E2E```
+pub const GREY_18 : RGBA<u8> = RGBA::from_u32(0x2E2E2EFF);
+
+/// ```grey19``` ```#303030```
+pub const GREY_19 : RGBA<u8> = RGBA::from_u32(0x303030FF);
+
+/// ```grey2``` ```#050505```
+pub const GREY_2 : RGBA<u8> = RGBA::from_u32(0x050505FF);
+
+/// ```grey20``` ```#333333```
+pub const GREY_20 : RGBA<u8> = RGBA::from_u32(0x333333FF);
+
+/// ```grey21``` ```#363636```
+pub const GREY_21 : RGBA<u8> = RGBA::from_u32(0x363636FF);
+
+/// ```grey22``` ```#383838```
+pub const GREY_22 : RGBA<u8> = RGBA::from_u32(0x383838FF);
+
+/// ```grey23``` ```#3B3B3B```
+pub const GREY_23 : RGBA<u8> = RGBA::from_u32(0x3B3B3BFF);
+
+/// ```grey24``` ```#3D3D3D```
+pub const GREY_24 : RGBA<u8> = RGBA::from_u32(0x3D3D3DFF);
+
+/// ```grey25``` ```#404040```
+pub const GREY_25 : RGBA<u8> = RGBA::from_u32(0x404040FF);
+
+/// ```grey26``` ```#424242```
+pub const GREY_26 : RGBA<u8> = RGBA::from_u32(0x424242FF);
+
+/// ```grey27``` ```#454545```
+pub const GREY_27 : RGBA<u8> = RGBA::from_u32(0x454545FF);
+
+/// ```grey28``` ```#474747```
+pub const GREY_28 : RGBA<u8> = RGBA::from_u32(0x474747FF);
+
+/// ```grey29``` ```#4A4A4A```
+pub const GREY_29 : RGBA<u8> = RGBA::from_u32(0x4A4A4AFF);
+
+/// ```grey3``` ```#080808```
+pub const GREY_3 : RGBA<u8> = RGBA::from_u32(0x080808FF);
+
+/// ```grey30``` ```#4D4D4D```
+pub const GREY_30 : RGBA<u8> = RGBA::from_u32(0x4D4D4DFF);
+
+/// ```grey31``` ```#4F4F4F```
+pub const GREY_31 : RGBA<u8> = RGBA::from_u32(0x4F4F4FFF);
+
+/// ```grey32``` ```#525252```
+pub const GREY_32 : RGBA<u8> = RGBA::from_u32(0x525252FF);
+
+/// ```grey33``` ```#545454```
+pub const GREY_33 : RGBA<u8> = RGBA::from_u32(0x545454FF);
+
+/// ```grey34``` ```#575757```
+pub const GREY_34 : RGBA<u8> = RGBA::from_u32(0x575757FF);
+
+/// ```grey35``` ```#595959```
+pub const GREY_35 : RGBA<u8> = RGBA::from_u32(0x595959FF);
+
+/// ```grey36``` ```#5C5C5C```
+pub const GREY_36 : RGBA<u8> = RGBA::from_u32(0x5C5C5CFF);
+
+/// ```grey37``` ```#5E5E5E```
+pub const GREY_37 : RGBA<u8> = RGBA::from_u32(0x5E5E5EFF);
+
+/// ```grey38``` ```#616161```
+pub const GREY_38 : RGBA<u8> = RGBA::from_u32(0x616161FF);
+
+/// ```grey39``` ```#636363```
+pub const GREY_39 : RGBA<u8> = RGBA::from_u32(0x636363FF);
+
+/// ```grey4``` ```#0A0A0A```
+pub const GREY_4 : RGBA<u8> = RGBA::from_u32(0x0A0A0AFF);
+
+/// ```grey40``` ```#666666```
+pub const GREY_40 : RGBA<u8> = RGBA::from_u32(0x666666FF);
+
+/// ```grey41``` ```#696969```
+pub const GREY_41 : RGBA<u8> = RGBA::from_u32(0x696969FF);
+
+/// ```grey42``` ```#6B6B6B```
+pub const GREY_42 : RGBA<u8> = RGBA::from_u32(0x6B6B6BFF);
+
+/// ```grey43``` ```#6E6E6E```
+pub const GREY_43 : RGBA<u8> = RGBA::from_u32(0x6E6E6EFF);
+
+/// ```grey44``` ```#707070```
+pub const GREY_44 : RGBA<u8> = RGBA::from_u32(0x707070FF);
+
+/// ```grey45``` ```#737373```
+pub const GREY_45 : RGBA<u8> = RGBA::from_u32(0x737373FF);
+
+/// ```grey46``` ```#757575```
+pub const GREY_46 : RGBA<u8> = RGBA::from_u32(0x757575FF);
+
+/// ```grey47``` ```#787878```
+pub const GREY_47 : RGBA<u8> = RGBA::from_u32(0x787878FF);
+
+/// ```grey48``` ```#7A7A7A```
+pub const GREY_48 : RGBA<u8> = RGBA::from_u32(0x7A7A7AFF);
+
+/// ```grey49``` ```#7D7D7D```
+pub const GREY_49 : RGBA<u8> = RGBA::from_u32(0x7D7D7DFF);
+
+/// ```grey5``` ```#0D0D0D```
+pub const GREY_5 : RGBA<u8> = RGBA::from_u32(0x0D0D0DFF);
+
+/// ```grey50``` ```#7F7F7F```
+pub const GREY_50 : RGBA<u8> = RGBA::from_u32(0x7F7F7FFF);
+
+/// ```grey51``` ```#828282```
+pub const GREY_51 : RGBA<u8> = RGBA::from_u32(0x828282FF);
+
+/// ```grey52``` ```#858585```
+pub const GREY_52 : RGBA<u8> = RGBA::from_u32(0x858585FF);
+
+/// ```grey53``` ```#878787```
+pub const GREY_53 : RGBA<u8> = RGBA::from_u32(0x878787FF);
+
+/// ```grey54``` ```#8A8A8A```
+pub const GREY_54 : RGBA<u8> = RGBA::from_u32(0x8A8A8AFF);
+
+/// ```grey55``` ```#8C8C8C```
+pub const GREY_55 : RGBA<u8> = RGBA::from_u32(0x8C8C8CFF);
+
+/// ```grey56``` ```#8F8F8F```
+pub const GREY_56 : RGBA<u8> = RGBA::from_u32(0x8F8F8FFF);
+
+/// ```grey57``` ```#919191```
+pub const GREY_57 : RGBA<u8> = RGBA::from_u32(0x919191FF);
+
+/// ```grey58``` ```#949494```
+pub const GREY_58 : RGBA<u8> = RGBA::from_u32(0x949494FF);
+
+/// ```grey59``` ```#969696```
+pub const GREY_59 : RGBA<u8> = RGBA::from_u32(0x969696FF);
+
+/// ```grey6``` ```#0F0F0F```
+pub const GREY_6 : RGBA<u8> = RGBA::from_u32(0x0F0F0FFF);
+
+/// ```grey60``` ```#999999```
+pub const GREY_60 : RGBA<u8> = RGBA::from_u32(0x999999FF);
+
+/// ```grey61``` ```#9C9C9C```
+pub const GREY_61 : RGBA<u8> = RGBA::from_u32(0x9C9C9CFF);
+
+/// ```grey62``` ```#9E9E9E```
+pub const GREY_62 : RGBA<u8> = RGBA::from_u32(0x9E9E9EFF);
+
+/// ```grey63``` ```#A1A1A1```
+pub const GREY_63 : RGBA<u8> = RGBA::from_u32(0xA1A1A1FF);
+
+/// ```grey64``` ```#A3A3A3```
+pub const GREY_64 : RGBA<u8> = RGBA::from_u32(0xA3A3A3FF);
+
+/// ```grey65``` ```#A6A6A6```
+pub const GREY_65 : RGBA<u8> = RGBA::from_u32(0xA6A6A6FF);
+
+/// ```grey66``` ```#A8A8A8```
+pub const GREY_66 : RGBA<u8> = RGBA::from_u32(0xA8A8A8FF);
+
+/// ```grey67``` ```#ABABAB```
+pub const GREY_67 : RGBA<u8> = RGBA::from_u32(0xABABABFF);
+
+/// ```grey68``` ```#ADADAD```
+pub const GREY_68 : RGBA<u8> = RGBA::from_u32(0xADADADFF);
+
+/// ```grey69``` ```#B0B0B0```
+pub const GREY_69 : RGBA<u8> = RGBA::from_u32(0xB0B0B0FF);
+
+/// ```grey7``` ```#121212```
+pub const GREY_7 : RGBA<u8> = RGBA::from_u32(0x121212FF);
+
+/// ```grey70``` ```#B3B3B3```
+pub const GREY_70 : RGBA<u8> = RGBA::from_u32(0xB3B3B3FF);
+
+/// ```grey71``` ```#B5B5B5```
+pub const GREY_71 : RGBA<u8> = RGBA::from_u32(0xB5B5B5FF);
+
+/// ```grey72``` ```#B8B8B8```
+pub const GREY_72 : RGBA<u8> = RGBA::from_u32(0xB8B8B8FF);
+
+/// ```grey73``` ```#BABABA```
+pub const GREY_73 : RGBA<u8> = RGBA::from_u32(0xBABABAFF);
+
+/// ```grey74``` ```#BDBDBD```
+pub const GREY_74 : RGBA<u8> = RGBA::from_u32(0xBDBDBDFF);
+
+/// ```grey75``` ```#BFBFBF```
+pub const GREY_75 : RGBA<u8> = RGBA::from_u32(0xBFBFBFFF);
+
+/// ```grey76``` ```#C2C2C2```
+pub const GREY_76 : RGBA<u8> = RGBA::from_u32(0xC2C2C2FF);
+
+/// ```grey77``` ```#C4C4C4```
+pub const GREY_77 : RGBA<u8> = RGBA::from_u32(0xC4C4C4FF);
+
+/// ```grey78``` ```#C7C7C7```
+pub const GREY_78 : RGBA<u8> = RGBA::from_u32(0xC7C7C7FF);
+
+/// ```grey79``` ```#C9C9C9```
+pub const GREY_79 : RGBA<u8> = RGBA::from_u32(0xC9C9C9FF);
+
+/// ```grey8``` ```#141414```
+pub const GREY_8 : RGBA<u8> = RGBA::from_u32(0x141414FF);
+
+/// ```grey80``` ```#CCCCCC```
+pub const GREY_80 : RGBA<u8> = RGBA::from_u32(0xCCCCCCFF);
+
+/// ```grey81``` ```#CFCFCF```
+pub const GREY_81 : RGBA<u8> = RGBA::from_u32(0xCFCFCFFF);
+
+/// ```grey82``` ```#D1D1D1```
+pub const GREY_82 : RGBA<u8> = RGBA::from_u32(0xD1D1D1FF);
+
+/// ```grey83``` ```#D4D4D4```
+pub const GREY_83 : RGBA<u8> = RGBA::from_u32(0xD4D4D4FF);
+
+/// ```grey84``` ```#D6D6D6```
+pub const GREY_84 : RGBA<u8> = RGBA::from_u32(0xD6D6D6FF);
+
+/// ```grey85``` ```#D9D9D9```
+pub const GREY_85 : RGBA<u8> = RGBA::from_u32(0xD9D9D9FF);
+
+/// ```grey86``` ```#DBDBDB```
+pub const GREY_86 : RGBA<u8> = RGBA::from_u32(0xDBDBDBFF);
+
+/// ```grey87``` ```#DEDEDE```
+pub const GREY_87 : RGBA<u8> = RGBA::from_u32(0xDEDEDEFF);
+
+/// ```grey88``` ```#E0E0E0```
+pub const GREY_88 : RGBA<u8> = RGBA::from_u32(0xE0E0E0FF);
+
+/// ```grey89``` ```#E3E3E3```
+pub const GREY_89 : RGBA<u8> = RGBA::from_u32(0xE3E3E3FF);
+
+/// ```grey9``` ```#171717```
+pub const GREY_9 : RGBA<u8> = RGBA::from_u32(0x171717FF);
+
+/// ```grey90``` ```#E5E5E5```
+pub const GREY_90 : RGBA<u8> = RGBA::from_u32(0xE5E5E5FF);
+
+/// ```grey91``` ```#E8E8E8```
+pub const GREY_91 : RGBA<u8> = RGBA::from_u32(0xE8E8E8FF);
+
+/// ```grey92``` ```#EBEBEB```
+pub const GREY_92 : RGBA<u8> = RGBA::from_u32(0xEBEBEBFF);
+
+/// ```grey93``` ```#EDEDED```
+pub const GREY_93 : RGBA<u8> = RGBA::from_u32(0xEDEDEDFF);
+
+/// ```grey94``` ```#F0F0F0```
+pub const GREY_94 : RGBA<u8> = RGBA::from_u32(0xF0F0F0FF);
+
+/// ```grey95``` ```#F2F2F2```
+pub const GREY_95 : RGBA<u8> = RGBA::from_u32(0xF2F2F2FF);
+
+/// ```grey96``` ```#F5F5F5```
+pub const GREY_96 : RGBA<u8> = RGBA::from_u32(0xF5F5F5FF);
+
+/// ```grey97``` ```#F7F7F7```
+pub const GREY_97 : RGBA<u8> = RGBA::from_u32(0xF7F7F7FF);
+
+/// ```grey98``` ```#FAFAFA```
+pub const GREY_98 : RGBA<u8> = RGBA::from_u32(0xFAFAFAFF);
+
+/// ```grey99``` ```#FCFCFC```
+pub const GREY_99 : RGBA<u8> = RGBA::from_u32(0xFCFCFCFF);
+
+/// ```honeydew``` ```#F0FFF0```
+pub const HONEYDEW : RGBA<u8> = RGBA::from_u32(0xF0FFF0FF);
+
+/// ```honeydew1``` ```#F0FFF0```
+pub const HONEYDEW_1 : RGBA<u8> = RGBA::from_u32(0xF0FFF0FF);
+
+/// ```honeydew2``` ```#E0EEE0```
+pub const HONEYDEW_2 : RGBA<u8> = RGBA::from_u32(0xE0EEE0FF);
+
+/// ```honeydew3``` ```#C1CDC1```
+pub const HONEYDEW_3 : RGBA<u8> = RGBA::from_u32(0xC1CDC1FF);
+
+/// ```honeydew4``` ```#838B83```
+pub const HONEYDEW_4 : RGBA<u8> = RGBA::from_u32(0x838B83FF);
+
+/// ```hotpink``` ```#FF69B4```
+pub const HOT_PINK : RGBA<u8> = RGBA::from_u32(0xFF69B4FF);
+
+/// ```hotpink1``` ```#FF6EB4```
+pub const HOT_PINK_1 : RGBA<u8> = RGBA::from_u32(0xFF6EB4FF);
+
+/// ```hotpink2``` ```#EE6AA7```
+pub const HOT_PINK_2 : RGBA<u8> = RGBA::from_u32(0xEE6AA7FF);
+
+/// ```hotpink3``` ```#CD6090```
+pub const HOT_PINK_3 : RGBA<u8> = RGBA::from_u32(0xCD6090FF);
+
+/// ```hotpink4``` ```#8B3A62```
+pub const HOT_PINK_4 : RGBA<u8> = RGBA::from_u32(0x8B3A62FF);
+
+/// ```indianred``` ```#CD5C5C```
+pub const INDIAN_RED : RGBA<u8> = RGBA::from_u32(0xCD5C5CFF);
+
+/// ```indianred1``` ```#FF6A6A```
+pub const INDIAN_RED_1 : RGBA<u8> = RGBA::from_u32(0xFF6A6AFF);
+
+/// ```indianred2``` ```#EE6363```
+pub const INDIAN_RED_2 : RGBA<u8> = RGBA::from_u32(0xEE6363FF);
+
+/// ```indianred3``` ```#CD5555```
+pub const INDIAN_RED_3 : RGBA<u8> = RGBA::from_u32(0xCD5555FF);
+
+/// ```indianred4``` ```#8B3A3A```
+pub const INDIAN_RED_4 : RGBA<u8> = RGBA::from_u32(0x8B3A3AFF);
+
+/// ```ivory``` ```#FFFFF0```
+pub const IVORY : RGBA<u8> = RGBA::from_u32(0xFFFFF0FF);
+
+/// ```ivory1``` ```#FFFFF0```
+pub const IVORY_1 : RGBA<u8> = RGBA::from_u32(0xFFFFF0FF);
+
+/// ```ivory2``` ```#EEEEE0```
+pub const IVORY_2 : RGBA<u8> = RGBA::from_u32(0xEEEEE0FF);
+
+/// ```ivory3``` ```#CDCDC1```
+pub const IVORY_3 : RGBA<u8> = RGBA::from_u32(0xCDCDC1FF);
+
+/// ```ivory4``` ```#8B8B83```
+pub const IVORY_4 : RGBA<u8> = RGBA::from_u32(0x8B8B83FF);
+
+/// ```khaki``` ```#F0E68C```
+pub const KHAKI : RGBA<u8> = RGBA::from_u32(0xF0E68CFF);
+
+/// ```khaki1``` ```#FFF68F```
+pub const KHAKI_1 : RGBA<u8> = RGBA::from_u32(0xFFF68FFF);
+
+/// ```khaki2``` ```#EEE685```
+pub const KHAKI_2 : RGBA<u8> = RGBA::from_u32(0xEEE685FF);
+
+/// ```khaki3``` ```#CDC673```
+pub const KHAKI_3 : RGBA<u8> = RGBA::from_u32(0xCDC673FF);
+
+/// ```khaki4``` ```#8B864E```
+pub const KHAKI_4 : RGBA<u8> = RGBA::from_u32(0x8B864EFF);
+
+/// ```lavender``` ```#E6E6FA```
+pub const LAVENDER : RGBA<u8> = RGBA::from_u32(0xE6E6FAFF);
+
+/// ```lavenderblush``` ```#FFF0F5```
+pub const LAVENDER_BLUSH : RGBA<u8> = RGBA::from_u32(0xFFF0F5FF);
+
+/// ```lavenderblush1``` ```#FFF0F5```
+pub const LAVENDER_BLUSH_1 : RGBA<u8> = RGBA::from_u32(0xFFF0F5FF);
+
+/// ```lavenderblush2``` ```#EEE0E5```
+pub const LAVENDER_BLUSH_2 : RGBA<u8> = RGBA::from_u32(0xEEE0E5FF);
+
+/// ```lavenderblush3``` ```#CDC1C5```
+pub const LAVENDER_BLUSH_3 : RGBA<u8> = RGBA::from_u32(0xCDC1C5FF);
+
+/// ```lavenderblush4``` ```#8B8386```
+pub const LAVENDER_BLUSH_4 : RGBA<u8> = RGBA::from_u32(0x8B8386FF);
+
+/// ```lawngreen``` ```#7CFC00```
+pub const LAWN_GREEN : RGBA<u8> = RGBA::from_u32(0x7CFC00FF);
+
+/// ```lemonchiffon``` ```#FFFACD```
+pub const LEMON_CHIFFON : RGBA<u8> = RGBA::from_u32(0xFFFACDFF);
+
+/// ```lemonchiffon1``` ```#FFFACD```
+pub const LEMON_CHIFFON_1 : RGBA<u8> = RGBA::from_u32(0xFFFACDFF);
+
+/// ```lemonchiffon2``` ```#EEE9BF```
+pub const LEMON_CHIFFON_2 : RGBA<u8> = RGBA::from_u32(0xEEE9BFFF);
+
+/// ```lemonchiffon3``` ```#CDC9A5```
+pub const LEMON_CHIFFON_3 : RGBA<u8> = RGBA::from_u32(0xCDC9A5FF);
+
+/// ```lemonchiffon4``` ```#8B8970```
+pub const LEMON_CHIFFON_4 : RGBA<u8> = RGBA::from_u32(0x8B8970FF);
+
+/// ```lightblue``` ```#ADD8E6```
+pub const LIGHT_BLUE : RGBA<u8> = RGBA::from_u32(0xADD8E6FF);
+
+/// ```lightblue1``` ```#BFEFFF```
+pub const LIGHT_BLUE_1 : RGBA<u8> = RGBA::from_u32(0xBFEFFFFF);
+
+/// ```lightblue2``` ```#B2DFEE```
+pub const LIGHT_BLUE_2 : RGBA<u8> = RGBA::from_u32(0xB2DFEEFF);
+
+/// ```lightblue3``` ```#9AC0CD```
+pub const LIGHT_BLUE_3 : RGBA<u8> = RGBA::from_u32(0x9AC0CDFF);
+
+/// ```lightblue4``` ```#68838B```
+pub const LIGHT_BLUE_4 : RGBA<u8> = RGBA::from_u32(0x68838BFF);
+
+/// ```lightcoral``` ```#F08080```
+pub const LIGHT_CORAL : RGBA<u8> = RGBA::from_u32(0xF08080FF);
+
+/// ```lightcyan``` ```#E0FFFF```
+pub const LIGHT_CYAN : RGBA<u8> = RGBA::from_u32(0xE0FFFFFF);
+
+/// ```lightcyan1``` ```#E0FFFF```
+pub const LIGHT_CYAN_1 : RGBA<u8> = RGBA::from_u32(0xE0FFFFFF);
+
+/// ```lightcyan2``` ```#D1EEEE```
+pub const LIGHT_CYAN_2 : RGBA<u8> = RGBA::from_u32(0xD1EEEEFF);
+
+/// ```lightcyan3``` ```#B4CDCD```
+pub const LIGHT_CYAN_3 : RGBA<u8> = RGBA::from_u32(0xB4CDCDFF);
+
+/// ```lightcyan4``` ```#7A8B8B```
+pub const LIGHT_CYAN_4 : RGBA<u8> = RGBA::from_u32(0x7A8B8BFF);
+
+/// ```lightgoldenrod``` ```#EEDD82```
+pub const LIGHT_GOLDENROD : RGBA<u8> = RGBA::from_u32(0xEEDD82FF);
+
+/// ```lightgoldenrod1``` ```#FFEC8B```
+pub const LIGHT_GOLDENROD_1 : RGBA<u8> = RGBA::from_u32(0xFFEC8BFF);
+
+/// ```lightgoldenrod2``` ```#EEDC82```
+pub const LIGHT_GOLDENROD_2 : RGBA<u8> = RGBA::from_u32(0xEEDC82FF);
+
+/// ```lightgoldenrod3``` ```#CDBE70```
+pub const LIGHT_GOLDENROD_3 : RGBA<u8> = RGBA::from_u32(0xCDBE70FF);
+
+/// ```lightgoldenrod4``` ```#8B814C```
+pub const LIGHT_GOLDENROD_4 : RGBA<u8> = RGBA::from_u32(0x8B814CFF);
+
+/// ```lightgoldenrodyellow``` ```#FAFAD2```
+pub const LIGHT_GOLDENROD_YELLOW : RGBA<u8> = RGBA::from_u32(0xFAFAD2FF);
+
+/// ```lightgray``` ```#D3D3D3```
+pub const LIGHT_GRAY : RGBA<u8> = RGBA::from_u32(0xD3D3D3FF);
+
+/// ```lightgreen``` ```#90EE90```
+pub const LIGHT_GREEN : RGBA<u8> = RGBA::from_u32(0x90EE90FF);
+
+/// ```lightgrey``` ```#D3D3D3```
+pub const LIGHT_GREY : RGBA<u8> = RGBA::from_u32(0xD3D3D3FF);
+
+/// ```lightpink``` ```#FFB6C1```
+pub const LIGHT_PINK : RGBA<u8> = RGBA::from_u32(0xFFB6C1FF);
+
+/// ```lightpink1``` ```#FFAEB9```
+pub const LIGHT_PINK_1 : RGBA<u8> = RGBA::from_u32(0xFFAEB9FF);
+
+/// ```lightpink2``` ```#EEA2AD```
+pub const LIGHT_PINK_2 : RGBA<u8> = RGBA::from_u32(0xEEA2ADFF);
+
+/// ```lightpink3``` ```#CD8C95```
+pub const LIGHT_PINK_3 : RGBA<u8> = RGBA::from_u32(0xCD8C95FF);
+
+/// ```lightpink4``` ```#8B5F65```
+pub const LIGHT_PINK_4 : RGBA<u8> = RGBA::from_u32(0x8B5F65FF);
+
+/// ```lightsalmon``` ```#FFA07A```
+pub const LIGHT_SALMON : RGBA<u8> = RGBA::from_u32(0xFFA07AFF);
+
+/// ```lightsalmon1``` ```#FFA07A```
+pub const LIGHT_SALMON_1 : RGBA<u8> = RGBA::from_u32(0xFFA07AFF);
+
+/// ```lightsalmon2``` ```#EE9572```
+pub const LIGHT_SALMON_2 : RGBA<u8> = RGBA::from_u32(0xEE9572FF);
+
+/// ```lightsalmon3``` ```#CD8162```
+pub const LIGHT_SALMON_3 : RGBA<u8> = RGBA::from_u32(0xCD8162FF);
+
+/// ```lightsalmon4``` ```#8B5742```
+pub const LIGHT_SALMON_4 : RGBA<u8> = RGBA::from_u32(0x8B5742FF);
+
+/// ```lightseagreen``` ```#20B2AA```
+pub const LIGHT_SEA_GREEN : RGBA<u8> = RGBA::from_u32(0x20B2AAFF);
+
+/// ```lightskyblue``` ```#87CEFA```
+pub const LIGHT_SKY_BLUE : RGBA<u8> = RGBA::from_u32(0x87CEFAFF);
+
+/// ```lightskyblue1``` ```#B0E2FF```
+pub const LIGHT_SKY_BLUE_1 : RGBA<u8> = RGBA::from_u32(0xB0E2FFFF);
+
+/// ```lightskyblue2``` ```#A4D3EE```
+pub const LIGHT_SKY_BLUE_2 : RGBA<u8> = RGBA::from_u32(0xA4D3EEFF);
+
+/// ```lightskyblue3``` ```#8DB6CD```
+pub const LIGHT_SKY_BLUE_3 : RGBA<u8> = RGBA::from_u32(0x8DB6CDFF);
+
+/// ```lightskyblue4``` ```#607B8B```
+pub const LIGHT_SKY_BLUE_4 : RGBA<u8> = RGBA::from_u32(0x607B8BFF);
+
+/// ```lightslateblue``` ```#8470FF```
+pub const LIGHT_SLATE_BLUE : RGBA<u8> = RGBA::from_u32(0x8470FFFF);
+
+/// ```lightslategray``` ```#778899```
+pub const LIGHT_SLATE_GRAY : RGBA<u8> = RGBA::from_u32(0x778899FF);
+
+/// ```lightslategrey``` ```#778899```
+pub const LIGHT_SLATE_GREY : RGBA<u8> = RGBA::from_u32(0x778899FF);
+
+/// ```lightsteelblue``` ```#B0C4DE```
+pub const LIGHT_STEEL_BLUE : RGBA<u8> = RGBA::from_u32(0xB0C4DEFF);
+
+/// ```lightsteelblue1``` ```#CAE1FF```
+pub const LIGHT_STEEL_BLUE_1 : RGBA<u8> = RGBA::from_u32(0xCAE1FFFF);
+
+/// ```lightsteelblue2``` ```#BCD2EE```
+pub const LIGHT_STEEL_BLUE_2 : RGBA<u8> = RGBA::from_u32(0xBCD2EEFF);
+
+/// ```lightsteelblue3``` ```#A2B5CD```
+pub const LIGHT_STEEL_BLUE_3 : RGBA<u8> = RGBA::from_u32(0xA2B5CDFF);
+
+/// ```lightsteelblue4``` ```#6E7B8B```
+pub const LIGHT_STEEL_BLUE_4 : RGBA<u8> = RGBA::from_u32(0x6E7B8BFF);
+
+/// ```lightyellow``` ```#FFFFE0```
+pub const LIGHT_YELLOW : RGBA<u8> = RGBA::from_u32(0xFFFFE0FF);
+
+/// ```lightyellow1``` ```#FFFFE0```
+pub const LIGHT_YELLOW_1 : RGBA<u8> = RGBA::from_u32(0xFFFFE0FF);
+
+/// ```lightyellow2``` ```#EEEED1```
+pub const LIGHT_YELLOW_2 : RGBA<u8> = RGBA::from_u32(0xEEEED1FF);
+
+/// ```lightyellow3``` ```#CDCDB4```
+pub const LIGHT_YELLOW_3 : RGBA<u8> = RGBA::from_u32(0xCDCDB4FF);
+
+/// ```lightyellow4``` ```#8B8B7A```
+pub const LIGHT_YELLOW_4 : RGBA<u8> = RGBA::from_u32(0x8B8B7AFF);
+
+/// ```limegreen``` ```#32CD32```
+pub const LIME_GREEN : RGBA<u8> = RGBA::from_u32(0x32CD32FF);
+
+/// ```linen``` ```#FAF0E6```
+pub const LINEN : RGBA<u8> = RGBA::from_u32(0xFAF0E6FF);
+
+/// ```magenta``` ```#FF00FF```
+pub const MAGENTA : RGBA<u8> = RGBA::from_u32(0xFF00FFFF);
+
+/// ```magenta1``` ```#FF00FF```
+pub const MAGENTA_1 : RGBA<u8> = RGBA::from_u32(0xFF00FFFF);
+
+/// ```magenta2``` ```#EE00EE```
+pub const MAGENTA_2 : RGBA<u8> = RGBA::from_u32(0xEE00EEFF);
+
+/// ```magenta3``` ```#CD00CD```
+pub const MAGENTA_3 : RGBA<u8> = RGBA::from_u32(0xCD00CDFF);
+
+/// ```magenta4``` ```#8B008B```
+pub const MAGENTA_4 : RGBA<u8> = RGBA::from_u32(0x8B008BFF);
+
+/// ```maroon``` ```#B03060```
+pub const MAROON : RGBA<u8> = RGBA::from_u32(0xB03060FF);
+
+/// ```maroon1``` ```#FF34B3```
+pub const MAROON_1 : RGBA<u8> = RGBA::from_u32(0xFF34B3FF);
+
+/// ```maroon2``` ```#EE30A7```
+pub const MAROON_2 : RGBA<u8> = RGBA::from_u32(0xEE30A7FF);
+
+/// ```maroon3``` ```#CD2990```
+pub const MAROON_3 : RGBA<u8> = RGBA::from_u32(0xCD2990FF);
+
+/// ```maroon4``` ```#8B1C62```
+pub const MAROON_4 : RGBA<u8> = RGBA::from_u32(0x8B1C62FF);
+
+/// ```mediumaquamarine``` ```#66CDAA```
+pub const MEDIUM_AQUAMARINE : RGBA<u8> = RGBA::from_u32(0x66CDAAFF);
+
+/// ```mediumblue``` ```#0000CD```
+pub const MEDIUM_BLUE : RGBA<u8> = RGBA::from_u32(0x0000CDFF);
+
+/// ```mediumorchid``` ```#BA55D3```
+pub const MEDIUM_ORCHID : RGBA<u8> = RGBA::from_u32(0xBA55D3FF);
+
+/// ```mediumorchid1``` ```#E066FF```
+pub const MEDIUM_ORCHID_1 : RGBA<u8> = RGBA::from_u32(0xE066FFFF);
+
+/// ```mediumorchid2``` ```#D15FEE```
+pub const MEDIUM_ORCHID_2 : RGBA<u8> = RGBA::from_u32(0xD15FEEFF);
+
+/// ```mediumorchid3``` ```#B452CD```
+pub const MEDIUM_ORCHID_3 : RGBA<u8> = RGBA::from_u32(0xB452CDFF);
+
+/// ```mediumorchid4``` ```#7A378B```
+pub const MEDIUM_ORCHID_4 : RGBA<u8> = RGBA::from_u32(0x7A378BFF);
+
+/// ```mediumpurple``` ```#9370DB```
+pub const MEDIUM_PURPLE : RGBA<u8> = RGBA::from_u32(0x9370DBFF);
+
+/// ```mediumpurple1``` ```#AB82FF```
+pub const MEDIUM_PURPLE_1 : RGBA<u8> = RGBA::from_u32(0xAB82FFFF);
+
+/// ```mediumpurple2``` ```#9F79EE```
+pub const MEDIUM_PURPLE_2 : RGBA<u8> = RGBA::from_u32(0x9F79EEFF);
+
+/// ```mediumpurple3``` ```#8968CD```
+pub const MEDIUM_PURPLE_3 : RGBA<u8> = RGBA::from_u32(0x8968CDFF);
+
+/// ```mediumpurple4``` ```#5D478B```
+pub const MEDIUM_PURPLE_4 : RGBA<u8> = RGBA::from_u32(0x5D478BFF);
+
+/// ```mediumseagreen``` ```#3CB371```
+pub const MEDIUM_SEA_GREEN : RGBA<u8> = RGBA::from_u32(0x3CB371FF);
+
+/// ```mediumslateblue``` ```#7B68EE```
+pub const MEDIUM_SLATE_BLUE : RGBA<u8> = RGBA::from_u32(0x7B68EEFF);
+
+/// ```mediumspringgreen``` ```#00FA9A```
+pub const MEDIUM_SPRING_GREEN : RGBA<u8> = RGBA::from_u32(0x00FA9AFF);
+
+/// ```mediumturquoise``` ```#48D1CC```
+pub const MEDIUM_TURQUOISE : RGBA<u8> = RGBA::from_u32(0x48D1CCFF);
+
+/// ```mediumvioletred``` ```#C71585```
+pub const MEDIUM_VIOLET_RED : RGBA<u8> = RGBA::from_u32(0xC71585FF);
+
+/// ```midnightblue``` ```#191970```
+pub const MIDNIGHT_BLUE : RGBA<u8> = RGBA::from_u32(0x191970FF);
+
+/// ```mintcream``` ```#F5FFFA```
+pub const MINT_CREAM : RGBA<u8> = RGBA::from_u32(0xF5FFFAFF);
+
+/// ```mistyrose``` ```#FFE4E1```
+pub const MISTY_ROSE : RGBA<u8> = RGBA::from_u32(0xFFE4E1FF);
+
+/// ```mistyrose1``` ```#FFE4E1```
+pub const MISTY_ROSE_1 : RGBA<u8> = RGBA::from_u32(0xFFE4E1FF);
+
+/// ```mistyrose2``` ```#EED5D2```
+pub const MISTY_ROSE_2 : RGBA<u8> = RGBA::from_u32(0xEED5D2FF);
+
+/// ```mistyrose3``` ```#CDB7B5```
+pub const MISTY_ROSE_3 : RGBA<u8> = RGBA::from_u32(0xCDB7B5FF);
+
+/// ```mistyrose4``` ```#8B7D7B```
+pub const MISTY_ROSE_4 : RGBA<u8> = RGBA::from_u32(0x8B7D7BFF);
+
+/// ```moccasin``` ```#FFE4B5```
+pub const MOCCASIN : RGBA<u8> = RGBA::from_u32(0xFFE4B5FF);
+
+/// ```navajowhite``` ```#FFDEAD```
+pub const NAVAJO_WHITE : RGBA<u8> = RGBA::from_u32(0xFFDEADFF);
+
+/// ```navajowhite1``` ```#FFDEAD```
+pub const NAVAJO_WHITE_1 : RGBA<u8> = RGBA::from_u32(0xFFDEADFF);
+
+/// ```navajowhite2``` ```#EECFA1```
+pub const NAVAJO_WHITE_2 : RGBA<u8> = RGBA::from_u32(0xEECFA1FF);
+
+/// ```navajowhite3``` ```#CDB38B```
+pub const NAVAJO_WHITE_3 : RGBA<u8> = RGBA::from_u32(0xCDB38BFF);
+
+/// ```navajowhite4``` ```#8B795E```
+pub const NAVAJO_WHITE_4 : RGBA<u8> = RGBA::from_u32(0x8B795EFF);
+
+/// ```navy``` ```#000080```
+pub const NAVY : RGBA<u8> = RGBA::from_u32(0x000080FF);
+
+/// ```navyblue``` ```#000080```
+pub const NAVY_BLUE : RGBA<u8> = RGBA::from_u32(0x000080FF);
+
+/// ```oldlace``` ```#FDF5E6```
+pub const OLD_LACE : RGBA<u8> = RGBA::from_u32(0xFDF5E6FF);
+
+/// ```olivedrab``` ```#6B8E23```
+pub const OLIVE_DRAB : RGBA<u8> = RGBA::from_u32(0x6B8E23FF);
+
+/// ```olivedrab1``` ```#C0FF3E```
+pub const OLIVE_DRAB_1 : RGBA<u8> = RGBA::from_u32(0xC0FF3EFF);
+
+/// ```olivedrab2``` ```#B3EE3A```
+pub const OLIVE_DRAB_2 : RGBA<u8> = RGBA::from_u32(0xB3EE3AFF);
+
+/// ```olivedrab3``` ```#9ACD32```
+pub const OLIVE_DRAB_3 : RGBA<u8> = RGBA::from_u32(0x9ACD32FF);
+
+/// ```olivedrab4``` ```#698B22```
+pub const OLIVE_DRAB_4 : RGBA<u8> = RGBA::from_u32(0x698B22FF);
+
+/// ```orange``` ```#FFA500```
+pub const ORANGE : RGBA<u8> = RGBA::from_u32(0xFFA500FF);
+
+/// ```orange1``` ```#FFA500```
+pub const ORANGE_1 : RGBA<u8> = RGBA::from_u32(0xFFA500FF);
+
+/// ```orange2``` ```#EE9A00```
+pub const ORANGE_2 : RGBA<u8> = RGBA::from_u32(0xEE9A00FF);
+
+/// ```orange3``` ```#CD8500```
+pub const ORANGE_3 : RGBA<u8> = RGBA::from_u32(0xCD8500FF);
+
+/// ```orange4``` ```#8B5A00```
+pub const ORANGE_4 : RGBA<u8> = RGBA::from_u32(0x8B5A00FF);
+
+/// ```orangered``` ```#FF4500```
+pub const ORANGE_RED : RGBA<u8> = RGBA::from_u32(0xFF4500FF);
+
+/// ```orangered1``` ```#FF4500```
+pub const ORANGE_RED_1 : RGBA<u8> = RGBA::from_u32(0xFF4500FF);
+
+/// ```orangered2``` ```#EE4000```
+pub const ORANGE_RED_2 : RGBA<u8> = RGBA::from_u32(0xEE4000FF);
+
+/// ```orangered3``` ```#CD3700```
+pub const ORANGE_RED_3 : RGBA<u8> = RGBA::from_u32(0xCD3700FF);
+
+/// ```orangered4``` ```#8B2500```
+pub const ORANGE_RED_4 : RGBA<u8> = RGBA::from_u32(0x8B2500FF);
+
+/// ```orchid``` ```#DA70D6```
+pub const ORCHID : RGBA<u8> = RGBA::from_u32(0xDA70D6FF);
+
+/// ```orchid1``` ```#FF83FA```
+pub const ORCHID_1 : RGBA<u8> = RGBA::from_u32(0xFF83FAFF);
+
+/// ```orchid2``` ```#EE7AE9```
+pub const ORCHID_2 : RGBA<u8> = RGBA::from_u32(0xEE7AE9FF);
+
+/// ```orchid3``` ```#CD69C9```
+pub const ORCHID_3 : RGBA<u8> = RGBA::from_u32(0xCD69C9FF);
+
+/// ```orchid4``` ```#8B4789```
+pub const ORCHID_4 : RGBA<u8> = RGBA::from_u32(0x8B4789FF);
+
+/// ```palegoldenrod``` ```#EEE8AA```
+pub const PALE_GOLDENROD : RGBA<u8> = RGBA::from_u32(0xEEE8AAFF);
+
+/// ```palegreen``` ```#98FB98```
+pub const PALE_GREEN : RGBA<u8> = RGBA::from_u32(0x98FB98FF);
+
+/// ```palegreen1``` ```#9AFF9A```
+pub const PALE_GREEN_1 : RGBA<u8> = RGBA::from_u32(0x9AFF9AFF);
+
+/// ```palegreen2``` ```#90EE90```
+pub const PALE_GREEN_2 : RGBA<u8> = RGBA::from_u32(0x90EE90FF);
+
+/// ```palegreen3``` ```#7CCD7C```
+pub const PALE_GREEN_3 : RGBA<u8> = RGBA::from_u32(0x7CCD7CFF);
+
+/// ```palegreen4``` ```#548B54```
+pub const PALE_GREEN_4 : RGBA<u8> = RGBA::from_u32(0x548B54FF);
+
+/// ```paleturquoise``` ```#AFEEEE```
+pub const PALE_TURQUOISE : RGBA<u8> = RGBA::from_u32(0xAFEEEEFF);
+
+/// ```paleturquoise1``` ```#BBFFFF```
+pub const PALE_TURQUOISE_1 : RGBA<u8> = RGBA::from_u32(0xBBFFFFFF);
+
+/// ```paleturquoise2``` ```#AEEEEE```
+pub const PALE_TURQUOISE_2 : RGBA<u8> = RGBA::from_u32(0xAEEEEEFF);
+
+/// ```paleturquoise3``` ```#96CDCD```
+pub const PALE_TURQUOISE_3 : RGBA<u8> = RGBA::from_u32(0x96CDCDFF);
+
+/// ```paleturquoise4``` ```#668B8B```
+pub const PALE_TURQUOISE_4 : RGBA<u8> = RGBA::from_u32(0x668B8BFF);
+
+/// ```palevioletred``` ```#DB7093```
+pub const PALE_VIOLET_RED : RGBA<u8> = RGBA::from_u32(0xDB7093FF);
+
+/// ```palevioletred1``` ```#FF82AB```
+pub const PALE_VIOLET_RED_1 : RGBA<u8> = RGBA::from_u32(0xFF82ABFF);
+
+/// ```palevioletred2``` ```#EE799F```
+pub const PALE_VIOLET_RED_2 : RGBA<u8> = RGBA::from_u32(0xEE799FFF);
+
+/// ```palevioletred3``` ```#CD6889```
+pub const PALE_VIOLET_RED_3 : RGBA<u8> = RGBA::from_u32(0xCD6889FF);
+
+/// ```palevioletred4``` ```#8B475D```
+pub const PALE_VIOLET_RED_4 : RGBA<u8> = RGBA::from_u32(0x8B475DFF);
+
+/// ```papayawhip``` ```#FFEFD5```
+pub const PAPAYA_WHIP : RGBA<u8> = RGBA::from_u32(0xFFEFD5FF);
+
+/// ```peachpuff``` ```#FFDAB9```
+pub const PEACH_PUFF : RGBA<u8> = RGBA::from_u32(0xFFDAB9FF);
+
+/// ```peachpuff1``` ```#FFDAB9```
+pub const PEACH_PUFF_1 : RGBA<u8> = RGBA::from_u32(0xFFDAB9FF);
+
+/// ```peachpuff2``` ```#EECBAD```
+pub const PEACH_PUFF_2 : RGBA<u8> = RGBA::from_u32(0xEECBADFF);
+
+/// ```peachpuff3``` ```#CDAF95```
+pub const PEACH_PUFF_3 : RGBA<u8> = RGBA::from_u32(0xCDAF95FF);
+
+/// ```peachpuff4``` ```#8B7765```
+pub const PEACH_PUFF_4 : RGBA<u8> = RGBA::from_u32(0x8B7765FF);
+
+/// ```peru``` ```#CD853F```
+pub const PERU : RGBA<u8> = RGBA::from_u32(0xCD853FFF);
+
+/// ```pink``` ```#FFC0CB```
+pub const PINK : RGBA<u8> = RGBA::from_u32(0xFFC0CBFF);
+
+/// ```pink1``` ```#FFB5C5```
+pub const PINK_1 : RGBA<u8> = RGBA::from_u32(0xFFB5C5FF);
+
+/// ```pink2``` ```#EEA9B8```
+pub const PINK_2 : RGBA<u8> = RGBA::from_u32(0xEEA9B8FF);
+
+/// ```pink3``` ```#CD919E```
+pub const PINK_3 : RGBA<u8> = RGBA::from_u32(0xCD919EFF);
+
+/// ```pink4``` ```#8B636C```
+pub const PINK_4 : RGBA<u8> = RGBA::from_u32(0x8B636CFF);
+
+/// ```plum``` ```#DDA0DD```
+pub const PLUM : RGBA<u8> = RGBA::from_u32(0xDDA0DDFF);
+
+/// ```plum1``` ```#FFBBFF```
+pub const PLUM_1 : RGBA<u8> = RGBA::from_u32(0xFFBBFFFF);
+
+/// ```plum2``` ```#EEAEEE```
+pub const PLUM_2 : RGBA<u8> = RGBA::from_u32(0xEEAEEEFF);
+
+/// ```plum3``` ```#CD96CD```
+pub const PLUM_3 : RGBA<u8> = RGBA::from_u32(0xCD96CDFF);
+
+/// ```plum4``` ```#8B668B```
+pub const PLUM_4 : RGBA<u8> = RGBA::from_u32(0x8B668BFF);
+
+/// ```powderblue``` ```#B0E0E6```
+pub const POWDER_BLUE : RGBA<u8> = RGBA::from_u32(0xB0E0E6FF);
+
+/// ```purple``` ```#A020F0```
+pub const PURPLE : RGBA<u8> = RGBA::from_u32(0xA020F0FF);
+
+/// ```purple1``` ```#9B30FF```
+pub const PURPLE_1 : RGBA<u8> = RGBA::from_u32(0x9B30FFFF);
+
+/// ```purple2``` ```#912CEE```
+pub const PURPLE_2 : RGBA<u8> = RGBA::from_u32(0x912CEEFF);
+
+/// ```purple3``` ```#7D26CD```
+pub const PURPLE_3 : RGBA<u8> = RGBA::from_u32(0x7D26CDFF);
+
+/// ```purple4``` ```#551A8B```
+pub const PURPLE_4 : RGBA<u8> = RGBA::from_u32(0x551A8BFF);
+
+/// ```red``` ```#FF0000```
+pub const RED : RGBA<u8> = RGBA::from_u32(0xFF0000FF);
+
+/// ```red1``` ```#FF0000```
+pub const RED_1 : RGBA<u8> = RGBA::from_u32(0xFF0000FF);
+
+/// ```red2``` ```#EE0000```
+pub const RED_2 : RGBA<u8> = RGBA::from_u32(0xEE0000FF);
+
+/// ```red3``` ```#CD0000```
+pub const RED_3 : RGBA<u8> = RGBA::from_u32(0xCD0000FF);
+
+/// ```red4``` ```#8B0000```
+pub const RED_4 : RGBA<u8> = RGBA::from_u32(0x8B0000FF);
+
+/// ```rosybrown``` ```#BC8F8F```
+pub const ROSY_BROWN : RGBA<u8> = RGBA::from_u32(0xBC8F8FFF);
+
+/// ```rosybrown1``` ```#FFC1C1```
+pub const ROSY_BROWN_1 : RGBA<u8> = RGBA::from_u32(0xFFC1C1FF);
+
+/// ```rosybrown2``` ```#EEB4B4```
+pub const ROSY_BROWN_2 : RGBA<u8> = RGBA::from_u32(0xEEB4B4FF);
+
+/// ```rosybrown3``` ```#CD9B9B```
+pub const ROSY_BROWN_3 : RGBA<u8> = RGBA::from_u32(0xCD9B9BFF);
+
+/// ```rosybrown4``` ```#8B6969```
+pub const ROSY_BROWN_4 : RGBA<u8> = RGBA::from_u32(0x8B6969FF);
+
+/// ```royalblue``` ```#4169E1```
+pub const ROYAL_BLUE : RGBA<u8> = RGBA::from_u32(0x4169E1FF);
+
+/// ```royalblue1``` ```#4876FF```
+pub const ROYAL_BLUE_1 : RGBA<u8> = RGBA::from_u32(0x4876FFFF);
+
+/// ```royalblue2``` ```#436EEE```
+pub const ROYAL_BLUE_2 : RGBA<u8> = RGBA::from_u32(0x436EEEFF);
+
+/// ```royalblue3``` ```#3A5FCD```
+pub const ROYAL_BLUE_3 : RGBA<u8> = RGBA::from_u32(0x3A5FCDFF);
+
+/// ```royalblue4``` ```#27408B```
+pub const ROYAL_BLUE_4 : RGBA<u8> = RGBA::from_u32(0x27408BFF);
+
+/// ```saddlebrown``` ```#8B4513```
+pub const SADDLE_BROWN : RGBA<u8> = RGBA::from_u32(0x8B4513FF);
+
+/// ```salmon``` ```#FA8072```
+pub const SALMON : RGBA<u8> = RGBA::from_u32(0xFA8072FF);
+
+/// ```salmon1``` ```#FF8C69```
+pub const SALMON_1 : RGBA<u8> = RGBA::from_u32(0xFF8C69FF);
+
+/// ```salmon2``` ```#EE8262```
+pub const SALMON_2 : RGBA<u8> = RGBA::from_u32(0xEE8262FF);
+
+/// ```salmon3``` ```#CD7054```
+pub const SALMON_3 : RGBA<u8> = RGBA::from_u32(0xCD7054FF);
+
+/// ```salmon4``` ```#8B4C39```
+pub const SALMON_4 : RGBA<u8> = RGBA::from_u32(0x8B4C39FF);
+
+/// ```sandybrown``` ```#F4A460```
+pub const SANDY_BROWN : RGBA<u8> = RGBA::from_u32(0xF4A460FF);
+
+/// ```seagreen``` ```#2E8B57```
+pub const SEA_GREEN : RGBA<u8> = RGBA::from_u32(0x2E8B57FF);
+
+/// ```seagreen1``` ```#54FF9F```
+pub const SEA_GREEN_1 : RGBA<u8> = RGBA::from_u32(0x54FF9FFF);
+
+/// ```seagreen2``` ```#4EEE94```
+pub const SEA_GREEN_2 : RGBA<u8> = RGBA::from_u32(0x4EEE94FF);
+
+/// ```seagreen3``` ```#43CD80```
+pub const SEA_GREEN_3 : RGBA<u8> = RGBA::from_u32(0x43CD80FF);
+
+/// ```seagreen4``` ```#2E8B57```
+pub const SEA_GREEN_4 : RGBA<u8> = RGBA::from_u32(0x2E8B57FF);
+
+/// ```seashell``` ```#FFF5EE```
+pub const SEASHELL : RGBA<u8> = RGBA::from_u32(0xFFF5EEFF);
+
+/// ```seashell1``` ```#FFF5EE```
+pub const SEASHELL_1 : RGBA<u8> = RGBA::from_u32(0xFFF5EEFF);
+
+/// ```seashell2``` ```#EEE5DE```
+pub const SEASHELL_2 : RGBA<u8> = RGBA::from_u32(0xEEE5DEFF);
+
+/// ```seashell3``` ```#CDC5BF```
+pub const SEASHELL_3 : RGBA<u8> = RGBA::from_u32(0xCDC5BFFF);
+
+/// ```seashell4``` ```#8B8682```
+pub const SEASHELL_4 : RGBA<u8> = RGBA::from_u32(0x8B8682FF);
+
+/// ```sienna``` ```#A0522D```
+pub const SIENNA : RGBA<u8> = RGBA::from_u32(0xA0522DFF);
+
+/// ```sienna1``` ```#FF8247```
+pub const SIENNA_1 : RGBA<u8> = RGBA::from_u32(0xFF8247FF);
+
+/// ```sienna2``` ```#EE7942```
+pub const SIENNA_2 : RGBA<u8> = RGBA::from_u32(0xEE7942FF);
+
+/// ```sienna3``` ```#CD6839```
+pub const SIENNA_3 : RGBA<u8> = RGBA::from_u32(0xCD6839FF);
+
+/// ```sienna4``` ```#8B4726```
+pub const SIENNA_4 : RGBA<u8> = RGBA::from_u32(0x8B4726FF);
+
+/// ```skyblue``` ```#87CEEB```
+pub const SKY_BLUE : RGBA<u8> = RGBA::from_u32(0x87CEEBFF);
+
+/// ```skyblue1``` ```#87CEFF```
+pub const SKY_BLUE_1 : RGBA<u8> = RGBA::from_u32(0x87CEFFFF);
+
+/// ```skyblue2``` ```#7EC0EE```
+pub const SKY_BLUE_2 : RGBA<u8> = RGBA::from_u32(0x7EC0EEFF);
+
+/// ```skyblue3``` ```#6CA6CD```
+pub const SKY_BLUE_3 : RGBA<u8> = RGBA::from_u32(0x6CA6CDFF);
+
+/// ```skyblue4``` ```#4A708B```
+pub const SKY_BLUE_4 : RGBA<u8> = RGBA::from_u32(0x4A708BFF);
+
+/// ```slateblue``` ```#6A5ACD```
+pub const SLATE_BLUE : RGBA<u8> = RGBA::from_u32(0x6A5ACDFF);
+
+/// ```slateblue1``` ```#836FFF```
+pub const SLATE_BLUE_1 : RGBA<u8> = RGBA::from_u32(0x836FFFFF);
+
+/// ```slateblue2``` ```#7A67EE```
+pub const SLATE_BLUE_2 : RGBA<u8> = RGBA::from_u32(0x7A67EEFF);
+
+/// ```slateblue3``` ```#6959CD```
+pub const SLATE_BLUE_3 : RGBA<u8> = RGBA::from_u32(0x6959CDFF);
+
+/// ```slateblue4``` ```#473C8B```
+pub const SLATE_BLUE_4 : RGBA<u8> = RGBA::from_u32(0x473C8BFF);
+
+/// ```slategray``` ```#708090```
+pub const SLATE_GRAY : RGBA<u8> = RGBA::from_u32(0x708090FF);
+
+/// ```slategray1``` ```#C6E2FF```
+pub const SLATE_GRAY_1 : RGBA<u8> = RGBA::from_u32(0xC6E2FFFF);
+
+/// ```slategray2``` ```#B9D3EE```
+pub const SLATE_GRAY_2 : RGBA<u8> = RGBA::from_u32(0xB9D3EEFF);
+
+/// ```slategray3``` ```#9FB6CD```
+pub const SLATE_GRAY_3 : RGBA<u8> = RGBA::from_u32(0x9FB6CDFF);
+
+/// ```slategray4``` ```#6C7B8B```
+pub const SLATE_GRAY_4 : RGBA<u8> = RGBA::from_u32(0x6C7B8BFF);
+
+/// ```slategrey``` ```#708090```
+pub const SLATE_GREY : RGBA<u8> = RGBA::from_u32(0x708090FF);
+
+/// ```snow``` ```#FFFAFA```
+pub const SNOW : RGBA<u8> = RGBA::from_u32(0xFFFAFAFF);
+
+/// ```snow1``` ```#FFFAFA```
+pub const SNOW_1 : RGBA<u8> = RGBA::from_u32(0xFFFAFAFF);
+
+/// ```snow2``` ```#EEE9E9```
+pub const SNOW_2 : RGBA<u8> = RGBA::from_u32(0xEEE9E9FF);
+
+/// ```snow3``` ```#CDC9C9```
+pub const SNOW_3 : RGBA<u8> = RGBA::from_u32(0xCDC9C9FF);
+
+/// ```snow4``` ```#8B8989```
+pub const SNOW_4 : RGBA<u8> = RGBA::from_u32(0x8B8989FF);
+
+/// ```springgreen``` ```#00FF7F```
+pub const SPRING_GREEN : RGBA<u8> = RGBA::from_u32(0x00FF7FFF);
+
+/// ```springgreen1``` ```#00FF7F```
+pub const SPRING_GREEN_1 : RGBA<u8> = RGBA::from_u32(0x00FF7FFF);
+
+/// ```springgreen2``` ```#00EE76```
+pub const SPRING_GREEN_2 : RGBA<u8> = RGBA::from_u32(0x00EE76FF);
+
+/// ```springgreen3``` ```#00CD66```
+pub const SPRING_GREEN_3 : RGBA<u8> = RGBA::from_u32(0x00CD66FF);
+
+/// ```springgreen4``` ```#008B45```
+pub const SPRING_GREEN_4 : RGBA<u8> = RGBA::from_u32(0x008B45FF);
+
+/// ```steelblue``` ```#4682B4```
+pub const STEEL_BLUE : RGBA<u8> = RGBA::from_u32(0x4682B4FF);
+
+/// ```steelblue1``` ```#63B8FF```
+pub const STEEL_BLUE_1 : RGBA<u8> = RGBA::from_u32(0x63B8FFFF);
+
+/// ```steelblue2``` ```#5CACEE```
+pub const STEEL_BLUE_2 : RGBA<u8> = RGBA::from_u32(0x5CACEEFF);
+
+/// ```steelblue3``` ```#4F94CD```
+pub const STEEL_BLUE_3 : RGBA<u8> = RGBA::from_u32(0x4F94CDFF);
+
+/// ```steelblue4``` ```#36648B```
+pub const STEEL_BLUE_4 : RGBA<u8> = RGBA::from_u32(0x36648BFF);
+
+/// ```tan``` ```#D2B48C```
+pub const TAN : RGBA<u8> = RGBA::from_u32(0xD2B48CFF);
+
+/// ```tan1``` ```#FFA54F```
+pub const TAN_1 : RGBA<u8> = RGBA::from_u32(0xFFA54FFF);
+
+/// ```tan2``` ```#EE9A49```
+pub const TAN_2 : RGBA<u8> = RGBA::from_u32(0xEE9A49FF);
+
+/// ```tan3``` ```#CD853F```
+pub const TAN_3 : RGBA<u8> = RGBA::from_u32(0xCD853FFF);
+
+/// ```tan4``` ```#8B5A2B```
+pub const TAN_4 : RGBA<u8> = RGBA::from_u32(0x8B5A2BFF);
+
+/// ```thistle``` ```#D8BFD8```
+pub const THISTLE : RGBA<u8> = RGBA::from_u32(0xD8BFD8FF);
+
+/// ```thistle1``` ```#FFE1FF```
+pub const THISTLE_1 : RGBA<u8> = RGBA::from_u32(0xFFE1FFFF);
+
+/// ```thistle2``` ```#EED2EE```
+pub const THISTLE_2 : RGBA<u8> = RGBA::from_u32(0xEED2EEFF);
+
+/// ```thistle3``` ```#CDB5CD```
+pub const THISTLE_3 : RGBA<u8> = RGBA::from_u32(0xCDB5CDFF);
+
+/// ```thistle4``` ```#8B7B8B```
+pub const THISTLE_4 : RGBA<u8> = RGBA::from_u32(0x8B7B8BFF);
+
+/// ```tomato``` ```#FF6347```
+pub const TOMATO : RGBA<u8> = RGBA::from_u32(0xFF6347FF);
+
+/// ```tomato1``` ```#FF6347```
+pub const TOMATO_1 : RGBA<u8> = RGBA::from_u32(0xFF6347FF);
+
+/// ```tomato2``` ```#EE5C42```
+pub const TOMATO_2 : RGBA<u8> = RGBA::from_u32(0xEE5C42FF);
+
+/// ```tomato3``` ```#CD4F39```
+pub const TOMATO_3 : RGBA<u8> = RGBA::from_u32(0xCD4F39FF);
+
+/// ```tomato4``` ```#8B3626```
+pub const TOMATO_4 : RGBA<u8> = RGBA::from_u32(0x8B3626FF);
+
+/// ```turquoise``` ```#40E0D0```
+pub const TURQUOISE : RGBA<u8> = RGBA::from_u32(0x40E0D0FF);
+
+/// ```turquoise1``` ```#00F5FF```
+pub const TURQUOISE_1 : RGBA<u8> = RGBA::from_u32(0x00F5FFFF);
+
+/// ```turquoise2``` ```#00E5EE```
+pub const TURQUOISE_2 : RGBA<u8> = RGBA::from_u32(0x00E5EEFF);
+
+/// ```turquoise3``` ```#00C5CD```
+pub const TURQUOISE_3 : RGBA<u8> = RGBA::from_u32(0x00C5CDFF);
+
+/// ```turquoise4``` ```#00868B```
+pub const TURQUOISE_4 : RGBA<u8> = RGBA::from_u32(0x00868BFF);
+
+/// ```violet``` ```#EE82EE```
+pub const VIOLET : RGBA<u8> = RGBA::from_u32(0xEE82EEFF);
+
+/// ```violetred``` ```#D02090```
+pub const VIOLET_RED : RGBA<u8> = RGBA::from_u32(0xD02090FF);
+
+/// ```violetred1``` ```#FF3E96```
+pub const VIOLET_RED_1 : RGBA<u8> = RGBA::from_u32(0xFF3E96FF);
+
+/// ```violetred2``` ```#EE3A8C```
+pub const VIOLET_RED_2 : RGBA<u8> = RGBA::from_u32(0xEE3A8CFF);
+
+/// ```violetred3``` ```#CD3278```
+pub const VIOLET_RED_3 : RGBA<u8> = RGBA::from_u32(0xCD3278FF);
+
+/// ```violetred4``` ```#8B2252```
+pub const VIOLET_RED_4 : RGBA<u8> = RGBA::from_u32(0x8B2252FF);
+
+/// ```wheat``` ```#F5DEB3```
+pub const WHEAT : RGBA<u8> = RGBA::from_u32(0xF5DEB3FF);
+
+/// ```wheat1``` ```#FFE7BA```
+pub const WHEAT_1 : RGBA<u8> = RGBA::from_u32(0xFFE7BAFF);
+
+/// ```wheat2``` ```#EED8AE```
+pub const WHEAT_2 : RGBA<u8> = RGBA::from_u32(0xEED8AEFF);
+
+/// ```wheat3``` ```#CDBA96```
+pub const WHEAT_3 : RGBA<u8> = RGBA::from_u32(0xCDBA96FF);
+
+/// ```wheat4``` ```#8B7E66```
+pub const WHEAT_4 : RGBA<u8> = RGBA::from_u32(0x8B7E66FF);
+
+/// ```white``` ```#FFFFFF```
+pub const WHITE : RGBA<u8> = RGBA::from_u32(0xFFFFFFFF);
+
+/// ```whitesmoke``` ```#F5F5F5```
+pub const WHITE_SMOKE : RGBA<u8> = RGBA::from_u32(0xF5F5F5FF);
+
+/// ```yellow``` ```#FFFF00```
+pub const YELLOW : RGBA<u8> = RGBA::from_u32(0xFFFF00FF);
+
+/// ```yellow1``` ```#FFFF00```
+pub const YELLOW_1 : RGBA<u8> = RGBA::from_u32(0xFFFF00FF);
+
+/// ```yellow2``` ```#EEEE00```
+pub const YELLOW_2 : RGBA<u8> = RGBA::from_u32(0xEEEE00FF);
+
+/// ```yellow3``` ```#CDCD00```
+pub const YELLOW_3 : RGBA<u8> = RGBA::from_u32(0xCDCD00FF);
+
+/// ```yellow4``` ```#8B8B00```
+pub const YELLOW_4 : RGBA<u8> = RGBA::from_u32(0x8B8B00FF);
+
+/// ```yellowgreen``` ```#9ACD32```
+pub const YELLOW_GREEN : RGBA<u8> = RGBA::from_u32(0x9ACD32FF);
+
+/// All the X11 colors sorted by name
+pub static COLORS : &[(&str, RGBA<u8>)] = &[
+    ("aliceblue", ALICE_BLUE),
+    ("antiquewhite", ANTIQUE_WHITE),
+    ("antiquewhite1", ANTIQUE_WHITE_1),
+    ("antiquewhite2", ANTIQUE_WHITE_2),
+    ("antiquewhite3", ANTIQUE_WHITE_3),
+    ("antiquewhite4", ANTIQUE_WHITE_4),
+    ("aquamarine", AQUAMARINE),
+    ("aquamarine1", AQUAMARINE_1),
+    ("aquamarine2", AQUAMARINE_2),
+    ("aquamarine3", AQUAMARINE_3),
+    ("aquamarine4", AQUAMARINE_4),
+    ("azure", AZURE),
+    ("azure1", AZURE_1),
+    ("azure2", AZURE_2),
+    ("azure3", AZURE_3),
+    ("azure4", AZURE_4),
+    ("beige", BEIGE),
+    ("bisque", BISQUE),
+    ("bisque1", BISQUE_1),
+    ("bisque2", BISQUE_2),
+    ("bisque3", BISQUE_3),
+    ("bisque4", BISQUE_4),
+    ("black", BLACK),
+    ("blanchedalmond", BLANCHED_ALMOND),
+    ("blue", BLUE),
+    ("blue1", BLUE_1),
+    ("blue2", BLUE_2),
+    ("blue3", BLUE_3),
+    ("blue4", BLUE_4),
+    ("blueviolet", BLUE_VIOLET),
+    ("brown", BROWN),
+    ("brown1", BROWN_1),
+    ("brown2", BROWN_2),
+    ("brown3", BROWN_3),
+    ("brown4", BROWN_4),
+    ("burlywood", BURLYWOOD),
+    ("burlywood1", BURLYWOOD_1),
+    ("burlywood2", BURLYWOOD_2),
+    ("burlywood3", BURLYWOOD_3),
+    ("burlywood4", BURLYWOOD_4),
+    ("cadetblue", CADET_BLUE),
+    ("cadetblue1", CADET_BLUE_1),
+    ("cadetblue2", CADET_BLUE_2),
+    ("cadetblue3", CADET_BLUE_3),
+    ("cadetblue4", CADET_BLUE_4),
+    ("chartreuse", CHARTREUSE),
+    ("chartreuse1", CHARTREUSE_1),
+    ("chartreuse2", CHARTREUSE_2),
+    ("chartreuse3", CHARTREUSE_3),
+    ("chartreuse4", CHARTREUSE_4),
+    ("chocolate", CHOCOLATE),
+    ("chocolate1", CHOCOLATE_1),
+    ("chocolate2", CHOCOLATE_2),
+    ("chocolate3", CHOCOLATE_3),
+    ("chocolate4", CHOCOLATE_4),
+    ("coral", CORAL),
+    ("coral1", CORAL_1),
+    ("coral2", CORAL_2),
+    ("coral3", CORAL_3),
+    ("coral4", CORAL_4),
+    ("cornflowerblue", CORNFLOWER_BLUE),
+    ("cornsilk", CORNSILK),
+    ("cornsilk1", CORNSILK_1),
+    ("cornsilk2", CORNSILK_2),
+    ("cornsilk3", CORNSILK_3),
+    ("cornsilk4", CORNSILK_4),
+    ("cyan", CYAN),
+    ("cyan1", CYAN_1),
+    ("cyan2", CYAN_2),
+    ("cyan3", CYAN_3),
+    ("cyan4", CYAN_4),
+    ("darkblue", DARK_BLUE),
+    ("darkcyan", DARK_CYAN),
+    ("darkgoldenrod", DARK_GOLDENROD),
+    ("darkgoldenrod1", DARK_GOLDENROD_1),
+    ("darkgoldenrod2", DARK_GOLDENROD_2),
+    ("darkgoldenrod3", DARK_GOLDENROD_3),
+    ("darkgoldenrod4", DARK_GOLDENROD_4),
+    ("darkgray", DARK_GRAY),
+    ("darkgreen", DARK_GREEN),
+    ("darkgrey", DARK_GREY),
+    ("darkkhaki", DARK_KHAKI),
+    ("darkmagenta", DARK_MAGENTA),
+    ("darkolivegreen", DARK_OLIVE_GREEN),
+    ("darkolivegreen1", DARK_OLIVE_GREEN_1),
+    ("darkolivegreen2", DARK_OLIVE_GREEN_2),
+    ("darkolivegreen3", DARK_OLIVE_GREEN_3),
+    ("darkolivegreen4", DARK_OLIVE_GREEN_4),
+    ("darkorange", DARK_ORANGE),
+    ("darkorange1", DARK_ORANGE_1),
+    ("darkorange2", DARK_ORANGE_2),
+    ("darkorange3", DARK_ORANGE_3),
+    ("darkorange4", DARK_ORANGE_4),
+    ("darkorchid", DARK_ORCHID),
+    ("darkorchid1", DARK_ORCHID_1),
+    ("darkorchid2", DARK_ORCHID_2),
+    ("darkorchid3", DARK_ORCHID_3),
+    ("darkorchid4", DARK_ORCHID_4),
+    ("darkred", DARK_RED),
+    ("darksalmon", DARK_SALMON),
+    ("darkseagreen", DARK_SEA_GREEN),
+    ("darkseagreen1", DARK_SEA_GREEN_1),
+    ("darkseagreen2", DARK_SEA_GREEN_2),
+    ("darkseagreen3", DARK_SEA_GREEN_3),
+    ("darkseagreen4", DARK_SEA_GREEN_4),
+    ("darkslateblue", DARK_SLATE_BLUE),
+    ("darkslategray", DARK_SLATE_GRAY),
+    ("darkslategray1", DARK_SLATE_GRAY_1),
+    ("darkslategray2", DARK_SLATE_GRAY_2),
+    ("darkslategray3", DARK_SLATE_GRAY_3),
+    ("darkslategray4", DARK_SLATE_GRAY_4),
+    ("darkslategrey", DARK_SLATE_GREY),
+    ("darkturquoise", DARK_TURQUOISE),
+    ("darkviolet", DARK_VIOLET),
+    ("debianred", DEBIAN_RED),
+    ("deeppink", DEEP_PINK),
+    ("deeppink1", DEEP_PINK_1),
+    ("deeppink2", DEEP_PINK_2),
+    ("deeppink3", DEEP_PINK_3),
+    ("deeppink4", DEEP_PINK_4),
+    ("deepskyblue", DEEP_SKY_BLUE),
+    ("deepskyblue1", DEEP_SKY_BLUE_1),
+    ("deepskyblue2", DEEP_SKY_BLUE_2),
+    ("deepskyblue3", DEEP_SKY_BLUE_3),
+    ("deepskyblue4", DEEP_SKY_BLUE_4),
+    ("dimgray", DIM_GRAY),
+    ("dimgrey", DIM_GREY),
+    ("dodgerblue", DODGER_BLUE),
+    ("dodgerblue1", DODGER_BLUE_1),
+    ("dodgerblue2", DODGER_BLUE_2),
+    ("dodgerblue3", DODGER_BLUE_3),
+    ("dodgerblue4", DODGER_BLUE_4),
+    ("firebrick", FIREBRICK),
+    ("firebrick1", FIREBRICK_1),
+    ("firebrick2", FIREBRICK_2),
+    ("firebrick3", FIREBRICK_3),
+    ("firebrick4", FIREBRICK_4),
+    ("floralwhite", FLORAL_WHITE),
+    ("forestgreen", FOREST_GREEN),
+    ("gainsboro", GAINSBORO),
+    ("ghostwhite", GHOST_WHITE),
+    ("gold", GOLD),
+    ("gold1", GOLD_1),
+    ("gold2", GOLD_2),
+    ("gold3", GOLD_3),
+    ("gold4", GOLD_4),
+    ("goldenrod", GOLDENROD),
+    ("goldenrod1", GOLDENROD_1),
+    ("goldenrod2", GOLDENROD_2),
+    ("goldenrod3", GOLDENROD_3),
+    ("goldenrod4", GOLDENROD_4),
+    ("gray", GRAY),
+    ("gray0", GRAY_0),
+    ("gray1", GRAY_1),
+    ("gray10", GRAY_10),
+    ("gray100", GRAY_100),
+    ("gray11", GRAY_11),
+    ("gray12", GRAY_12),
+    ("gray13", GRAY_13),
+    ("gray14", GRAY_14),
+    ("gray15", GRAY_15),
+    ("gray16", GRAY_16),
+    ("gray17", GRAY_17),
+    ("gray18", GRAY_18),
+    ("gray19", GRAY_19),
+    ("gray2", GRAY_2),
+    ("gray20", GRAY_20),
+    ("gray21", GRAY_21),
+    ("gray22", GRAY_22),
+    ("gray23", GRAY_23),
+    ("gray24", GRAY_24),
+    ("gray25", GRAY_25),
+    ("gray26", GRAY_26),
+    ("gray27", GRAY_27),
+    ("gray28", GRAY_28),
+    ("gray29", GRAY_29),
+    ("gray3", GRAY_3),
+    ("gray30", GRAY_30),
+    ("gray31", GRAY_31),
+    ("gray32", GRAY_32),
+    ("gray33", GRAY_33),
+    ("gray34", GRAY_34),
+    ("gray35", GRAY_35),
+    ("gray36", GRAY_36),
+    ("gray37", GRAY_37),
+    ("gray38", GRAY_38),
+    ("gray39", GRAY_39),
+    ("gray4", GRAY_4),
+    ("gray40", GRAY_40),
+    ("gray41", GRAY_41),
+    ("gray42", GRAY_42),
+    ("gray43", GRAY_43),
+    ("gray44", GRAY_44),
+    ("gray45", GRAY_45),
+    ("gray46", GRAY_46),
+    ("gray47", GRAY_47),
+    ("gray48", GRAY_48),
+    ("gray49", GRAY_49),
+    ("gray5", GRAY_5),
+    ("gray50", GRAY_50),
+    ("gray51", GRAY_51),
+    ("gray52", GRAY_52),
+    ("gray53", GRAY_53),
+    ("gray54", GRAY_54),
+    ("gray55", GRAY_55),
+    ("gray56", GRAY_56),
+    ("gray57", GRAY_57),
+    ("gray58", GRAY_58),
+    ("gray59", GRAY_59),
+    ("gray6", GRAY_6),
+    ("gray60", GRAY_60),
+    ("gray61", GRAY_61),
+    ("gray62", GRAY_62),
+    ("gray63", GRAY_63),
+    ("gray64", GRAY_64),
+    ("gray65", GRAY_65),
+    ("gray66", GRAY_66),
+    ("gray67", GRAY_67),
+    ("gray68", GRAY_68),
+    ("gray69", GRAY_69),
+    ("gray7", GRAY_7),
+    ("gray70", GRAY_70),
+    ("gray71", GRAY_71),
+    ("gray72", GRAY_72),
+    ("gray73", GRAY_73),
+    ("gray74", GRAY_74),
+    ("gray75", GRAY_75),
+    ("gray76", GRAY_76),
+    ("gray77", GRAY_77),
+    ("gray78", GRAY_78),
+    ("gray79", GRAY_79),
+    ("gray8", GRAY_8),
+    ("gray80", GRAY_80),
+    ("gray81", GRAY_81),
+    ("gray82", GRAY_82),
+    ("gray83", GRAY_83),
+    ("gray84", GRAY_84),
+    ("gray85", GRAY_85),
+    ("gray86", GRAY_86),
+    ("gray87", GRAY_87),
+    ("gray88", GRAY_88),
+    ("gray89", GRAY_89),
+    ("gray9", GRAY_9),
+    ("gray90", GRAY_90),
+    ("gray91", GRAY_91),
+    ("gray92", GRAY_92),
+    ("gray93", GRAY_93),
+    ("gray94", GRAY_94),
+    ("gray95", GRAY_95),
+    ("gray96", GRAY_96),
+    ("gray97", GRAY_97),
+    ("gray98", GRAY_98),
+    ("gray99", GRAY_99),
+    ("green", GREEN),
+    ("green1", GREEN_1),
+    ("green2", GREEN_2),
+    ("green3", GREEN_3),
+    ("green4", GREEN_4),
+    ("greenyellow", GREEN_YELLOW),
+    ("grey", GREY),
+    ("grey0", GREY_0),
+    ("grey1", GREY_1),
+    ("grey10", GREY_10),
+    ("grey100", GREY_100),
+    ("grey11", GREY_11),
+    ("grey12", GREY_12),
+    ("grey13", GREY_13),
+    ("grey14", GREY_14),
+    ("grey15", GREY_15),
+    ("grey16", GREY_16),
+    ("grey17", GREY_17),
+    ("grey18", GREY_18),
+    ("grey19", GREY_19),
+    ("grey2", GREY_2),
+    ("grey20", GREY_20),
+    ("grey21", GREY_21),
+    ("grey22", GREY_22),
+    ("grey23", GREY_23),
+    ("grey24", GREY_24),
+    ("grey25", GREY_25),
+    ("grey26", GREY_26),
+    ("grey27", GREY_27),
+    ("grey28", GREY_28),
+    ("grey29", GREY_29),
+    ("grey3", GREY_3),
+    ("grey30", GREY_30),
+    ("grey31", GREY_31),
+    ("grey32", GREY_32),
+    ("grey33", GREY_33),
+    ("grey34", GREY_34),
+    ("grey35", GREY_35),
+    ("grey36", GREY_36),
+    ("grey37", GREY_37),
+    ("grey38", GREY_38),
+    ("grey39", GREY_39),
+    ("grey4", GREY_4),
+    ("grey40", GREY_40),
+    ("grey41", GREY_41),
+    ("grey42", GREY_42),
+    ("grey43", GREY_43),
+    ("grey44", GREY_44),
+    ("grey45", GREY_45),
+    ("grey46", GREY_46),
+    ("grey47", GREY_47),
+    ("grey48", GREY_48),
+    ("grey49", GREY_49),
+    ("grey5", GREY_5),
+    ("grey50", GREY_50),
+    ("grey51", GREY_51),
+    ("grey52", GREY_52),
+    ("grey53", GREY_53),
+    ("grey54", GREY_54),
+    ("grey55", GREY_55),
+    ("grey56", GREY_56),
+    ("grey57", GREY_57),
+    ("grey58", GREY_58),
+    ("grey59", GREY_59),
+    ("grey6", GREY_6),
+    ("grey60", GREY_60),
+    ("grey61", GREY_61),
+    ("grey62", GREY_62),
+    ("grey63", GREY_63),
+    ("grey64", GREY_64),
+    ("grey65", GREY_65),
+    ("grey66", GREY_66),
+    ("grey67", GREY_67),
+    ("grey68", GREY_68),
+    ("grey69", GREY_69),
+    ("grey7", GREY_7),
+    ("grey70", GREY_70),
+    ("grey71", GREY_71),
+    ("grey72", GREY_72),
+    ("grey73", GREY_73),
+    ("grey74", GREY_74),
+    ("grey75", GREY_75),
+    ("grey76", GREY_76),
+    ("grey77", GREY_77),
+    ("grey78", GREY_78),
+    ("grey79", GREY_79),
+    ("grey8", GREY_8),
+    ("grey80", GREY_80),
+    ("grey81", GREY_81),
+    ("grey82", GREY_82),
+    ("grey83", GREY_83),
+    ("grey84", GREY_84),
+    ("grey85", GREY_85),
+    ("grey86", GREY_86),
+    ("grey87", GREY_87),
+    ("grey88", GREY_88),
+    ("grey89", GREY_89),
+    ("grey9", GREY_9),
+    ("grey90", GREY_90),
+    ("grey91", GREY_91),
+    ("grey92", GREY_92),
+    ("grey93", GREY_93),
+    ("grey94", GREY_94),
+    ("grey95", GREY_95),
+    ("grey96", GREY_96),
+    ("grey97", GREY_97),
+    ("grey98", GREY_98),
+    ("grey99", GREY_99),
+    ("honeydew", HONEYDEW),
+    ("honeydew1", HONEYDEW_1),
+    ("honeydew2", HONEYDEW_2),
+    ("honeydew3", HONEYDEW_3),
+    ("honeydew4", HONEYDEW_4),
+    ("hotpink", HOT_PINK),
+    ("hotpink1", HOT_PINK_1),
+    ("hotpink2", HOT_PINK_2),
+    ("hotpink3", HOT_PINK_3),
+    ("hotpink4", HOT_PINK_4),
+    ("indianred", INDIAN_RED),
+    ("indianred1", INDIAN_RED_1),
+    ("indianred2", INDIAN_RED_2),
+    ("indianred3", INDIAN_RED_3),
+    ("indianred4", INDIAN_RED_4),
+    ("ivory", IVORY),
+    ("ivory1", IVORY_1),
+    ("ivory2", IVORY_2),
+    ("ivory3", IVORY_3),
+    ("ivory4", IVORY_4),
+    ("khaki", KHAKI),
+    ("khaki1", KHAKI_1),
+    ("khaki2", KHAKI_2),
+    ("khaki3", KHAKI_3),
+    ("khaki4", KHAKI_4),
+    ("lavender", LAVENDER),
+    ("lavenderblush", LAVENDER_BLUSH),
+    ("lavenderblush1", LAVENDER_BLUSH_1),
+    ("lavenderblush2", LAVENDER_BLUSH_2),
+    ("lavenderblush3", LAVENDER_BLUSH_3),
+    ("lavenderblush4", LAVENDER_BLUSH_4),
+    ("lawngreen", LAWN_GREEN),
+    ("lemonchiffon", LEMON_CHIFFON),
+    ("lemonchiffon1", LEMON_CHIFFON_1),
+    ("lemonchiffon2", LEMON_CHIFFON_2),
+    ("lemonchiffon3", LEMON_CHIFFON_3),
+    ("lemonchiffon4", LEMON_CHIFFON_4),
+    ("lightblue", LIGHT_BLUE),
+    ("lightblue1", LIGHT_BLUE_1),
+    ("lightblue2", LIGHT_BLUE_2),
+    ("lightblue3", LIGHT_BLUE_3),
+    ("lightblue4", LIGHT_BLUE_4),
+    ("lightcoral", LIGHT_CORAL),
+    ("lightcyan", LIGHT_CYAN),
+    ("lightcyan1", LIGHT_CYAN_1),
+    ("lightcyan2", LIGHT_CYAN_2),
+    ("lightcyan3", LIGHT_CYAN_3),
+    ("lightcyan4", LIGHT_CYAN_4),
+    ("lightgoldenrod", LIGHT_GOLDENROD),
+    ("lightgoldenrod1", LIGHT_GOLDENROD_1),
+    ("lightgoldenrod2", LIGHT_GOLDENROD_2),
+    ("lightgoldenrod3", LIGHT_GOLDENROD_3),
+    ("lightgoldenrod4", LIGHT_GOLDENROD_4),
+    ("lightgoldenrodyellow", LIGHT_GOLDENROD_YELLOW),
+    ("lightgray", LIGHT_GRAY),
+    ("lightgreen", LIGHT_GREEN),
+    ("lightgrey", LIGHT_GREY),
+    ("lightpink", LIGHT_PINK),
+    ("lightpink1", LIGHT_PINK_1),
+    ("lightpink2", LIGHT_PINK_2),
+    ("lightpink3", LIGHT_PINK_3),
+    ("lightpink4", LIGHT_PINK_4),
+    ("lightsalmon", LIGHT_SALMON),
+    ("lightsalmon1", LIGHT_SALMON_1),
+    ("lightsalmon2", LIGHT_SALMON_2),
+    ("lightsalmon3", LIGHT_SALMON_3),
+    ("lightsalmon4", LIGHT_SALMON_4),
+    ("lightseagreen", LIGHT_SEA_GREEN),
+    ("lightskyblue", LIGHT_SKY_BLUE),
+    ("lightskyblue1", LIGHT_SKY_BLUE_1),
+    ("lightskyblue2", LIGHT_SKY_BLUE_2),
+    ("lightskyblue3", LIGHT_SKY_BLUE_3),
+    ("lightskyblue4", LIGHT_SKY_BLUE_4),
+    ("lightslateblue", LIGHT_SLATE_BLUE),
+    ("lightslategray", LIGHT_SLATE_GRAY),
+    ("lightslategrey", LIGHT_SLATE_GREY),
+    ("lightsteelblue", LIGHT_STEEL_BLUE),
+    ("lightsteelblue1", LIGHT_STEEL_BLUE_1),
+    ("lightsteelblue2", LIGHT_STEEL_BLUE_2),
+    ("lightsteelblue3", LIGHT_STEEL_BLUE_3),
+    ("lightsteelblue4", LIGHT_STEEL_BLUE_4),
+    ("lightyellow", LIGHT_YELLOW),
+    ("lightyellow1", LIGHT_YELLOW_1),
+    ("lightyellow2", LIGHT_YELLOW_2),
+    ("lightyellow3", LIGHT_YELLOW_3),
+    ("lightyellow4", LIGHT_YELLOW_4),
+    ("limegreen", LIME_GREEN),
+    ("linen", LINEN),
+    ("magenta", MAGENTA),
+    ("magenta1", MAGENTA_1),
+    ("magenta2", MAGENTA_2),
+    ("magenta3", MAGENTA_3),
+    ("magenta4", MAGENTA_4),
+    ("maroon", MAROON),
+    ("maroon1", MAROON_1),
+    ("maroon2", MAROON_2),
+    ("maroon3", MAROON_3),
+    ("maroon4", MAROON_4),
+    ("mediumaquamarine", MEDIUM_AQUAMARINE),
+    ("mediumblue", MEDIUM_BLUE),
+    ("mediumorchid", MEDIUM_ORCHID),
+    ("mediumorchid1", MEDIUM_ORCHID_1),
+    ("mediumorchid2", MEDIUM_ORCHID_2),
+    ("mediumorchid3", MEDIUM_ORCHID_3),
+    ("mediumorchid4", MEDIUM_ORCHID_4),
+    ("mediumpurple", MEDIUM_PURPLE),
+    ("mediumpurple1", MEDIUM_PURPLE_1),
+    ("mediumpurple2", MEDIUM_PURPLE_2),
+    ("mediumpurple3", MEDIUM_PURPLE_3),
+    ("mediumpurple4", MEDIUM_PURPLE_4),
+    ("mediumseagreen", MEDIUM_SEA_GREEN),
+    ("mediumslateblue", MEDIUM_SLATE_BLUE),
+    ("mediumspringgreen", MEDIUM_SPRING_GREEN),
+    ("mediumturquoise", MEDIUM_TURQUOISE),
+    ("mediumvioletred", MEDIUM_VIOLET_RED),
+    ("midnightblue", MIDNIGHT_BLUE),
+    ("mintcream", MINT_CREAM),
+    ("mistyrose", MISTY_ROSE),
+    ("mistyrose1", MISTY_ROSE_1),
+    ("mistyrose2", MISTY_ROSE_2),
+    ("mistyrose3", MISTY_ROSE_3),
+    ("mistyrose4", MISTY_ROSE_4),
+    ("moccasin", MOCCASIN),
+    ("navajowhite", NAVAJO_WHITE),
+    ("navajowhite1", NAVAJO_WHITE_1),
+    ("navajowhite2", NAVAJO_WHITE_2),
+    ("navajowhite3", NAVAJO_WHITE_3),
+    ("navajowhite4", NAVAJO_WHITE_4),
+    ("navy", NAVY),
+    ("navyblue", NAVY_BLUE),
+    ("oldlace", OLD_LACE),
+    ("olivedrab", OLIVE_DRAB),
+    ("olivedrab1", OLIVE_DRAB_1),
+    ("olivedrab2", OLIVE_DRAB_2),
+    ("olivedrab3", OLIVE_DRAB_3),
+    ("olivedrab4", OLIVE_DRAB_4),
+    ("orange", ORANGE),
+    ("orange1", ORANGE_1),
+    ("orange2", ORANGE_2),
+    ("orange3", ORANGE_3),
+    ("orange4", ORANGE_4),
+    ("orangered", ORANGE_RED),
+    ("orangered1", ORANGE_RED_1),
+    ("orangered2", ORANGE_RED_2),
+    ("orangered3", ORANGE_RED_3),
+    ("orangered4", ORANGE_RED_4),
+    ("orchid", ORCHID),
+    ("orchid1", ORCHID_1),
+    ("orchid2", ORCHID_2),
+    ("orchid3", ORCHID_3),
+    ("orchid4", ORCHID_4),
+    ("palegoldenrod", PALE_GOLDENROD),
+    ("palegreen", PALE_GREEN),
+    ("palegreen1", PALE_GREEN_1),
+    ("palegreen2", PALE_GREEN_2),
+    ("palegreen3", PALE_GREEN_3),
+    ("palegreen4", PALE_GREEN_4),
+    ("paleturquoise", PALE_TURQUOISE),
+    ("paleturquoise1", PALE_TURQUOISE_1),
+    ("paleturquoise2", PALE_TURQUOISE_2),
+    ("paleturquoise3", PALE_TURQUOISE_3),
+    ("paleturquoise4", PALE_TURQUOISE_4),
+    ("palevioletred", PALE_VIOLET_RED),
+    ("palevioletred1", PALE_VIOLET_RED_1),
+    ("palevioletred2", PALE_VIOLET_RED_2),
+    ("palevioletred3", PALE_VIOLET_RED_3),
+    ("palevioletred4", PALE_VIOLET_RED_4),
+    ("papayawhip", PAPAYA_WHIP),
+    ("peachpuff", PEACH_PUFF),
+    ("peachpuff1", PEACH_PUFF_1),
+    ("peachpuff2", PEACH_PUFF_2),
+    ("peachpuff3", PEACH_PUFF_3),
+    ("peachpuff4", PEACH_PUFF_4),
+    ("peru", PERU),
+    ("pink", PINK),
+    ("pink1", PINK_1),
+    ("pink2", PINK_2),
+    ("pink3", PINK_3),
+    ("pink4", PINK_4),
+    ("plum", PLUM),
+    ("plum1", PLUM_1),
+    ("plum2", PLUM_2),
+    ("plum3", PLUM_3),
+    ("plum4", PLUM_4),
+    ("powderblue", POWDER_BLUE),
+    ("purple", PURPLE),
+    ("purple1", PURPLE_1),
+    ("purple2", PURPLE_2),
+    ("purple3", PURPLE_3),
+    ("purple4", PURPLE_4),
+    ("red", RED),
+    ("red1", RED_1),
+    ("red2", RED_2),
+    ("red3", RED_3),
+    ("red4", RED_4),
+    ("rosybrown", ROSY_BROWN),
+    ("rosybrown1", ROSY_BROWN_1),
+    ("rosybrown2", ROSY_BROWN_2),
+    ("rosybrown3", ROSY_BROWN_3),
+    ("rosybrown4", ROSY_BROWN_4),
+    ("royalblue", ROYAL_BLUE),
+    ("royalblue1", ROYAL_BLUE_1),
+    ("royalblue2", ROYAL_BLUE_2),
+    ("royalblue3", ROYAL_BLUE_3),
+    ("royalblue4", ROYAL_BLUE_4),
+    ("saddlebrown", SADDLE_BROWN),
+    ("salmon", SALMON),
+    ("salmon1", SALMON_1),
+    ("salmon2", SALMON_2),
+    ("salmon3", SALMON_3),
+    ("salmon4", SALMON_4),
+    ("sandybrown", SANDY_BROWN),
+    ("seagreen", SEA_GREEN),
+    ("seagreen1", SEA_GREEN_1),
+    ("seagreen2", SEA_GREEN_2),
+    ("seagreen3", SEA_GREEN_3),
+    ("seagreen4", SEA_GREEN_4),
+    ("seashell", SEASHELL),
+    ("seashell1", SEASHELL_1),
+    ("seashell2", SEASHELL_2),
+    ("seashell3", SEASHELL_3),
+    ("seashell4", SEASHELL_4),
+    ("sienna", SIENNA),
+    ("sienna1", SIENNA_1),
+    ("sienna2", SIENNA_2),
+    ("sienna3", SIENNA_3),
+    ("sienna4", SIENNA_4),
+    ("skyblue", SKY_BLUE),
+    ("skyblue1", SKY_BLUE_1),
+    ("skyblue2", SKY_BLUE_2),
+    ("skyblue3", SKY_BLUE_3),
+    ("skyblue4", SKY_BLUE_4),
+    ("slateblue", SLATE_BLUE),
+    ("slateblue1", SLATE_BLUE_1),
+    ("slateblue2", SLATE_BLUE_2),
+    ("slateblue3", SLATE_BLUE_3),
+    ("slateblue4", SLATE_BLUE_4),
+    ("slategray", SLATE_GRAY),
+    ("slategray1", SLATE_GRAY_1),
+    ("slategray2", SLATE_GRAY_2),
+    ("slategray3", SLATE_GRAY_3),
+    ("slategray4", SLATE_GRAY_4),
+    ("slategrey", SLATE_GREY),
+    ("snow", SNOW),
+    ("snow1", SNOW_1),
+    ("snow2", SNOW_2),
+    ("snow3", SNOW_3),
+    ("snow4", SNOW_4),
+    ("springgreen", SPRING_GREEN),
+    ("springgreen1", SPRING_GREEN_1),
+    ("springgreen2", SPRING_GREEN_2),
+    ("springgreen3", SPRING_GREEN_3),
+    ("springgreen4", SPRING_GREEN_4),
+    ("steelblue", STEEL_BLUE),
+    ("steelblue1", STEEL_BLUE_1),
+    ("steelblue2", STEEL_BLUE_2),
+    ("steelblue3", STEEL_BLUE_3),
+    ("steelblue4", STEEL_BLUE_4),
+    ("tan", TAN),
+    ("tan1", TAN_1),
+    ("tan2", TAN_2),
+    ("tan3", TAN_3),
+    ("tan4", TAN_4),
+    ("thistle", THISTLE),
+    ("thistle1", THISTLE_1),
+    ("thistle2", THISTLE_2),
+    ("thistle3", THISTLE_3),
+    ("thistle4", THISTLE_4),
+    ("tomato", TOMATO),
+    ("tomato1", TOMATO_1),
+    ("tomato2", TOMATO_2),
+    ("tomato3", TOMATO_3),
+    ("tomato4", TOMATO_4),
+    ("turquoise", TURQUOISE),
+    ("turquoise1", TURQUOISE_1),
+    ("turquoise2", TURQUOISE_2),
+    ("turquoise3", TURQUOISE_3),
+    ("turquoise4", TURQUOISE_4),
+    ("violet", VIOLET),
+    ("violetred", VIOLET_RED),
+    ("violetred1", VIOLET_RED_1),
+    ("violetred2", VIOLET_RED_2),
+    ("violetred3", VIOLET_RED_3),
+    ("violetred4", VIOLET_RED_4),
+    ("wheat", WHEAT),
+    ("wheat1", WHEAT_1),
+    ("wheat2", WHEAT_2),
+    ("wheat3", WHEAT_3),
+    ("wheat4", WHEAT_4),
+    ("white", WHITE),
+    ("whitesmoke", WHITE_SMOKE),
+    ("yellow", YELLOW),
+    ("yellow1", YELLOW_1),
+    ("yellow2", YELLOW_2),
+    ("yellow3", YELLOW_3),
+    ("yellow4", YELLOW_4),
+    ("yellowgreen", YELLOW_GREEN),
+];
@@ -0,0 +1,262 @@
+//! # Palettes
+//! Find the nearest color of a palette with a selectable [Metric]. <br>
+//! The colors are stored in a k-d tree, so a query takes about ```O(log n)``` distance evaluations.
+//! CIEDE2000 is not a Euclidean distance, so its colors are sorted by lightness instead.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::palette::{Metric, Palette};
+//! let palette = Palette::new(vec![rgb!(0x000000), rgb!(0xFFFFFF), rgb!(0xFF0000)], Metric::DeltaEOk);
+//! assert_eq!(palette.nearest(rgb!(0xE01010)).unwrap().index, 2);
+//! assert_eq!(palette.nearest(rgb!(0x202020)).unwrap().index, 0);
+//! ```
+use crate::RGB;
+use crate::difference::{delta_e_2000, delta_e_ok, lightness_weight_2000};
+use crate::space::{Lab, Oklab};
+
+/// A distance between two colors
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Metric {
+    /// The Euclidean distance of the sRGB components in 0..=1
+    Rgb,
+    /// [CIEDE2000](delta_e_2000)
+    DeltaE2000,
+    /// The Euclidean distance in [Oklab](delta_e_ok)
+    #[default]
+    DeltaEOk,
+}
+
+impl Metric {
+    /// Calculate the distance between two colors.
+    pub fn distance(self, a : RGB<f32>, b : RGB<f32>) -> f32 {
+        match self {
+            Metric::Rgb => {
+                let d = [a.r() - b.r(), a.g() - b.g(), a.b() - b.b()];
+                (d[0] * d[0] + d[1] * d[1] + d[2] * d[2]).sqrt()
+            }
+            Metric::DeltaE2000 => delta_e_2000(a, b),
+            Metric::DeltaEOk => delta_e_ok(a, b),
+        }
+    }
+
+    /// The point of a color in the space where the colors are searched
+    fn point(self, color : RGB<f32>) -> [f32; 3] {
+        match self {
+            Metric::Rgb => [color.r(), color.g(), color.b()],
+            Metric::DeltaE2000 => {
+                let lab = Lab::from(color);
+                [lab.l, lab.a, lab.b]
+            }
+            Metric::DeltaEOk => {
+                let lab = Oklab::from(color);
+                [lab.l, lab.a, lab.b]
+            }
+        }
+    }
+}
+
+/// The result of a nearest color query
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub struct Nearest {
+    /// The index of the color in the palette
+    pub index : usize,
+    /// The distance measured with the metric of the palette
+    pub distance : f32,
+}
+
+/// Leaves of the tree hold at most this many colors
+const LEAF_SIZE : usize = 8;
+
+/// A list of colors with a k-d tree for nearest color queries
+#[derive(Debug,Clone)]
+pub struct Palette {
+    colors : Vec<RGB<f32>>,
+    metric : Metric,
+    /// The points of the colors in tree order, every range is split at its middle,
+    /// or sorted by lightness for CIEDE2000
+    points : Vec<[f32; 3]>,
+    /// The palette index of every point
+    indices : Vec<usize>,
+}
+
+impl Palette {
+    /// Create a palette and build its tree.
+    pub fn new<I>(colors : I, metric : Metric) -> Palette
+        where I : IntoIterator, I::Item : Into<RGB<f32>> {
+        let colors : Vec<RGB<f32>> = colors.into_iter().map(Into::into).collect();
+        let mut nodes : Vec<([f32; 3], usize)> = colors.iter()
+            .enumerate()
+            .map(|(i, &c)| (metric.point(c), i))
+            .collect();
+        if metric == Metric::DeltaE2000 {
+            nodes.sort_by(|a, b| a.0[0].total_cmp(&b.0[0]).then(a.1.cmp(&b.1)));
+        } else {
+            build(&mut nodes, 0);
+        }
+        Palette{
+            colors,
+            metric,
+            points : nodes.iter().map(|n| n.0).collect(),
+            indices : nodes.iter().map(|n| n.1).collect(),
+        }
+    }
+
+    /// Get the colors in their original order.
+    pub fn colors(&self) -> &[RGB<f32>] {
+        &self.colors
+    }
+
+    /// Get the metric.
+    pub fn metric(&self) -> Metric {
+        self.metric
+    }
+
+    /// Get the number of colors.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Check if the palette has no color.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Find the nearest color, the first one in the palette wins a tie.<br>
+    /// Return None if the palette is empty.<br>
+    /// The result is exact for every metric.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::palette::{Metric, Palette};
+    /// let mut seed = 7u32;
+    /// let mut random = move || { seed = seed.wrapping_mul(1664525).wrapping_add(1013904223); (seed >> 24) as u8 };
+    /// let mut color = move || rgb!(random(), random(), random());
+    /// let colors : Vec<RGB<u8>> = (0..64).map(|_| color()).collect();
+    /// for metric in [Metric::Rgb, Metric::DeltaE2000, Metric::DeltaEOk] {
+    ///     let palette = Palette::new(colors.clone(), metric);
+    ///     for _ in 0..300 {
+    ///         let query = color();
+    ///         // brute force, the first color wins a tie
+    ///         let (index, distance) = colors.iter()
+    ///             .map(|&c| metric.distance(query.into(), c.into()))
+    ///             .enumerate()
+    ///             .fold((0, f32::INFINITY), |best, (i, d)| if d < best.1 { (i, d) } else { best });
+    ///         let nearest = palette.nearest(query).unwrap();
+    ///         assert_eq!((nearest.index, nearest.distance), (index, distance));
+    ///     }
+    /// }
+    /// ```
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::palette::{Metric, Palette};
+    /// let gray : Vec<RGB<u8>> = (0..=255).map(|v| rgb!(v,v,v)).collect();
+    /// for metric in [Metric::Rgb, Metric::DeltaE2000, Metric::DeltaEOk] {
+    ///     let palette = Palette::new(gray.clone(), metric);
+    ///     let color = rgb!(0.393,0.393,0.393);
+    ///     let nearest = palette.nearest(color).unwrap();
+    ///     assert_eq!(nearest.index, 100);
+    ///     assert_eq!(nearest.distance, metric.distance(color, gray[100].into()));
+    /// }
+    /// ```
+    pub fn nearest<C : Into<RGB<f32>>>(&self, color : C) -> Option<Nearest> {
+        let color = color.into();
+        if self.metric == Metric::DeltaE2000 {
+            return self.nearest_2000(color);
+        }
+        if self.points.is_empty() {
+            return None;
+        }
+        let mut best = (f32::INFINITY, usize::MAX);
+        self.search(0, self.points.len(), 0, &self.metric.point(color), &mut best);
+        let index = best.1;
+        Some(Nearest{ index, distance : self.metric.distance(color, self.colors[index]) })
+    }
+
+    /// Scan the colors sorted by lightness from the lightness of the query outwards. <br>
+    /// ```ΔE00 >= |ΔL| / SL``` because the chroma, hue and rotation terms together are never negative
+    /// (```|RT| <= 2```), so a side is done once its lightness difference alone is larger than the best difference.
+    fn nearest_2000(&self, color : RGB<f32>) -> Option<Nearest> {
+        let q = Lab::from(color);
+        let (first, last) = (self.points.first()?[0], self.points[self.points.len() - 1][0]);
+        // SL grows away from 50, so its maximum over the remaining colors is at an end of the range
+        let sl_max = [q.l, (q.l + first) / 2.0, (q.l + last) / 2.0].iter()
+            .map(|&l| lightness_weight_2000(l as f64) as f32)
+            .fold(1.0, f32::max);
+        let mut best : Option<Nearest> = None;
+        // returns false when the color and every color beyond it are too far
+        let mut visit = |i : usize| {
+            let p = self.points[i];
+            let bound = (p[0] - q.l).abs() / sl_max * (1.0 - 1e-5);
+            if best.is_some_and(|b| bound > b.distance) {
+                return false;
+            }
+            let nearest = Nearest{ index : self.indices[i], distance : delta_e_2000(q, Lab{ l : p[0], a : p[1], b : p[2] }) };
+            let nearer = match best {
+                Some(b) => nearest.distance < b.distance || (nearest.distance == b.distance && nearest.index < b.index),
+                None => true,
+            };
+            if nearer {
+                best = Some(nearest);
+            }
+            true
+        };
+        let start = self.points.partition_point(|p| p[0] < q.l);
+        let (mut up, mut down) = (start, start);
+        let (mut up_open, mut down_open) = (true, true);
+        while up_open || down_open {
+            if up_open {
+                up_open = up < self.points.len() && visit(up);
+                up += 1;
+            }
+            if down_open {
+                down_open = down > 0 && visit(down - 1);
+                down = down.saturating_sub(1);
+            }
+        }
+        best
+    }
+
+    /// Find the nearest point by Euclidean distance as (squared distance, index),
+    /// the lowest index wins a tie
+    fn search(&self, lo : usize, hi : usize, depth : usize, q : &[f32; 3], best : &mut (f32, usize)) {
+        let visit = |i : usize, best : &mut (f32, usize)| {
+            let p = &self.points[i];
+            let d = (p[0] - q[0]).powi(2) + (p[1] - q[1]).powi(2) + (p[2] - q[2]).powi(2);
+            let index = self.indices[i];
+            let worse = best.0 < d || (best.0 == d && best.1 < index);
+            if !worse {
+                *best = (d, index);
+            }
+        };
+        if hi - lo <= LEAF_SIZE {
+            for i in lo..hi {
+                visit(i, best);
+            }
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let axis = depth % 3;
+        visit(mid, best);
+        let diff = q[axis] - self.points[mid][axis];
+        let (near, far) = if diff < 0.0 { ((lo, mid), (mid + 1, hi)) } else { ((mid + 1, hi), (lo, mid)) };
+        self.search(near.0, near.1, depth + 1, q, best);
+        // equal distances are kept so that the lowest index wins a tie
+        if diff * diff <= best.0 {
+            self.search(far.0, far.1, depth + 1, q, best);
+        }
+    }
+}
+
+/// Reorder the nodes into a balanced k-d tree split at the middle of every range
+fn build(nodes : &mut [([f32; 3], usize)], depth : usize) {
+    if nodes.len() <= LEAF_SIZE {
+        return;
+    }
+    let axis = depth % 3;
+    let mid = nodes.len() / 2;
+    nodes.select_nth_unstable_by(mid, |a, b| a.0[axis].total_cmp(&b.0[axis]));
+    let (left, right) = nodes.split_at_mut(mid);
+    build(left, depth + 1);
+    build(&mut right[1..], depth + 1);
+}
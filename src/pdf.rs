@@ -0,0 +1,573 @@
+//! # PDF transparency model
+//! The transparency model of PDF 1.4 and later (ISO 32000-1, section 11). <br>
+//! Unlike the rest of the crate, colors are not premultiplied here:
+//! every [Object] has a color, a shape and an opacity for every pixel of the page.
+//! Elements are composited inside [Group]s, which can be isolated and/or knockout,
+//! and each element can have a constant alpha and a [SoftMask].
+//! Every blend mode of PDF is supported, see [BlendMode].
+//!
+//! Two overlapping objects with opacity 0.5 in a knockout group: where they overlap,
+//! the second object knocks out the first one and is composited with the group backdrop only.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::pdf::{Element, Group, Object};
+//! # fn close(a : &[RGBA<f32>], b : &[RGBA<f32>]) -> bool {
+//! #     a.iter().zip(b).all(|(x, y)| [x.r() - y.r(), x.g() - y.g(), x.b() - y.b(), x.a() - y.a()].iter().all(|d| d.abs() < 1e-6))
+//! # }
+//! let red = Object::fill(rgb!(1.0,0.0,0.0), vec![1.0, 1.0, 0.0]);
+//! let blue = Object::fill(rgb!(0.0,0.0,1.0), vec![0.0, 1.0, 1.0]);
+//! let mut elements = vec![Element::object(red), Element::object(blue)];
+//! for e in elements.iter_mut() {
+//!     e.opacity = 0.5;
+//! }
+//! let white = rgba!(1.0,1.0,1.0,1.0);
+//!
+//! let mut page = vec![white; 3];
+//! Group::new(elements.clone()).render(&mut page);
+//! assert!(close(&page, &[rgba!(1.0,0.5,0.5,1.0), rgba!(0.5,0.25,0.75,1.0), rgba!(0.5,0.5,1.0,1.0)]));
+//!
+//! let mut page = vec![white; 3];
+//! let mut knockout = Group::new(elements);
+//! knockout.knockout = true;
+//! knockout.render(&mut page);
+//! assert!(close(&page, &[rgba!(1.0,0.5,0.5,1.0), rgba!(0.5,0.5,1.0,1.0), rgba!(0.5,0.5,1.0,1.0)]));
+//! ```
+use crate::{RGB, RGBA};
+
+/// The blend modes of PDF (BM), with the blend functions ```B(Cb, Cs)``` of ISO 32000-1, section 11.3.5.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::pdf::BlendMode;
+/// # fn close(a : RGB<f32>, b : RGB<f32>) -> bool {
+/// #     [a.r() - b.r(), a.g() - b.g(), a.b() - b.b()].iter().all(|d| d.abs() < 1e-6)
+/// # }
+/// let cb = rgb!(0.25,0.5,0.75);
+/// let cs = rgb!(0.75,0.5,0.25);
+/// assert_eq!(BlendMode::Normal.blend(cb, cs), cs);
+/// assert_eq!(BlendMode::Darken.blend(cb, cs), rgb!(0.25,0.5,0.25));
+/// assert_eq!(BlendMode::Difference.blend(cb, cs), rgb!(0.5,0.0,0.5));
+/// // Overlay is HardLight with the colors swapped
+/// assert!(close(BlendMode::Overlay.blend(cb, cs), rgb!(0.375,0.5,0.625)));
+/// assert!(close(BlendMode::Overlay.blend(cb, cs), BlendMode::HardLight.blend(cs, cb)));
+/// assert!(close(BlendMode::ColorDodge.blend(cb, cs), rgb!(1.0,1.0,1.0)));
+/// assert!(close(BlendMode::ColorBurn.blend(cb, cs), rgb!(0.0,0.0,0.0)));
+/// assert!(close(BlendMode::SoftLight.blend(cb, cs), rgb!(0.375,0.5,0.65625)));
+/// assert!(close(BlendMode::Exclusion.blend(cb, cs), rgb!(0.625,0.5,0.625)));
+/// // the Color of red with the luminosity of a 50% gray, clipped into the gamut
+/// let red = BlendMode::Color.blend(rgb!(0.5,0.5,0.5), rgb!(1.0,0.0,0.0));
+/// assert!(close(red, rgb!(1.0, 0.5 - 0.15 / 0.7, 0.5 - 0.15 / 0.7)));
+/// assert!((0.3 * red.r() + 0.59 * red.g() + 0.11 * red.b() - 0.5).abs() < 1e-6);
+/// ```
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum BlendMode {
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+}
+
+impl BlendMode {
+    /// Whether the blend function works on every channel on its own.
+    /// Hue, Saturation, Color and Luminosity are not separable.
+    pub fn is_separable(self) -> bool {
+        !matches!(self, BlendMode::Hue | BlendMode::Saturation | BlendMode::Color | BlendMode::Luminosity)
+    }
+
+    /// The blend function on non-premultiplied colors.
+    pub fn blend(self, cb : RGB<f32>, cs : RGB<f32>) -> RGB<f32> {
+        let cb = [cb.r(), cb.g(), cb.b()];
+        let cs = [cs.r(), cs.g(), cs.b()];
+        let c = match self {
+            BlendMode::Hue => set_lum(set_sat(cs, sat(cb)), lum(cb)),
+            BlendMode::Saturation => set_lum(set_sat(cb, sat(cs)), lum(cb)),
+            BlendMode::Color => set_lum(cs, lum(cb)),
+            BlendMode::Luminosity => set_lum(cb, lum(cs)),
+            _ => [0, 1, 2].map(|i| self.blend_channel(cb[i], cs[i])),
+        };
+        RGB::new(c[0], c[1], c[2])
+    }
+
+    fn blend_channel(self, cb : f32, cs : f32) -> f32 {
+        match self {
+            BlendMode::Multiply => cb * cs,
+            BlendMode::Screen => cb + cs - cb * cs,
+            BlendMode::Overlay => BlendMode::HardLight.blend_channel(cs, cb),
+            BlendMode::Darken => cb.min(cs),
+            BlendMode::Lighten => cb.max(cs),
+            BlendMode::ColorDodge => {
+                if cb <= 0.0 {
+                    0.0
+                } else if cs >= 1.0 {
+                    1.0
+                } else {
+                    (cb / (1.0 - cs)).min(1.0)
+                }
+            }
+            BlendMode::ColorBurn => {
+                if cb >= 1.0 {
+                    1.0
+                } else if cs <= 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - cb) / cs).min(1.0)
+                }
+            }
+            BlendMode::HardLight => {
+                if cs <= 0.5 {
+                    cb * 2.0 * cs
+                } else {
+                    BlendMode::Screen.blend_channel(cb, 2.0 * cs - 1.0)
+                }
+            }
+            BlendMode::SoftLight => {
+                if cs <= 0.5 {
+                    cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+                } else {
+                    let d = if cb <= 0.25 { ((16.0 * cb - 12.0) * cb + 4.0) * cb } else { cb.sqrt() };
+                    cb + (2.0 * cs - 1.0) * (d - cb)
+                }
+            }
+            BlendMode::Difference => (cb - cs).abs(),
+            BlendMode::Exclusion => cb + cs - 2.0 * cb * cs,
+            _ => cs,
+        }
+    }
+}
+
+fn lum(c : [f32; 3]) -> f32 {
+    0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2]
+}
+
+fn clip_color(c : [f32; 3]) -> [f32; 3] {
+    let l = lum(c);
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+    let mut c = c;
+    if n < 0.0 {
+        c = c.map(|v| l + (v - l) * l / (l - n));
+    }
+    if x > 1.0 {
+        c = c.map(|v| l + (v - l) * (1.0 - l) / (x - l));
+    }
+    c
+}
+
+fn set_lum(c : [f32; 3], l : f32) -> [f32; 3] {
+    let d = l - lum(c);
+    clip_color(c.map(|v| v + d))
+}
+
+fn sat(c : [f32; 3]) -> f32 {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+
+fn set_sat(c : [f32; 3], s : f32) -> [f32; 3] {
+    let mut order = [0, 1, 2];
+    order.sort_by(|&a, &b| c[a].total_cmp(&c[b]));
+    let [min, mid, max] = order;
+    let mut out = [0.0; 3];
+    if c[max] > c[min] {
+        out[mid] = (c[mid] - c[min]) * s / (c[max] - c[min]);
+        out[max] = s;
+    }
+    out
+}
+
+/// A graphics object: color, shape and opacity of every pixel of the page.
+#[derive(Debug,Clone,PartialEq)]
+pub struct Object {
+    color : Vec<RGB<f32>>,
+    shape : Vec<f32>,
+    opacity : Vec<f32>,
+}
+
+impl Object {
+    /// Create an object from its color, shape and opacity.
+    /// # Panics
+    /// Panics if the buffers have different lengths.
+    pub fn new(color : Vec<RGB<f32>>, shape : Vec<f32>, opacity : Vec<f32>) -> Object {
+        assert!(color.len() == shape.len() && shape.len() == opacity.len(), "buffers have different lengths");
+        Object{ color, shape, opacity }
+    }
+
+    /// Create an opaque object of a single color, e.g. a filled path with antialiased coverage.
+    pub fn fill(color : RGB<f32>, shape : Vec<f32>) -> Object {
+        let len = shape.len();
+        Object{ color : vec![color; len], shape, opacity : vec![1.0; len] }
+    }
+
+    /// Get the colors.
+    pub fn color(&self) -> &[RGB<f32>] {
+        &self.color
+    }
+
+    /// Get the shape values.
+    pub fn shape(&self) -> &[f32] {
+        &self.shape
+    }
+
+    /// Get the opacity values.
+    pub fn opacity(&self) -> &[f32] {
+        &self.opacity
+    }
+}
+
+/// The content of an [Element].
+#[derive(Debug,Clone)]
+pub enum Content {
+    Object(Object),
+    Group(Group),
+}
+
+/// An element of a group with its graphics state.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::pdf::{Element, Group, Object};
+/// // in a knockout group, shape interpolates between the previous result and the
+/// // element composited with the initial backdrop, while opacity only fades the element
+/// let knockout = |alpha_is_shape| {
+///     let red = Element::object(Object::fill(rgb!(1.0,0.0,0.0), vec![1.0]));
+///     let mut blue = Element::object(Object::fill(rgb!(0.0,0.0,1.0), vec![1.0]));
+///     blue.opacity = 0.5;
+///     blue.alpha_is_shape = alpha_is_shape;
+///     let mut group = Group::new(vec![red, blue]);
+///     group.knockout = true;
+///     let mut page = vec![rgba!(1.0,1.0,1.0,1.0)];
+///     group.render(&mut page);
+///     page[0]
+/// };
+/// assert_eq!(knockout(true), rgba!(0.5,0.0,0.5,1.0));
+/// assert_eq!(knockout(false), rgba!(0.5,0.5,1.0,1.0));
+/// ```
+#[derive(Debug,Clone)]
+pub struct Element {
+    /// The object or the nested group.
+    pub content : Content,
+    /// The blend mode (BM).
+    pub mode : BlendMode,
+    /// The constant alpha (CA/ca).
+    pub opacity : f32,
+    /// The soft mask (SMask).
+    pub soft_mask : Option<SoftMask>,
+    /// The alpha source flag (AIS). <br>
+    /// When set, the constant alpha and the soft mask are shape instead of opacity.
+    pub alpha_is_shape : bool,
+}
+
+impl Element {
+    /// Create a Normal element without constant alpha and soft mask.
+    pub fn new(content : Content) -> Element {
+        Element{
+            content,
+            mode : BlendMode::Normal,
+            opacity : 1.0,
+            soft_mask : None,
+            alpha_is_shape : false,
+        }
+    }
+
+    /// Create an element painting an object.
+    pub fn object(object : Object) -> Element {
+        Element::new(Content::Object(object))
+    }
+
+    /// Create an element painting a nested group.
+    pub fn group(group : Group) -> Element {
+        Element::new(Content::Group(group))
+    }
+}
+
+/// A transparency group.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::pdf::{BlendMode, Element, Group, Object};
+/// let mut multiply = Element::object(Object::fill(rgb!(0.5,0.5,0.5), vec![1.0]));
+/// multiply.mode = BlendMode::Multiply;
+/// multiply.opacity = 0.5;
+/// let backdrop = rgba!(0.2,0.4,0.8,1.0);
+/// // painted directly on the page
+/// let mut direct = vec![backdrop];
+/// Group::new(vec![multiply.clone()]).render(&mut direct);
+/// // a non-isolated group blends with the page, so the result is the same
+/// let mut grouped = vec![backdrop];
+/// Group::new(vec![Element::group(Group::new(vec![multiply.clone()]))]).render(&mut grouped);
+/// assert!((direct[0].b() - grouped[0].b()).abs() < 1e-6);
+/// assert!((direct[0].b() - 0.6).abs() < 1e-6);
+/// // an isolated group multiplies with nothing
+/// let mut isolated = Group::new(vec![multiply]);
+/// isolated.isolated = true;
+/// let mut page = vec![backdrop];
+/// Group::new(vec![Element::group(isolated)]).render(&mut page);
+/// assert!((page[0].b() - 0.65).abs() < 1e-6);
+/// ```
+#[derive(Debug,Clone)]
+pub struct Group {
+    /// The elements in painting order.
+    pub elements : Vec<Element>,
+    /// An isolated group is composited on a transparent backdrop
+    /// instead of the backdrop of the group.
+    pub isolated : bool,
+    /// In a knockout group, every element is composited with the initial backdrop of the group
+    /// instead of the elements painted before it.
+    pub knockout : bool,
+}
+
+impl Group {
+    /// Create a non-isolated, non-knockout group.
+    pub fn new(elements : Vec<Element>) -> Group {
+        Group{ elements, isolated : false, knockout : false }
+    }
+
+    /// Paint the group as a Normal element onto a premultiplied page backdrop. <br>
+    /// The results follow the formulas of ISO 32000-1, sections 11.3 and 11.4,
+    /// worked out by hand below on a half transparent backdrop.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::pdf::{BlendMode, Element, Group, Object};
+    /// # fn close(a : RGBA<f32>, b : RGBA<f32>) -> bool {
+    /// #     [a.r() - b.r(), a.g() - b.g(), a.b() - b.b(), a.a() - b.a()].iter().all(|d| d.abs() < 1e-6)
+    /// # }
+    /// // backdrop Cb = (0.2, 0.4, 0.8), αb = 0.5
+    /// let backdrop = rgba!(0.1,0.2,0.4,0.5);
+    ///
+    /// // A non-isolated group with one Multiply object, Cs = (0.5, 0.5, 0.5), qs = 0.6, fs = 1.
+    /// // αs = fs * qs = 0.6 and αr = Union(αb, αs) = αb + αs - αb * αs = 0.8
+    /// // Cr = (1 - αs / αr) * Cb + αs / αr * ((1 - αb) * Cs + αb * B(Cb, Cs))
+    /// //    = 0.25 * (0.2, 0.4, 0.8) + 0.75 * (0.3, 0.35, 0.45) = (0.275, 0.3625, 0.5375)
+    /// // The group alpha is αg = 0.6, and the backdrop is removed from the group result:
+    /// // C = Cn + (Cn - C0) * (α0 / αg - α0) = (0.3, 0.35, 0.45)
+    /// // Painting the group as Normal gives Cr again, so the page is (0.275, 0.3625, 0.5375) * 0.8
+    /// let mut multiply = Element::object(Object::new(vec![rgb!(0.5,0.5,0.5)], vec![1.0], vec![0.6]));
+    /// multiply.mode = BlendMode::Multiply;
+    /// let mut page = vec![backdrop];
+    /// Group::new(vec![Element::group(Group::new(vec![multiply.clone()]))]).render(&mut page);
+    /// assert!(close(page[0], rgba!(0.22,0.29,0.43,0.8)));
+    /// // the same as painting the object directly on the page
+    /// let mut direct = vec![backdrop];
+    /// Group::new(vec![multiply]).render(&mut direct);
+    /// assert!(close(page[0], direct[0]));
+    ///
+    /// // A non-isolated knockout group, every element is composited with the initial backdrop
+    /// // and the result is interpolated with the previous one by the shape:
+    /// // αi = (1 - fs) * αi-1 + fs * Union(α0, qs)
+    /// // αi * Ci = (1 - fs) * αi-1 * Ci-1 + fs * ((1 - qs) * α0 * C0 + qs * Cs) for the Normal mode
+    /// // Red, qs = 0.5, fs = 1: α1 = 0.75, α1 * C1 = (0.55, 0.1, 0.2)
+    /// // Blue, qs = 0.8, fs = 0.5: α2 = 0.5 * 0.75 + 0.5 * 0.9 = 0.825
+    /// // α2 * C2 = 0.5 * (0.55, 0.1, 0.2) + 0.5 * ((0.02, 0.04, 0.08) + (0.0, 0.0, 0.8)) = (0.285, 0.07, 0.54)
+    /// let red = Element::object(Object::new(vec![rgb!(1.0,0.0,0.0)], vec![1.0], vec![0.5]));
+    /// let blue = Element::object(Object::new(vec![rgb!(0.0,0.0,1.0)], vec![0.5], vec![0.8]));
+    /// let mut knockout = Group::new(vec![red, blue]);
+    /// knockout.knockout = true;
+    /// let mut page = vec![backdrop];
+    /// Group::new(vec![Element::group(knockout)]).render(&mut page);
+    /// assert!(close(page[0], rgba!(0.285,0.07,0.54,0.825)));
+    /// ```
+    /// # Panics
+    /// Panics if an object is not of the same size as the page.
+    pub fn render(&self, page : &mut [RGBA<f32>]) {
+        self.check(page.len());
+        let element = Element::group(self.clone());
+        for (p, pixel) in page.iter_mut().enumerate() {
+            let a = pixel.a();
+            let c = if a > 0.0 { [pixel.r() / a, pixel.g() / a, pixel.b() / a] } else { [0.0; 3] };
+            let (c, a) = element.composite(p, Pixel{ c, a }, Pixel{ c, a }, 0.0).0;
+            *pixel = RGBA::new(c[0] * a, c[1] * a, c[2] * a, a);
+        }
+    }
+
+    fn check(&self, len : usize) {
+        for element in self.elements.iter() {
+            match &element.content {
+                Content::Object(object) => assert_eq!(object.color.len(), len, "object has a wrong size"),
+                Content::Group(group) => group.check(len),
+            }
+            if let Some(mask) = &element.soft_mask {
+                mask.group.check(len);
+            }
+        }
+    }
+
+    /// Composite the group at pixel ```p``` on the backdrop and return (C, αg, fg)
+    fn composite(&self, p : usize, backdrop : Pixel) -> ([f32; 3], f32, f32) {
+        let initial = if self.isolated { Pixel{ c : backdrop.c, a : 0.0 } } else { backdrop };
+        let mut current = initial;
+        let mut group_alpha = 0.0;
+        let mut group_shape = 0.0;
+        for element in self.elements.iter() {
+            let ((c, a), fs, alpha) = if self.knockout {
+                element.composite(p, current, initial, 0.0)
+            } else {
+                element.composite(p, current, current, group_alpha)
+            };
+            current = Pixel{ c, a };
+            group_alpha = (1.0 - fs) * group_alpha + fs * alpha;
+            group_shape = union(group_shape, fs);
+        }
+        // remove the contribution of the backdrop from the result of a non-isolated group
+        let c = if group_alpha > 0.0 {
+            let k = initial.a / group_alpha - initial.a;
+            [0, 1, 2].map(|i| current.c[i] + (current.c[i] - initial.c[i]) * k)
+        } else {
+            current.c
+        };
+        (c, group_alpha, group_shape)
+    }
+}
+
+/// The kind of a [SoftMask].
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub enum SoftMaskKind {
+    /// The mask is the group alpha of the mask group.
+    Alpha,
+    /// The mask is the luminosity of the mask group composited on an opaque backdrop color (BC).
+    Luminosity{ backdrop : RGB<f32> },
+}
+
+/// A soft mask defined by a transparency group.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// use xblend::pdf::{Element, Group, Object, SoftMask};
+/// // a gray mask on a black backdrop covering the left pixel only
+/// let mask = Group::new(vec![Element::object(Object::fill(rgb!(0.5,0.5,0.5), vec![1.0, 0.0]))]);
+/// let mut element = Element::object(Object::fill(rgb!(0.0,0.0,0.0), vec![1.0, 1.0]));
+/// element.soft_mask = Some(SoftMask::luminosity(mask, rgb!(0.0,0.0,0.0)));
+/// let mut page = vec![rgba!(1.0,1.0,1.0,1.0); 2];
+/// Group::new(vec![element]).render(&mut page);
+/// assert_eq!(page, vec![rgba!(0.5,0.5,0.5,1.0), rgba!(1.0,1.0,1.0,1.0)]);
+/// ```
+#[derive(Debug,Clone)]
+pub struct SoftMask {
+    /// The kind of the mask (S).
+    pub kind : SoftMaskKind,
+    /// The mask group (G).
+    pub group : Group,
+    /// The transfer function (TR) applied to the mask values.
+    pub transfer : fn(f32) -> f32,
+}
+
+impl SoftMask {
+    /// Create an alpha soft mask with the identity transfer function.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::pdf::{Element, Group, Object, SoftMask};
+    /// let mask = Group::new(vec![Element::object(Object::new(vec![rgb!(0.0,0.0,0.0)], vec![1.0], vec![0.8]))]);
+    /// let mut mask = SoftMask::alpha(mask);
+    /// mask.transfer = |x| 1.0 - x;
+    /// let mut element = Element::object(Object::fill(rgb!(0.0,0.0,0.0), vec![1.0]));
+    /// element.soft_mask = Some(mask);
+    /// let mut page = vec![rgba!(1.0,1.0,1.0,1.0)];
+    /// Group::new(vec![element]).render(&mut page);
+    /// assert!((page[0].r() - 0.8).abs() < 1e-6);
+    /// ```
+    pub fn alpha(group : Group) -> SoftMask {
+        SoftMask{ kind : SoftMaskKind::Alpha, group, transfer : identity }
+    }
+
+    /// Create a luminosity soft mask with the identity transfer function.
+    pub fn luminosity(group : Group, backdrop : RGB<f32>) -> SoftMask {
+        SoftMask{ kind : SoftMaskKind::Luminosity{ backdrop }, group, transfer : identity }
+    }
+
+    fn value(&self, p : usize) -> f32 {
+        let value = match self.kind {
+            SoftMaskKind::Alpha => {
+                // the mask group is always isolated
+                self.group.composite(p, Pixel{ c : [0.0; 3], a : 0.0 }).1
+            }
+            SoftMaskKind::Luminosity{ backdrop } => {
+                let backdrop = Pixel{ c : [backdrop.r(), backdrop.g(), backdrop.b()], a : 1.0 };
+                let (c, alpha, _) = self.group.composite(p, backdrop);
+                // composite the group back onto the opaque backdrop
+                let c = [0, 1, 2].map(|i| backdrop.c[i] + (c[i] - backdrop.c[i]) * alpha);
+                0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2]
+            }
+        };
+        (self.transfer)(value)
+    }
+}
+
+fn identity(x : f32) -> f32 {
+    x
+}
+
+/// Non-premultiplied color and alpha
+#[derive(Debug,Copy,Clone)]
+struct Pixel {
+    c : [f32; 3],
+    a : f32,
+}
+
+fn union(a : f32, b : f32) -> f32 {
+    a + b - a * b
+}
+
+impl Element {
+    /// The source color, shape and opacity of the element at pixel ```p```
+    fn source(&self, p : usize, backdrop : Pixel) -> ([f32; 3], f32, f32) {
+        let mask = self.opacity * self.soft_mask.as_ref().map_or(1.0, |mask| mask.value(p));
+        let (shape_factor, opacity_factor) = if self.alpha_is_shape { (mask, 1.0) } else { (1.0, mask) };
+        match &self.content {
+            Content::Object(object) => {
+                let c = object.color[p];
+                ([c.r(), c.g(), c.b()], object.shape[p] * shape_factor, object.opacity[p] * opacity_factor)
+            }
+            Content::Group(group) => {
+                let (c, alpha, shape) = group.composite(p, backdrop);
+                if shape > 0.0 {
+                    (c, shape * shape_factor, alpha / shape * opacity_factor)
+                } else {
+                    (c, 0.0, 0.0)
+                }
+            }
+        }
+    }
+
+    /// Composite the element at pixel ```p```. <br>
+    /// ```previous``` is the result so far and ```backdrop``` the backdrop of the element,
+    /// which differ in knockout groups only. ```group_backdrop``` is the group alpha of the backdrop. <br>
+    /// Returns the result, the source shape and the new group alpha before weighting by the shape.
+    fn composite(&self, p : usize, previous : Pixel, backdrop : Pixel, group_backdrop : f32) -> (([f32; 3], f32), f32, f32) {
+        let (cs, fs, qs) = self.source(p, backdrop);
+        // composite with the opacity first, then interpolate with the shape
+        let alpha = union(backdrop.a, qs);
+        let mode = self.mode;
+        let blended = mode.blend(RGB::new(backdrop.c[0], backdrop.c[1], backdrop.c[2]), RGB::new(cs[0], cs[1], cs[2]));
+        let blended = [blended.r(), blended.g(), blended.b()];
+        let mut c = [0.0; 3];
+        for i in 0..3 {
+            let cb = backdrop.c[i];
+            let mixed = (1.0 - backdrop.a) * cs[i] + backdrop.a * blended[i];
+            let premultiplied = (1.0 - qs) * backdrop.a * cb + qs * mixed;
+            c[i] = (1.0 - fs) * previous.a * previous.c[i] + fs * premultiplied;
+        }
+        let a = (1.0 - fs) * previous.a + fs * alpha;
+        if a > 0.0 {
+            for v in c.iter_mut() {
+                *v /= a;
+            }
+        } else {
+            c = previous.c;
+        }
+        ((c, a), fs, union(group_backdrop, qs))
+    }
+}
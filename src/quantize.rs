@@ -0,0 +1,404 @@
+//! # Quantization
+//! Reduce an image to a palette of at most 256 colors and an index per pixel, for GIF, PNG8 and similar formats. <br>
+//! Colors have a straight alpha. Their color components are weighted by the alpha,
+//! so translucent colors are merged more easily and all transparent pixels are the same color.
+//! The result only depends on the pixels, the method and the seed.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::quantize::{Method, Quantizer};
+//! let pixels = [rgba!(255,0,0,255), rgba!(250,5,0,255), rgba!(0,0,255,255), rgba!(0,0,0,0)];
+//! let quantized = Quantizer::new(3, Method::MedianCut).quantize(&pixels);
+//! assert_eq!(quantized.palette.len(), 3);
+//! assert_eq!(quantized.indices[0], quantized.indices[1]);
+//! assert_eq!(quantized.palette[quantized.indices[3] as usize], rgba!(0,0,0,0));
+//! assert_eq!(quantized.counts.iter().sum::<usize>(), 4);
+//! ```
+use crate::{RGB, RGBA};
+use crate::space::{Lab, Oklab};
+
+/// The space where k-means clusters colors
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum ClusterSpace {
+    /// CIELAB, where Euclidean distances are deltaE 76
+    Lab,
+    #[default]
+    Oklab,
+}
+
+/// A quantization algorithm
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Method {
+    /// Split the box of colors with the largest population times range at its median, in RGBA
+    #[default]
+    MedianCut,
+    /// Merge the smallest branches of a tree of the RGBA bits
+    Octree,
+    /// Lloyd's k-means with a k-means++ initialization
+    KMeans(ClusterSpace),
+}
+
+impl Method {
+    /// The point of a color where the method measures distances, the color components are premultiplied
+    fn point(self, color : RGBA<u8>) -> [f32; 4] {
+        let a = color.a() as f32 / 255.0;
+        match self {
+            Method::MedianCut | Method::Octree => {
+                [color.r() as f32 * a, color.g() as f32 * a, color.b() as f32 * a, color.a() as f32]
+            }
+            Method::KMeans(ClusterSpace::Lab) => {
+                let lab = Lab::from(color.rgb());
+                [lab.l * a, lab.a * a, lab.b * a, 100.0 * a]
+            }
+            Method::KMeans(ClusterSpace::Oklab) => {
+                let lab = Oklab::from(color.rgb());
+                [lab.l * a, lab.a * a, lab.b * a, a]
+            }
+        }
+    }
+
+    /// The color of a point
+    fn color(self, p : [f32; 4]) -> RGBA<u8> {
+        let (rgb, a) = match self {
+            Method::MedianCut | Method::Octree => {
+                let a = p[3] / 255.0;
+                if a <= 0.0 {
+                    return RGBA::new(0, 0, 0, 0);
+                }
+                (RGB::new(p[0] / a / 255.0, p[1] / a / 255.0, p[2] / a / 255.0), a)
+            }
+            Method::KMeans(space) => {
+                let a = if space == ClusterSpace::Lab { p[3] / 100.0 } else { p[3] };
+                if a <= 0.0 {
+                    return RGBA::new(0, 0, 0, 0);
+                }
+                let rgb = if space == ClusterSpace::Lab {
+                    RGB::from(Lab{ l : p[0] / a, a : p[1] / a, b : p[2] / a })
+                } else {
+                    RGB::from(Oklab{ l : p[0] / a, a : p[1] / a, b : p[2] / a })
+                };
+                (rgb, a)
+            }
+        };
+        let round = |v : f32| (v * 255.0).round().clamp(0.0, 255.0) as u8;
+        RGBA::new(round(rgb.r()), round(rgb.g()), round(rgb.b()), round(a))
+    }
+}
+
+/// A palette and the index of every pixel
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct Quantized {
+    pub palette : Vec<RGBA<u8>>,
+    /// The palette index of every pixel
+    pub indices : Vec<u8>,
+    /// The number of pixels of every palette color
+    pub counts : Vec<usize>,
+}
+
+/// Settings of a quantization
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct Quantizer {
+    max_colors : usize,
+    method : Method,
+    seed : u64,
+    iterations : usize,
+}
+
+impl Quantizer {
+    /// Create a quantizer to at most max_colors colors, clamped to 1..=256, with a seed of 0 and 16 k-means iterations.
+    pub fn new(max_colors : usize, method : Method) -> Quantizer {
+        Quantizer{ max_colors : max_colors.clamp(1, 256), method, seed : 0, iterations : 16 }
+    }
+
+    /// Set the seed of the k-means initialization.
+    pub fn with_seed(mut self, seed : u64) -> Quantizer {
+        self.seed = seed;
+        self
+    }
+
+    /// Set the maximum number of k-means iterations.
+    pub fn with_iterations(mut self, iterations : usize) -> Quantizer {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Get the maximum number of colors.
+    pub fn max_colors(&self) -> usize {
+        self.max_colors
+    }
+
+    /// Get the method.
+    pub fn method(&self) -> Method {
+        self.method
+    }
+
+    /// Quantize pixels.<br>
+    /// If they have no more distinct colors than the maximum, the palette is exactly these colors.
+    /// Otherwise every pixel takes the nearest palette color in the space of the method.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::quantize::{ClusterSpace, Method, Quantizer};
+    /// // a gray ramp with every value
+    /// let pixels : Vec<RGBA<u8>> = (0..=255).map(|v| rgba!(v,v,v,255)).collect();
+    /// for method in [Method::MedianCut, Method::Octree, Method::KMeans(ClusterSpace::Oklab)] {
+    ///     let quantized = Quantizer::new(8, method).with_seed(7).quantize(&pixels);
+    ///     assert!(quantized.palette.len() <= 8);
+    ///     // every pixel is close to its palette color
+    ///     for (p, &i) in pixels.iter().zip(&quantized.indices) {
+    ///         assert!((p.r() as i32 - quantized.palette[i as usize].r() as i32).abs() < 40);
+    ///     }
+    ///     assert_eq!(quantized, Quantizer::new(8, method).with_seed(7).quantize(&pixels));
+    /// }
+    /// ```
+    pub fn quantize(&self, pixels : &[RGBA<u8>]) -> Quantized {
+        let histogram = histogram(pixels);
+        let palette = if histogram.len() <= self.max_colors {
+            histogram.iter().map(|&(c, _)| c).collect()
+        } else {
+            match self.method {
+                Method::MedianCut => median_cut(&histogram, self.max_colors, self.method),
+                Method::Octree => octree(&histogram, self.max_colors),
+                Method::KMeans(_) => self.k_means(&histogram),
+            }
+        };
+        // map every distinct color to its nearest palette color
+        let points : Vec<[f32; 4]> = palette.iter().map(|&c| self.method.point(c)).collect();
+        let nearest : Vec<u8> = histogram.iter()
+            .map(|&(c, _)| nearest(&points, &self.method.point(c)) as u8)
+            .collect();
+        let mut counts = vec![0; palette.len()];
+        let indices = pixels.iter().map(|&p| {
+            let key = key(p);
+            let i = nearest[histogram.partition_point(|&(c, _)| c.as_u32() < key)];
+            counts[i as usize] += 1;
+            i
+        }).collect();
+        Quantized{ palette, indices, counts }
+    }
+
+    fn k_means(&self, histogram : &[(RGBA<u8>, usize)]) -> Vec<RGBA<u8>> {
+        let points : Vec<[f32; 4]> = histogram.iter().map(|&(c, _)| self.method.point(c)).collect();
+        let weights : Vec<f64> = histogram.iter().map(|&(_, n)| n as f64).collect();
+        let mut rng = SplitMix64(self.seed);
+        // k-means++: every next center is chosen with a probability proportional to weight * distance^2
+        let mut centers : Vec<[f32; 4]> = Vec::with_capacity(self.max_colors);
+        let mut distances = vec![f64::INFINITY; points.len()];
+        while centers.len() < self.max_colors {
+            let scores : Vec<f64> = weights.iter().zip(&distances)
+                .map(|(&w, &d)| if d.is_infinite() { w } else { w * d })
+                .collect();
+            let total : f64 = scores.iter().sum();
+            if total <= 0.0 {
+                break;
+            }
+            let mut target = rng.next_f64() * total;
+            let chosen = scores.iter().position(|&s| { target -= s; target < 0.0 }).unwrap_or(points.len() - 1);
+            centers.push(points[chosen]);
+            for (d, p) in distances.iter_mut().zip(&points) {
+                *d = d.min(distance2(p, &points[chosen]) as f64);
+            }
+        }
+        let mut assignments = vec![usize::MAX; points.len()];
+        for _ in 0..self.iterations {
+            let mut changed = false;
+            for (a, p) in assignments.iter_mut().zip(&points) {
+                let i = nearest(&centers, p);
+                changed |= *a != i;
+                *a = i;
+            }
+            if !changed {
+                break;
+            }
+            let mut sums = vec![([0f64; 4], 0f64); centers.len()];
+            for ((p, &a), &w) in points.iter().zip(&assignments).zip(&weights) {
+                for (s, &v) in sums[a].0.iter_mut().zip(p) {
+                    *s += v as f64 * w;
+                }
+                sums[a].1 += w;
+            }
+            for (center, (sum, w)) in centers.iter_mut().zip(sums) {
+                // an empty cluster keeps its center
+                if w > 0.0 {
+                    *center = [0, 1, 2, 3].map(|k| (sum[k] / w) as f32);
+                }
+            }
+        }
+        centers.iter().map(|&c| self.method.color(c)).collect()
+    }
+}
+
+/// The straight color as a u32, with every transparent pixel as 0
+fn key(color : RGBA<u8>) -> u32 {
+    if color.a() == 0 { 0 } else { color.as_u32() }
+}
+
+/// The distinct colors sorted by their u32 value, with their number of pixels
+fn histogram(pixels : &[RGBA<u8>]) -> Vec<(RGBA<u8>, usize)> {
+    let mut keys : Vec<u32> = pixels.iter().map(|&p| key(p)).collect();
+    keys.sort_unstable();
+    let mut histogram : Vec<(RGBA<u8>, usize)> = Vec::new();
+    for k in keys {
+        match histogram.last_mut() {
+            Some(last) if last.0.as_u32() == k => last.1 += 1,
+            _ => histogram.push((RGBA::from_u32(k), 1)),
+        }
+    }
+    histogram
+}
+
+fn distance2(a : &[f32; 4], b : &[f32; 4]) -> f32 {
+    (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2) + (a[3] - b[3]).powi(2)
+}
+
+/// The index of the nearest point, the first one wins a tie
+fn nearest(points : &[[f32; 4]], p : &[f32; 4]) -> usize {
+    let mut best = (f32::INFINITY, 0);
+    for (i, q) in points.iter().enumerate() {
+        let d = distance2(p, q);
+        if d < best.0 {
+            best = (d, i);
+        }
+    }
+    best.1
+}
+
+/// The weighted mean of points
+fn mean(entries : &[([f32; 4], usize)]) -> [f32; 4] {
+    let mut sum = [0f64; 4];
+    let mut total = 0f64;
+    for (p, n) in entries {
+        for k in 0..4 {
+            sum[k] += p[k] as f64 * *n as f64;
+        }
+        total += *n as f64;
+    }
+    sum.map(|s| (s / total) as f32)
+}
+
+fn median_cut(histogram : &[(RGBA<u8>, usize)], max_colors : usize, method : Method) -> Vec<RGBA<u8>> {
+    let mut entries : Vec<([f32; 4], usize)> = histogram.iter().map(|&(c, n)| (method.point(c), n)).collect();
+    // the boxes are ranges of entries
+    let mut boxes = Vec::with_capacity(max_colors);
+    boxes.push(0..entries.len());
+    while boxes.len() < max_colors {
+        // the widest axis of every box, and its score
+        let widest = |entries : &[([f32; 4], usize)]| {
+            let mut best = (0.0, 0);
+            for k in 0..4 {
+                let (lo, hi) = entries.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), e| (lo.min(e.0[k]), hi.max(e.0[k])));
+                if hi - lo > best.0 {
+                    best = (hi - lo, k);
+                }
+            }
+            let population : usize = entries.iter().map(|e| e.1).sum();
+            (best.0 * population as f32, best.1)
+        };
+        let candidate = boxes.iter().enumerate()
+            .filter(|(_, b)| b.len() > 1)
+            .map(|(i, b)| (i, widest(&entries[b.clone()])))
+            .fold(None, |best : Option<(usize, (f32, usize))>, c| match best {
+                Some(b) if b.1.0 >= c.1.0 => Some(b),
+                _ => Some(c),
+            });
+        let (i, (_, axis)) = match candidate {
+            Some(c) => c,
+            None => break,
+        };
+        let range = boxes[i].clone();
+        let slice = &mut entries[range.clone()];
+        slice.sort_by(|a, b| a.0[axis].total_cmp(&b.0[axis]));
+        // split at the weighted median, keeping both halves non-empty
+        let half = slice.iter().map(|e| e.1).sum::<usize>() / 2;
+        let mut seen = 0;
+        let mut split = 1;
+        for (j, e) in slice.iter().enumerate() {
+            seen += e.1;
+            if seen > half {
+                split = j.clamp(1, slice.len() - 1);
+                break;
+            }
+        }
+        boxes[i] = range.start..range.start + split;
+        boxes.push(range.start + split..range.end);
+    }
+    boxes.into_iter().map(|b| method.color(mean(&entries[b]))).collect()
+}
+
+/// A node of the octree over the bits of the premultiplied RGBA components, it has 16 children
+struct Node {
+    children : [usize; 16],
+    depth : usize,
+    population : usize,
+    sum : [f64; 4],
+    leaf : bool,
+}
+
+fn octree(histogram : &[(RGBA<u8>, usize)], max_colors : usize) -> Vec<RGBA<u8>> {
+    let new_node = |depth : usize| Node{ children : [0; 16], depth, population : 0, sum : [0.0; 4], leaf : depth == 8 };
+    let mut nodes = vec![new_node(0)];
+    for &(c, n) in histogram {
+        let p = Method::Octree.point(c);
+        let bits = p.map(|v| v.round() as u8);
+        let mut node = 0;
+        loop {
+            nodes[node].population += n;
+            for (s, &v) in nodes[node].sum.iter_mut().zip(&p) {
+                *s += v as f64 * n as f64;
+            }
+            let depth = nodes[node].depth;
+            if depth == 8 {
+                break;
+            }
+            let shift = 7 - depth;
+            let child = (0..4).fold(0, |i, k| i | (((bits[k] >> shift) & 1) as usize) << k);
+            if nodes[node].children[child] == 0 {
+                nodes.push(new_node(depth + 1));
+                let index = nodes.len() - 1;
+                nodes[node].children[child] = index;
+            }
+            node = nodes[node].children[child];
+        }
+    }
+    // merge the smallest nodes of the deepest level first
+    let mut leaves = nodes.iter().filter(|n| n.leaf).count();
+    'levels: for depth in (0..8).rev() {
+        let mut candidates : Vec<usize> = (0..nodes.len()).filter(|&i| nodes[i].depth == depth).collect();
+        candidates.sort_by_key(|&i| (nodes[i].population, i));
+        for i in candidates {
+            if leaves <= max_colors {
+                break 'levels;
+            }
+            let children = nodes[i].children.iter().filter(|&&c| c != 0).count();
+            leaves -= children - 1;
+            nodes[i].leaf = true;
+        }
+    }
+    // the leaves in depth first order
+    let mut palette = Vec::new();
+    let mut stack = vec![0];
+    while let Some(i) = stack.pop() {
+        let node = &nodes[i];
+        if node.leaf {
+            let p = node.sum.map(|s| (s / node.population as f64) as f32);
+            palette.push(Method::Octree.color(p));
+        } else {
+            stack.extend(node.children.iter().rev().filter(|&&c| c != 0));
+        }
+    }
+    palette
+}
+
+/// A small deterministic random number generator
+pub(crate) struct SplitMix64(pub(crate) u64);
+
+impl SplitMix64 {
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
@@ -4,35 +4,54 @@ use crate::{InnerType, RGBA};
 
 /// This struct represents a RGB color
 #[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash,PartialOrd,Ord)]
+#[repr(C)]
 pub struct RGB<T : InnerType>(T,T,T);
 
 impl<T : InnerType > RGB<T>{
     /// Create a new RGB color with 3 components.
-    pub fn new(r : T,g : T,b : T) -> RGB<T>{
+    pub const fn new(r : T,g : T,b : T) -> RGB<T>{
         RGB(r,g,b)
     }
 
     /// Get the Red component.
-    pub fn r(&self) -> T{
+    pub const fn r(&self) -> T{
         self.0
     }
 
     /// Get the Green component.
-    pub fn g(&self) -> T{
+    pub const fn g(&self) -> T{
         self.1
     }
 
     /// Get the Blue component.
-    pub fn b(&self) -> T{
+    pub const fn b(&self) -> T{
         self.2
     }
 
 }
 
-/// A useful macro to create a Color with 3 components or an integer value.
+/// A useful macro to create a Color with 3 components or an integer value.<br>
+/// A hex string literal ```"#rgb"``` or ```"#rrggbb"``` is parsed at compile time.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// const ORANGE : RGB<u8> = rgb!("#FFAA00");
+/// assert_eq!(ORANGE, rgb!(0xFFAA00));
+/// assert_eq!(rgb!("#fa0"), ORANGE);
+/// ```
+/// ```compile_fail
+/// # extern crate xblend;
+/// # use xblend::*;
+/// // RGB has no alpha
+/// let color = rgb!("#ff000080");
+/// ```
 #[macro_export]
 macro_rules! rgb{
     ($r:expr, $g:expr, $b:expr) => {RGB::new($r,$g,$b)};
+    ($v:literal) => {{
+        const COLOR : $crate::RGB<u8> = $crate::__Literal($v).rgb();
+        COLOR
+    }};
     ($v:expr) => {RGB::from($v)}
 }
 
@@ -61,7 +80,7 @@ impl RGB<f32>{
 impl RGB<u8>{
     /// Calculate the gray value<br>
     /// The result equals```(R*28+G*151+B*77)>>8```
-    pub fn to_gray(&self) -> u8{
+    pub const fn to_gray(&self) -> u8{
         //convert to u32 to avoid overflow
         (((self.0 as u32) * 28 + (self.1 as u32) * 151 + (self.2 as u32) * 77) >> 8 ) as u8
     }
@@ -76,11 +95,22 @@ impl RGB<u8>{
     }
 
     /// Get the unsigned integer representation of itself
-    pub fn as_u32(&self) -> u32 {
+    #[allow(clippy::identity_op)]
+    pub const fn as_u32(&self) -> u32 {
         ((self.0 as u32) << 16)
       | ((self.1 as u32) << 8 )
       | ((self.2 as u32) << 0 )
     }
+
+    /// Create a color from its unsigned integer representation ```0xRRGGBB```.
+    #[allow(clippy::identity_op)]
+    pub const fn from_u32(color : u32) -> RGB<u8>{
+        RGB(
+            ((color & 0x00FF0000) >> 16) as u8,
+            ((color & 0x0000FF00) >> 8 ) as u8,
+            ((color & 0x000000FF) >> 0 ) as u8,
+        )
+    }
 }
 
 /// A useful trait to convert the other type to RGB
@@ -96,14 +126,11 @@ impl<T : InnerType> IntoRGB<T> for (T,T,T){
 
 impl From<u32> for RGB<u8>{
     fn from(color : u32) -> Self {
-        RGB(
-            ((color & 0x00FF0000) >> 16) as u8,
-            ((color & 0x0000FF00) >> 8 ) as u8,
-            ((color & 0x000000FF) >> 0 ) as u8,
-        )
+        RGB::from_u32(color)
     }
 }
 
+#[allow(clippy::from_over_into, clippy::identity_op)]
 impl Into<u32> for RGB<u8>{
     fn into(self) -> u32 {
           ((self.0 as u32) << 16)
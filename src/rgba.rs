@@ -1,52 +1,72 @@
 use std::ops::{Add, Sub, Mul, Div};
-use crate::blend::{Clear, Src, Dst, SrcOver, DstOver, SrcIn, DstIn, SrcOut, DstOut, SrcATop, DstATop, Xor, Darken, Lighten, Multiply, Screen};
+use crate::blend::{Blend, BlendMode, Clear, Src, Dst, SrcOver, DstOver, SrcIn, DstIn, SrcOut, DstOut, SrcATop, DstATop, Xor, Darken, Lighten, Multiply, Screen};
 use crate::{InnerType, RGB};
+use crate::simd::{self, Kernel};
 
 /// This struct represents a RGBA color
 #[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash,PartialOrd,Ord)]
+#[repr(C)]
 pub struct RGBA<T : InnerType>(T,T,T,T);
 
 impl<T : InnerType > RGBA<T>{
     /// Create a new RGBA color with 4 components.
-    pub fn new(r : T,g : T,b : T,a : T) -> RGBA<T>{
+    pub const fn new(r : T,g : T,b : T,a : T) -> RGBA<T>{
         RGBA(r,g,b,a)
     }
 
     /// Create a new RGBA color from another RGB color and the alpha component.
-    pub fn from_rgb(color : &RGB<T>,a : T) -> RGBA<T>{
+    pub const fn from_rgb(color : &RGB<T>,a : T) -> RGBA<T>{
         RGBA(color.r(),color.g(),color.b(),a)
     }
 
     /// Get the Red component.
-    pub fn r(&self) -> T{
+    pub const fn r(&self) -> T{
         self.0
     }
 
     /// Get the Green component.
-    pub fn g(&self) -> T{
+    pub const fn g(&self) -> T{
         self.1
     }
 
     /// Get the Blue component.
-    pub fn b(&self) -> T{
+    pub const fn b(&self) -> T{
         self.2
     }
 
     /// Get the alpha component.
-    pub fn a(&self) -> T{
+    pub const fn a(&self) -> T{
         self.3
     }
 
     /// Get the RGB components.
-    pub fn rgb(&self) -> RGB<T> {
+    pub const fn rgb(&self) -> RGB<T> {
         RGB::new(self.0, self.1, self.2)
     }
 }
 
-/// A useful macro to create a Color with 4 components or an integer value.
+/// A useful macro to create a Color with 4 components or an integer value.<br>
+/// A hex string literal ```"#rgb"```, ```"#rgba"```, ```"#rrggbb"``` or ```"#rrggbbaa"``` is parsed at compile time,
+/// so integer and string literals can be used in ```const``` and ```static``` items.
+/// ```
+/// # extern crate xblend;
+/// # use xblend::*;
+/// static PALETTE : [RGBA<u8>; 3] = [rgba!("#ff0"), rgba!("#FFAA00"), rgba!("#ff000080")];
+/// assert_eq!(PALETTE, [rgba!(0xFFFF00FF), rgba!(255,170,0,255), rgba!(255,0,0,128)]);
+/// ```
+/// A typo fails the build:
+/// ```compile_fail
+/// # extern crate xblend;
+/// # use xblend::*;
+/// let color = rgba!("#ff00g0");
+/// ```
 #[macro_export]
 macro_rules! rgba{
     ($r:expr, $g:expr, $b:expr, $a:expr) => {RGBA::new($r,$g,$b,$a)};
+    ($v:literal) => {{
+        const COLOR : $crate::RGBA<u8> = $crate::__Literal($v).rgba();
+        COLOR
+    }};
     ($v:expr) => {RGBA::from($v)}
 }
 
@@ -87,7 +107,7 @@ impl RGBA<f32>{
 impl RGBA<u8>{
     /// Calculate the gray value<br>
     /// The result equals```(R*28+G*151+B*77)>>8```
-    pub fn to_gray(&self) -> u8{
+    pub const fn to_gray(&self) -> u8{
         //convert to u32 to avoid overflow
         (((self.0 as u32) * 28 + (self.1 as u32) * 151 + (self.2 as u32) * 77) >> 8 ) as u8
     }
@@ -103,16 +123,23 @@ impl RGBA<u8>{
     }
 
     /// Get the unsigned integer representation of itself
-    pub fn as_u32(&self) -> u32 {
+    #[allow(clippy::identity_op)]
+    pub const fn as_u32(&self) -> u32 {
         ((self.0 as u32) << 24)
       | ((self.1 as u32) << 16)
       | ((self.2 as u32) << 8 )
       | ((self.3 as u32) << 0 )
     }
-}
 
-impl From<u32> for RGBA<u8>{
-    fn from(color : u32) -> Self {
+    /// Create a color from its unsigned integer representation ```0xRRGGBBAA```.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// const YELLOW : RGBA<u8> = RGBA::from_u32(0xFFFF00FF);
+    /// assert_eq!(YELLOW, RGBA::new(255,255,0,255));
+    /// ```
+    #[allow(clippy::identity_op)]
+    pub const fn from_u32(color : u32) -> RGBA<u8>{
         RGBA(
             ((color & 0xFF000000) >> 24) as u8,
             ((color & 0x00FF0000) >> 16) as u8,
@@ -122,6 +149,13 @@ impl From<u32> for RGBA<u8>{
     }
 }
 
+impl From<u32> for RGBA<u8>{
+    fn from(color : u32) -> Self {
+        RGBA::from_u32(color)
+    }
+}
+
+#[allow(clippy::from_over_into, clippy::identity_op)]
 impl Into<u32> for RGBA<u8>{
     fn into(self) -> u32 {
           ((self.0 as u32) << 24)
@@ -460,4 +494,140 @@ impl Screen for RGBA<f32>{
             1.0 - (1.0 - self.3) * (1.0 - rhs.3)
         )
     }
-}
\ No newline at end of file
+}
+
+/// Calculate ```round(a * b / 255)``` without division
+#[inline]
+pub(crate) fn mul_div255(a : u8, b : u8) -> u8 {
+    let t = a as u32 * b as u32 + 128;
+    ((t + (t >> 8)) >> 8) as u8
+}
+
+// u8 blending works on premultiplied colors
+impl SrcOver for RGBA<u8>{
+    type Output = RGBA<u8>;
+
+    fn src_over(self, rhs: Self) -> Self::Output {
+        let fd = 255 - self.3;
+        RGBA(
+            self.0.saturating_add(mul_div255(rhs.0, fd)),
+            self.1.saturating_add(mul_div255(rhs.1, fd)),
+            self.2.saturating_add(mul_div255(rhs.2, fd)),
+            self.3.saturating_add(mul_div255(rhs.3, fd))
+        )
+    }
+}
+impl Multiply for RGBA<u8>{
+    type Output = RGBA<u8>;
+
+    fn multiply(self, rhs: Self) -> Self::Output {
+        RGBA(
+            mul_div255(self.0, rhs.0),
+            mul_div255(self.1, rhs.1),
+            mul_div255(self.2, rhs.2),
+            mul_div255(self.3, rhs.3)
+        )
+    }
+}
+impl Screen for RGBA<u8>{
+    type Output = RGBA<u8>;
+
+    fn screen(self, rhs: Self) -> Self::Output {
+        //s + d - s * d never overflows
+        RGBA(
+            (self.0 as u32 + rhs.0 as u32 - mul_div255(self.0, rhs.0) as u32) as u8,
+            (self.1 as u32 + rhs.1 as u32 - mul_div255(self.1, rhs.1) as u32) as u8,
+            (self.2 as u32 + rhs.2 as u32 - mul_div255(self.2, rhs.2) as u32) as u8,
+            (self.3 as u32 + rhs.3 as u32 - mul_div255(self.3, rhs.3) as u32) as u8
+        )
+    }
+}
+
+impl Blend for RGBA<f32>{
+    fn blend(self, rhs: Self, mode: BlendMode) -> Self {
+        match mode {
+            BlendMode::Clear => self.clear(rhs),
+            BlendMode::Src => self.src(rhs),
+            BlendMode::Dst => self.dst(rhs),
+            BlendMode::SrcOver => self.src_over(rhs),
+            BlendMode::DstOver => self.dst_over(rhs),
+            BlendMode::SrcIn => self.src_in(rhs),
+            BlendMode::DstIn => self.dst_in(rhs),
+            BlendMode::SrcOut => self.src_out(rhs),
+            BlendMode::DstOut => self.dst_out(rhs),
+            BlendMode::SrcATop => self.src_atop(rhs),
+            BlendMode::DstATop => self.dst_atop(rhs),
+            BlendMode::Xor => self.xor(rhs),
+            BlendMode::Darken => self.darken(rhs),
+            BlendMode::Lighten => self.lighten(rhs),
+            BlendMode::Multiply => self.multiply(rhs),
+            BlendMode::Screen => self.screen(rhs),
+        }
+    }
+
+    fn fade(self, opacity: f32) -> Self {
+        RGBA(
+            self.0 * opacity,
+            self.1 * opacity,
+            self.2 * opacity,
+            self.3 * opacity
+        )
+    }
+
+    fn blend_slice(src: &[Self], dst: &mut [Self], mode: BlendMode) {
+        match mode {
+            BlendMode::SrcOver => simd::blend_f32(src, dst, Kernel::SrcOver),
+            BlendMode::Multiply => simd::blend_f32(src, dst, Kernel::Multiply),
+            BlendMode::Screen => simd::blend_f32(src, dst, Kernel::Screen),
+            _ => {
+                assert_eq!(src.len(), dst.len(), "slices have different lengths");
+                for (s, d) in src.iter().zip(dst.iter_mut()) {
+                    *d = s.blend(*d, mode);
+                }
+            }
+        }
+    }
+}
+
+impl Blend for RGBA<u8>{
+    fn blend(self, rhs: Self, mode: BlendMode) -> Self {
+        match mode {
+            BlendMode::SrcOver => self.src_over(rhs),
+            BlendMode::Multiply => self.multiply(rhs),
+            BlendMode::Screen => self.screen(rhs),
+            //blend in f32 and round back like mul_div255
+            _ => {
+                let c = self.to_f32().blend(rhs.to_f32(), mode);
+                RGBA(
+                    (c.0 * 255.0).round() as u8,
+                    (c.1 * 255.0).round() as u8,
+                    (c.2 * 255.0).round() as u8,
+                    (c.3 * 255.0).round() as u8
+                )
+            }
+        }
+    }
+
+    fn fade(self, opacity: f32) -> Self {
+        RGBA(
+            (self.0 as f32 * opacity + 0.5) as u8,
+            (self.1 as f32 * opacity + 0.5) as u8,
+            (self.2 as f32 * opacity + 0.5) as u8,
+            (self.3 as f32 * opacity + 0.5) as u8
+        )
+    }
+
+    fn blend_slice(src: &[Self], dst: &mut [Self], mode: BlendMode) {
+        match mode {
+            BlendMode::SrcOver => simd::blend_u8(src, dst, Kernel::SrcOver),
+            BlendMode::Multiply => simd::blend_u8(src, dst, Kernel::Multiply),
+            BlendMode::Screen => simd::blend_u8(src, dst, Kernel::Screen),
+            _ => {
+                assert_eq!(src.len(), dst.len(), "slices have different lengths");
+                for (s, d) in src.iter().zip(dst.iter_mut()) {
+                    *d = s.blend(*d, mode);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! SIMD compositing kernels with runtime CPU feature detection.
+//! Every kernel gives exactly the same result as the scalar implementation in rgba.rs.
+use crate::RGBA;
+use crate::blend::{SrcOver, Multiply, Screen};
+
+/// The blending modes that have SIMD kernels
+#[derive(Debug,Copy,Clone,PartialEq,Eq)]
+pub(crate) enum Kernel {
+    SrcOver,
+    Multiply,
+    Screen,
+}
+
+fn scalar<P>(src : &[P], dst : &mut [P], kernel : Kernel)
+    where P : Copy + SrcOver<Output = P> + Multiply<Output = P> + Screen<Output = P> {
+    for (s, d) in src.iter().zip(dst.iter_mut()) {
+        *d = match kernel {
+            Kernel::SrcOver => s.src_over(*d),
+            Kernel::Multiply => s.multiply(*d),
+            Kernel::Screen => s.screen(*d),
+        };
+    }
+}
+
+/// Blend premultiplied RGBA&lt;u8&gt; pixels with the fastest kernel available.
+pub(crate) fn blend_u8(src : &[RGBA<u8>], dst : &mut [RGBA<u8>], kernel : Kernel) {
+    assert_eq!(src.len(), dst.len(), "slices have different lengths");
+    #[cfg(target_arch = "x86_64")]
+    {
+        let done = if is_x86_feature_detected!("avx2") {
+            // SAFETY: avx2 is available
+            unsafe { x86::blend_u8_avx2(src, dst, kernel) }
+        } else {
+            // SAFETY: sse2 is always available on x86_64
+            unsafe { x86::blend_u8_sse2(src, dst, kernel) }
+        };
+        scalar(&src[done..], &mut dst[done..], kernel);
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    scalar(src, dst, kernel);
+}
+
+/// Blend RGBA&lt;f32&gt; pixels with the fastest kernel available.
+pub(crate) fn blend_f32(src : &[RGBA<f32>], dst : &mut [RGBA<f32>], kernel : Kernel) {
+    assert_eq!(src.len(), dst.len(), "slices have different lengths");
+    #[cfg(target_arch = "x86_64")]
+    {
+        let done = if is_x86_feature_detected!("avx") {
+            // SAFETY: avx is available
+            unsafe { x86::blend_f32_avx(src, dst, kernel) }
+        } else {
+            // SAFETY: sse2 is always available on x86_64
+            unsafe { x86::blend_f32_sse2(src, dst, kernel) }
+        };
+        scalar(&src[done..], &mut dst[done..], kernel);
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    scalar(src, dst, kernel);
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use std::arch::x86_64::*;
+    use crate::RGBA;
+    use super::Kernel;
+
+    // Every function returns the number of pixels it has blended,
+    // the remaining pixels are left to the scalar implementation.
+
+    /// round(a * b / 255) on 16-bit lanes
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn mul_div255_sse2(a : __m128i, b : __m128i) -> __m128i {
+        let t = _mm_add_epi16(_mm_mullo_epi16(a, b), _mm_set1_epi16(128));
+        _mm_srli_epi16(_mm_add_epi16(t, _mm_srli_epi16(t, 8)), 8)
+    }
+
+    /// Blend 2 pixels unpacked into 16-bit lanes
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn kernel_u16_sse2(s : __m128i, d : __m128i, kernel : Kernel) -> __m128i {
+        match kernel {
+            Kernel::SrcOver => {
+                let a = _mm_shufflehi_epi16(_mm_shufflelo_epi16(s, 0xFF), 0xFF);
+                let fd = _mm_sub_epi16(_mm_set1_epi16(255), a);
+                _mm_adds_epu16(s, mul_div255_sse2(d, fd))
+            }
+            Kernel::Multiply => mul_div255_sse2(s, d),
+            Kernel::Screen => _mm_sub_epi16(_mm_add_epi16(s, d), mul_div255_sse2(s, d)),
+        }
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn blend_u8_sse2(src : &[RGBA<u8>], dst : &mut [RGBA<u8>], kernel : Kernel) -> usize {
+        let zero = _mm_setzero_si128();
+        let n = src.len() / 4 * 4;
+        for i in (0..n).step_by(4) {
+            let ps = src.as_ptr().add(i) as *const __m128i;
+            let pd = dst.as_mut_ptr().add(i) as *mut __m128i;
+            let s = _mm_loadu_si128(ps);
+            let d = _mm_loadu_si128(pd);
+            let lo = kernel_u16_sse2(_mm_unpacklo_epi8(s, zero), _mm_unpacklo_epi8(d, zero), kernel);
+            let hi = kernel_u16_sse2(_mm_unpackhi_epi8(s, zero), _mm_unpackhi_epi8(d, zero), kernel);
+            // SrcOver saturates every component at 255 like u8::saturating_add
+            _mm_storeu_si128(pd, _mm_packus_epi16(lo, hi));
+        }
+        n
+    }
+
+    /// round(a * b / 255) on 16-bit lanes
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn mul_div255_avx2(a : __m256i, b : __m256i) -> __m256i {
+        let t = _mm256_add_epi16(_mm256_mullo_epi16(a, b), _mm256_set1_epi16(128));
+        _mm256_srli_epi16(_mm256_add_epi16(t, _mm256_srli_epi16(t, 8)), 8)
+    }
+
+    /// Blend 4 pixels unpacked into 16-bit lanes
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn kernel_u16_avx2(s : __m256i, d : __m256i, kernel : Kernel) -> __m256i {
+        match kernel {
+            Kernel::SrcOver => {
+                let a = _mm256_shufflehi_epi16(_mm256_shufflelo_epi16(s, 0xFF), 0xFF);
+                let fd = _mm256_sub_epi16(_mm256_set1_epi16(255), a);
+                _mm256_adds_epu16(s, mul_div255_avx2(d, fd))
+            }
+            Kernel::Multiply => mul_div255_avx2(s, d),
+            Kernel::Screen => _mm256_sub_epi16(_mm256_add_epi16(s, d), mul_div255_avx2(s, d)),
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn blend_u8_avx2(src : &[RGBA<u8>], dst : &mut [RGBA<u8>], kernel : Kernel) -> usize {
+        let zero = _mm256_setzero_si256();
+        let n = src.len() / 8 * 8;
+        for i in (0..n).step_by(8) {
+            let ps = src.as_ptr().add(i) as *const __m256i;
+            let pd = dst.as_mut_ptr().add(i) as *mut __m256i;
+            let s = _mm256_loadu_si256(ps);
+            let d = _mm256_loadu_si256(pd);
+            // unpack and pack both work inside 128-bit lanes, so the pixel order is kept
+            let lo = kernel_u16_avx2(_mm256_unpacklo_epi8(s, zero), _mm256_unpacklo_epi8(d, zero), kernel);
+            let hi = kernel_u16_avx2(_mm256_unpackhi_epi8(s, zero), _mm256_unpackhi_epi8(d, zero), kernel);
+            _mm256_storeu_si256(pd, _mm256_packus_epi16(lo, hi));
+        }
+        n + blend_u8_sse2(&src[n..], &mut dst[n..], kernel)
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn blend_f32_sse2(src : &[RGBA<f32>], dst : &mut [RGBA<f32>], kernel : Kernel) -> usize {
+        let one = _mm_set1_ps(1.0);
+        for i in 0..src.len() {
+            let ps = src.as_ptr().add(i) as *const f32;
+            let pd = dst.as_mut_ptr().add(i) as *mut f32;
+            let s = _mm_loadu_ps(ps);
+            let d = _mm_loadu_ps(pd);
+            // same operations in the same order as the scalar code, no fused multiply-add
+            let r = match kernel {
+                Kernel::SrcOver => {
+                    let fd = _mm_sub_ps(one, _mm_shuffle_ps(s, s, 0xFF));
+                    _mm_add_ps(s, _mm_mul_ps(d, fd))
+                }
+                Kernel::Multiply => _mm_mul_ps(s, d),
+                Kernel::Screen => _mm_sub_ps(one, _mm_mul_ps(_mm_sub_ps(one, s), _mm_sub_ps(one, d))),
+            };
+            _mm_storeu_ps(pd, r);
+        }
+        src.len()
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn blend_f32_avx(src : &[RGBA<f32>], dst : &mut [RGBA<f32>], kernel : Kernel) -> usize {
+        let one = _mm256_set1_ps(1.0);
+        let n = src.len() / 2 * 2;
+        for i in (0..n).step_by(2) {
+            let ps = src.as_ptr().add(i) as *const f32;
+            let pd = dst.as_mut_ptr().add(i) as *mut f32;
+            let s = _mm256_loadu_ps(ps);
+            let d = _mm256_loadu_ps(pd);
+            let r = match kernel {
+                Kernel::SrcOver => {
+                    let fd = _mm256_sub_ps(one, _mm256_permute_ps(s, 0xFF));
+                    _mm256_add_ps(s, _mm256_mul_ps(d, fd))
+                }
+                Kernel::Multiply => _mm256_mul_ps(s, d),
+                Kernel::Screen => _mm256_sub_ps(one, _mm256_mul_ps(_mm256_sub_ps(one, s), _mm256_sub_ps(one, d))),
+            };
+            _mm256_storeu_ps(pd, r);
+        }
+        n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RGBA;
+    use crate::blend::{Blend, BlendMode};
+    use crate::rgba::mul_div255;
+    use super::*;
+
+    const KERNELS : [Kernel; 3] = [Kernel::SrcOver, Kernel::Multiply, Kernel::Screen];
+
+    /// Premultiplied pixels from a linear congruential generator
+    fn pixels(n : usize, seed : u32) -> Vec<RGBA<u8>> {
+        let mut seed = seed;
+        let mut next = move || {
+            seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+            (seed >> 24) as u8
+        };
+        (0..n).map(|_| {
+            let a = next();
+            let mut c = || mul_div255(next(), a);
+            RGBA::new(c(), c(), c(), a)
+        }).collect()
+    }
+
+    /// The reference result, channel by channel with mul_div255
+    fn expected(s : RGBA<u8>, d : RGBA<u8>, kernel : Kernel) -> RGBA<u8> {
+        let fd = 255 - s.a();
+        let channel = |s : u8, d : u8| match kernel {
+            Kernel::SrcOver => s.saturating_add(mul_div255(d, fd)),
+            Kernel::Multiply => mul_div255(s, d),
+            Kernel::Screen => (s as u32 + d as u32 - mul_div255(s, d) as u32) as u8,
+        };
+        RGBA::new(channel(s.r(), d.r()), channel(s.g(), d.g()), channel(s.b(), d.b()), channel(s.a(), d.a()))
+    }
+
+    /// Run a kernel that returns the number of blended pixels and check every pixel
+    fn check<F : Fn(&[RGBA<u8>], &mut [RGBA<u8>], Kernel) -> usize>(name : &str, blend : F, step : usize) {
+        for len in 0..=40 {
+            let src = pixels(len, len as u32 + 1);
+            let dst = pixels(len, len as u32 + 1000);
+            for &kernel in KERNELS.iter() {
+                let mut out = dst.clone();
+                let done = blend(&src, &mut out, kernel);
+                assert_eq!(done, len / step * step, "{} {:?} length {}", name, kernel, len);
+                for i in 0..len {
+                    let want = if i < done { expected(src[i], dst[i], kernel) } else { dst[i] };
+                    assert_eq!(out[i], want, "{} {:?} length {} pixel {}", name, kernel, len, i);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn scalar_matches_mul_div255() {
+        check("scalar", |s, d, k| { scalar(s, d, k); s.len() }, 1);
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn sse2_matches_mul_div255() {
+        // SAFETY: sse2 is always available on x86_64
+        check("sse2", |s, d, k| unsafe { x86::blend_u8_sse2(s, d, k) }, 4);
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn avx2_matches_mul_div255() {
+        if !is_x86_feature_detected!("avx2") {
+            return;
+        }
+        // the avx2 kernel finishes its tail of 4 pixels with sse2
+        // SAFETY: avx2 is available
+        check("avx2", |s, d, k| unsafe { x86::blend_u8_avx2(s, d, k) }, 4);
+    }
+
+    #[test]
+    fn dispatch_matches_mul_div255() {
+        check("dispatch", |s, d, k| { blend_u8(s, d, k); s.len() }, 1);
+    }
+
+    /// Run an f32 kernel and compare it with the scalar code and with Blend
+    fn check_f32<F : Fn(&[RGBA<f32>], &mut [RGBA<f32>], Kernel) -> usize>(name : &str, blend : F, step : usize) {
+        for len in 0..=40 {
+            let src : Vec<RGBA<f32>> = pixels(len, len as u32 + 1).iter().map(|p| p.to_f32()).collect();
+            let dst : Vec<RGBA<f32>> = pixels(len, len as u32 + 1000).iter().map(|p| p.to_f32()).collect();
+            for &kernel in KERNELS.iter() {
+                let mut out = dst.clone();
+                let done = blend(&src, &mut out, kernel);
+                assert_eq!(done, len / step * step, "{} {:?} length {}", name, kernel, len);
+                let mut want = dst.clone();
+                scalar(&src[..done], &mut want[..done], kernel);
+                assert_eq!(out, want, "{} {:?} length {}", name, kernel, len);
+                let mode = match kernel {
+                    Kernel::SrcOver => BlendMode::SrcOver,
+                    Kernel::Multiply => BlendMode::Multiply,
+                    Kernel::Screen => BlendMode::Screen,
+                };
+                for i in 0..done {
+                    assert_eq!(out[i], src[i].blend(dst[i], mode), "{} {:?} length {} pixel {}", name, kernel, len, i);
+                }
+            }
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn sse2_f32_matches_scalar() {
+        // SAFETY: sse2 is always available on x86_64
+        check_f32("sse2", |s, d, k| unsafe { x86::blend_f32_sse2(s, d, k) }, 1);
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn avx_f32_matches_scalar() {
+        if !is_x86_feature_detected!("avx") {
+            return;
+        }
+        // SAFETY: avx is available
+        check_f32("avx", |s, d, k| unsafe { x86::blend_f32_avx(s, d, k) }, 2);
+    }
+
+    #[test]
+    fn dispatch_f32_matches_scalar() {
+        check_f32("dispatch", |s, d, k| { blend_f32(s, d, k); s.len() }, 1);
+    }
+}
@@ -0,0 +1,387 @@
+//! # Color spaces
+//! Conversions between sRGB (RGB&lt;f32&gt;) and other color spaces. <br>
+//! All conversions are unclamped, so colors outside of the sRGB gamut give components
+//! outside of 0..=1. The white point of [Xyz] is D65, [Lab] and [Lch] use D50 like CSS.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::space::{Oklab, Hsl};
+//! let lab = Oklab::from(rgb!(1.0,1.0,1.0));
+//! assert!((lab.l - 1.0).abs() < 1e-4 && lab.a.abs() < 1e-4 && lab.b.abs() < 1e-4);
+//! let hsl = Hsl::from(rgb!(1.0,0.0,0.0));
+//! assert_eq!((hsl.h, hsl.s, hsl.l), (0.0, 1.0, 0.5));
+//! ```
+use crate::RGB;
+
+pub(crate) type Matrix = [[f32; 3]; 3];
+
+pub(crate) fn mul(m : &Matrix, v : [f32; 3]) -> [f32; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+fn to_array(color : RGB<f32>) -> [f32; 3] {
+    [color.r(), color.g(), color.b()]
+}
+
+fn from_array(v : [f32; 3]) -> RGB<f32> {
+    RGB::new(v[0], v[1], v[2])
+}
+
+const LINEAR_SRGB_TO_XYZ : Matrix = [
+    [0.412_390_8, 0.357_584_33, 0.180_480_8],
+    [0.212_639, 0.715_168_7, 0.072_192_32],
+    [0.019_330_818, 0.119_194_78, 0.950_532_14],
+];
+
+const XYZ_TO_LINEAR_SRGB : Matrix = [
+    [3.240_97, -1.537_383_2, -0.498_610_76],
+    [-0.969_243_65, 1.875_967_5, 0.041_555_06],
+    [0.055_630_08, -0.203_976_96, 1.056_971_5],
+];
+
+const LINEAR_P3_TO_XYZ : Matrix = [
+    [0.486_570_95, 0.265_667_7, 0.198_217_29],
+    [0.228_974_56, 0.691_738_5, 0.079_286_91],
+    [0.0, 0.045_113_38, 1.043_944_4],
+];
+
+const XYZ_TO_LINEAR_P3 : Matrix = [
+    [2.493_497, -0.931_383_6, -0.402_710_8],
+    [-0.829_489, 1.762_664_1, 0.023_624_686],
+    [0.035_845_83, -0.076_172_39, 0.956_884_5],
+];
+
+/// Bradford chromatic adaptation from D65 to D50
+const D65_TO_D50 : Matrix = [
+    [1.047_929_8, 0.022_946_793, -0.050_192_23],
+    [0.029_627_815, 0.990_434_5, -0.017_073_825],
+    [-0.009_243_058, 0.015_055_145, 0.751_874_3],
+];
+
+/// Bradford chromatic adaptation from D50 to D65
+const D50_TO_D65 : Matrix = [
+    [0.955_473_4, -0.023_098_537, 0.063_259_31],
+    [-0.028_369_706, 1.009_995_5, 0.021_041_399],
+    [0.012_314_002, -0.020_507_697, 1.330_366],
+];
+
+const D50_WHITE : [f32; 3] = [0.964_295_7, 1.0, 0.825_104_6];
+
+/// Convert a gamma encoded sRGB component into linear light. <br>
+/// Negative values are mirrored like in CSS.
+pub fn srgb_to_linear(c : f32) -> f32 {
+    let abs = c.abs();
+    if abs <= 0.04045 {
+        c / 12.92
+    } else {
+        c.signum() * ((abs + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert a linear light component into gamma encoded sRGB. <br>
+/// Negative values are mirrored like in CSS.
+pub fn linear_to_srgb(c : f32) -> f32 {
+    let abs = c.abs();
+    if abs <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        c.signum() * (1.055 * abs.powf(1.0 / 2.4) - 0.055)
+    }
+}
+
+/// Convert a gamma encoded sRGB color into linear light.
+pub fn to_linear(color : RGB<f32>) -> RGB<f32> {
+    RGB::new(srgb_to_linear(color.r()), srgb_to_linear(color.g()), srgb_to_linear(color.b()))
+}
+
+/// Convert a linear light color into gamma encoded sRGB.
+pub fn from_linear(color : RGB<f32>) -> RGB<f32> {
+    RGB::new(linear_to_srgb(color.r()), linear_to_srgb(color.g()), linear_to_srgb(color.b()))
+}
+
+/// Convert a Display P3 color into sRGB.
+pub fn display_p3_to_srgb(color : RGB<f32>) -> RGB<f32> {
+    let xyz = mul(&LINEAR_P3_TO_XYZ, to_array(to_linear(color)));
+    from_linear(from_array(mul(&XYZ_TO_LINEAR_SRGB, xyz)))
+}
+
+/// Convert a sRGB color into Display P3.
+pub fn srgb_to_display_p3(color : RGB<f32>) -> RGB<f32> {
+    let xyz = mul(&LINEAR_SRGB_TO_XYZ, to_array(to_linear(color)));
+    from_linear(from_array(mul(&XYZ_TO_LINEAR_P3, xyz)))
+}
+
+/// Normalize a hue in degrees into 0..360
+fn normalize_hue(h : f32) -> f32 {
+    let h = h % 360.0;
+    if h < 0.0 { h + 360.0 } else { h }
+}
+
+/// CIE XYZ with a D65 white point
+#[derive(Debug,Default,Copy,Clone,PartialEq)]
+pub struct Xyz {
+    pub x : f32,
+    pub y : f32,
+    pub z : f32,
+}
+
+impl Xyz {
+    /// Adapt from a D50 white point with the Bradford transform.
+    pub fn from_d50(x : f32, y : f32, z : f32) -> Xyz {
+        let [x, y, z] = mul(&D50_TO_D65, [x, y, z]);
+        Xyz{ x, y, z }
+    }
+
+    /// Adapt to a D50 white point with the Bradford transform.
+    pub fn to_d50(&self) -> [f32; 3] {
+        mul(&D65_TO_D50, [self.x, self.y, self.z])
+    }
+}
+
+impl From<RGB<f32>> for Xyz {
+    fn from(color : RGB<f32>) -> Xyz {
+        let [x, y, z] = mul(&LINEAR_SRGB_TO_XYZ, to_array(to_linear(color)));
+        Xyz{ x, y, z }
+    }
+}
+
+impl From<Xyz> for RGB<f32> {
+    fn from(color : Xyz) -> RGB<f32> {
+        from_linear(from_array(mul(&XYZ_TO_LINEAR_SRGB, [color.x, color.y, color.z])))
+    }
+}
+
+/// CIE L\*a\*b\* with a D50 white point, L in 0..=100
+#[derive(Debug,Default,Copy,Clone,PartialEq)]
+pub struct Lab {
+    pub l : f32,
+    pub a : f32,
+    pub b : f32,
+}
+
+const EPSILON : f32 = 216.0 / 24389.0;
+const KAPPA : f32 = 24389.0 / 27.0;
+
+impl From<Xyz> for Lab {
+    fn from(color : Xyz) -> Lab {
+        let xyz = color.to_d50();
+        let f = |i : usize| {
+            let v = xyz[i] / D50_WHITE[i];
+            if v > EPSILON { v.cbrt() } else { (KAPPA * v + 16.0) / 116.0 }
+        };
+        let (fx, fy, fz) = (f(0), f(1), f(2));
+        Lab{ l : 116.0 * fy - 16.0, a : 500.0 * (fx - fy), b : 200.0 * (fy - fz) }
+    }
+}
+
+impl From<Lab> for Xyz {
+    fn from(color : Lab) -> Xyz {
+        let fy = (color.l + 16.0) / 116.0;
+        let fx = color.a / 500.0 + fy;
+        let fz = fy - color.b / 200.0;
+        let inv = |f : f32| if f * f * f > EPSILON { f * f * f } else { (116.0 * f - 16.0) / KAPPA };
+        let y = if color.l > KAPPA * EPSILON { fy * fy * fy } else { color.l / KAPPA };
+        Xyz::from_d50(inv(fx) * D50_WHITE[0], y * D50_WHITE[1], inv(fz) * D50_WHITE[2])
+    }
+}
+
+impl From<RGB<f32>> for Lab {
+    fn from(color : RGB<f32>) -> Lab {
+        Lab::from(Xyz::from(color))
+    }
+}
+
+impl From<RGB<u8>> for Lab {
+    fn from(color : RGB<u8>) -> Lab {
+        Lab::from(color.to_f32())
+    }
+}
+
+impl From<Lab> for RGB<f32> {
+    fn from(color : Lab) -> RGB<f32> {
+        RGB::from(Xyz::from(color))
+    }
+}
+
+/// The cylindrical form of [Lab], hue in degrees
+#[derive(Debug,Default,Copy,Clone,PartialEq)]
+pub struct Lch {
+    pub l : f32,
+    pub c : f32,
+    pub h : f32,
+}
+
+impl From<Lab> for Lch {
+    fn from(color : Lab) -> Lch {
+        Lch{ l : color.l, c : color.a.hypot(color.b), h : normalize_hue(color.b.atan2(color.a).to_degrees()) }
+    }
+}
+
+impl From<Lch> for Lab {
+    fn from(color : Lch) -> Lab {
+        let (sin, cos) = color.h.to_radians().sin_cos();
+        Lab{ l : color.l, a : color.c * cos, b : color.c * sin }
+    }
+}
+
+impl From<RGB<f32>> for Lch {
+    fn from(color : RGB<f32>) -> Lch {
+        Lch::from(Lab::from(color))
+    }
+}
+
+impl From<Lch> for RGB<f32> {
+    fn from(color : Lch) -> RGB<f32> {
+        RGB::from(Lab::from(color))
+    }
+}
+
+/// The Oklab color space, L in 0..=1
+#[derive(Debug,Default,Copy,Clone,PartialEq)]
+pub struct Oklab {
+    pub l : f32,
+    pub a : f32,
+    pub b : f32,
+}
+
+impl From<RGB<f32>> for Oklab {
+    fn from(color : RGB<f32>) -> Oklab {
+        let [r, g, b] = to_array(to_linear(color));
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        Oklab{
+            l : 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a : 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b : 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        }
+    }
+}
+
+impl From<RGB<u8>> for Oklab {
+    fn from(color : RGB<u8>) -> Oklab {
+        Oklab::from(color.to_f32())
+    }
+}
+
+impl From<Oklab> for RGB<f32> {
+    fn from(color : Oklab) -> RGB<f32> {
+        let l = color.l + 0.396_337_78 * color.a + 0.215_803_76 * color.b;
+        let m = color.l - 0.105_561_346 * color.a - 0.063_854_17 * color.b;
+        let s = color.l - 0.089_484_18 * color.a - 1.291_485_5 * color.b;
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+        from_linear(RGB::new(
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ))
+    }
+}
+
+/// The cylindrical form of [Oklab], hue in degrees
+#[derive(Debug,Default,Copy,Clone,PartialEq)]
+pub struct Oklch {
+    pub l : f32,
+    pub c : f32,
+    pub h : f32,
+}
+
+impl From<Oklab> for Oklch {
+    fn from(color : Oklab) -> Oklch {
+        Oklch{ l : color.l, c : color.a.hypot(color.b), h : normalize_hue(color.b.atan2(color.a).to_degrees()) }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(color : Oklch) -> Oklab {
+        let (sin, cos) = color.h.to_radians().sin_cos();
+        Oklab{ l : color.l, a : color.c * cos, b : color.c * sin }
+    }
+}
+
+impl From<RGB<f32>> for Oklch {
+    fn from(color : RGB<f32>) -> Oklch {
+        Oklch::from(Oklab::from(color))
+    }
+}
+
+impl From<Oklch> for RGB<f32> {
+    fn from(color : Oklch) -> RGB<f32> {
+        RGB::from(Oklab::from(color))
+    }
+}
+
+/// Hue in degrees, saturation and lightness in 0..=1
+#[derive(Debug,Default,Copy,Clone,PartialEq)]
+pub struct Hsl {
+    pub h : f32,
+    pub s : f32,
+    pub l : f32,
+}
+
+impl From<RGB<f32>> for Hsl {
+    fn from(color : RGB<f32>) -> Hsl {
+        let [r, g, b] = to_array(color);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let d = max - min;
+        if d == 0.0 {
+            return Hsl{ h : 0.0, s : 0.0, l };
+        }
+        let s = if l == 0.0 || l == 1.0 { 0.0 } else { (max - l) / l.min(1.0 - l) };
+        let h = if max == r {
+            (g - b) / d + if g < b { 6.0 } else { 0.0 }
+        } else if max == g {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+        Hsl{ h : h * 60.0, s, l }
+    }
+}
+
+impl From<Hsl> for RGB<f32> {
+    fn from(color : Hsl) -> RGB<f32> {
+        let h = normalize_hue(color.h);
+        let a = color.s * color.l.min(1.0 - color.l);
+        let f = |n : f32| {
+            let k = (n + h / 30.0) % 12.0;
+            color.l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+        RGB::new(f(0.0), f(8.0), f(4.0))
+    }
+}
+
+/// Hue in degrees, whiteness and blackness in 0..=1
+#[derive(Debug,Default,Copy,Clone,PartialEq)]
+pub struct Hwb {
+    pub h : f32,
+    pub w : f32,
+    pub b : f32,
+}
+
+impl From<RGB<f32>> for Hwb {
+    fn from(color : RGB<f32>) -> Hwb {
+        let [r, g, b] = to_array(color);
+        let w = r.min(g).min(b);
+        let black = 1.0 - r.max(g).max(b);
+        Hwb{ h : Hsl::from(color).h, w, b : black }
+    }
+}
+
+impl From<Hwb> for RGB<f32> {
+    fn from(color : Hwb) -> RGB<f32> {
+        if color.w + color.b >= 1.0 {
+            let gray = color.w / (color.w + color.b);
+            return RGB::new(gray, gray, gray);
+        }
+        let pure = RGB::from(Hsl{ h : color.h, s : 1.0, l : 0.5 });
+        let f = |c : f32| c * (1.0 - color.w - color.b) + color.w;
+        RGB::new(f(pure.r()), f(pure.g()), f(pure.b()))
+    }
+}
@@ -0,0 +1,221 @@
+//! # Image statistics
+//! Histograms of the red, green, blue, luma and alpha channels of an image,
+//! with their mean, variance, minimum, maximum and percentiles. <br>
+//! The channels can be measured in linear light, and weighted by the alpha so that transparent pixels do not count.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use xblend::image::ImageView;
+//! use xblend::stats::Analyzer;
+//! let pixels = [rgb!(0,0,0), rgb!(255,0,0), rgb!(255,255,255), rgb!(255,255,255)];
+//! let stats = Analyzer::new().analyze(&ImageView::from_slice(&pixels, 2, 2).unwrap());
+//! assert_eq!(stats.red.mean(), Some(0.75));
+//! assert_eq!(stats.green.min(), Some(0.0));
+//! assert_eq!(stats.red.percentile(50.0), Some(1.0));
+//! assert_eq!(stats.red.bins()[255], 3.0);
+//! ```
+use crate::image::{ImageView, RgbPixel};
+use crate::space::srgb_to_linear;
+
+/// A weighted histogram of values in 0..=1, which also keeps the exact moments of the values
+#[derive(Debug,Clone,PartialEq)]
+pub struct Histogram {
+    bins : Vec<f64>,
+    weight : f64,
+    sum : f64,
+    sum2 : f64,
+    min : f32,
+    max : f32,
+}
+
+impl Histogram {
+    /// Create an empty histogram, with at least 2 bins.
+    /// Bin ```i``` holds the values nearest to ```i / (bins - 1)```.
+    pub fn new(bins : usize) -> Histogram {
+        Histogram{ bins : vec![0.0; bins.max(2)], weight : 0.0, sum : 0.0, sum2 : 0.0, min : f32::INFINITY, max : f32::NEG_INFINITY }
+    }
+
+    /// Add a value with a weight, values out of 0..=1 count in the first or the last bin.
+    /// A weight of 0 or less, a NaN weight and a NaN value are ignored.
+    /// ```
+    /// # extern crate xblend;
+    /// use xblend::stats::Histogram;
+    /// let mut histogram = Histogram::new(3);
+    /// histogram.add(0.5, 2.0);
+    /// histogram.add(1.0, f32::NAN);
+    /// histogram.add(f32::NAN, 1.0);
+    /// histogram.add(0.0, -1.0);
+    /// assert_eq!(histogram.bins(), &[0.0, 2.0, 0.0]);
+    /// assert_eq!(histogram.mean(), Some(0.5));
+    /// ```
+    // the negated comparison also rejects a NaN weight
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    pub fn add(&mut self, value : f32, weight : f32) {
+        if !(weight > 0.0) || value.is_nan() {
+            return;
+        }
+        let last = self.bins.len() - 1;
+        self.bins[(value.clamp(0.0, 1.0) * last as f32).round() as usize] += weight as f64;
+        self.weight += weight as f64;
+        self.sum += value as f64 * weight as f64;
+        self.sum2 += (value as f64).powi(2) * weight as f64;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// Get the weights of the bins.
+    pub fn bins(&self) -> &[f64] {
+        &self.bins
+    }
+
+    /// Get the total weight.
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// Get the value of a bin.
+    pub fn value_of(&self, bin : usize) -> f32 {
+        bin as f32 / (self.bins.len() - 1) as f32
+    }
+
+    /// Get the weighted mean, None if the histogram is empty.
+    pub fn mean(&self) -> Option<f32> {
+        if self.weight > 0.0 { Some((self.sum / self.weight) as f32) } else { None }
+    }
+
+    /// Get the weighted population variance, None if the histogram is empty.
+    pub fn variance(&self) -> Option<f32> {
+        if self.weight > 0.0 {
+            let mean = self.sum / self.weight;
+            Some((self.sum2 / self.weight - mean * mean).max(0.0) as f32)
+        } else {
+            None
+        }
+    }
+
+    /// Get the smallest value, None if the histogram is empty.
+    pub fn min(&self) -> Option<f32> {
+        if self.weight > 0.0 { Some(self.min) } else { None }
+    }
+
+    /// Get the largest value, None if the histogram is empty.
+    pub fn max(&self) -> Option<f32> {
+        if self.weight > 0.0 { Some(self.max) } else { None }
+    }
+
+    /// Get the value of the first bin where the cumulative weight reaches p percent of the total,
+    /// with p clamped to 0..=100. None if the histogram is empty.
+    /// ```
+    /// # extern crate xblend;
+    /// use xblend::stats::Histogram;
+    /// let mut histogram = Histogram::new(11);
+    /// for i in 0..=10 {
+    ///     histogram.add(i as f32 / 10.0, 1.0);
+    /// }
+    /// assert_eq!(histogram.percentile(0.0), Some(0.0));
+    /// assert_eq!(histogram.percentile(50.0), Some(0.5));
+    /// assert_eq!(histogram.percentile(100.0), Some(1.0));
+    /// assert!((histogram.variance().unwrap() - 0.1).abs() < 1e-6);
+    /// ```
+    pub fn percentile(&self, p : f32) -> Option<f32> {
+        if self.weight <= 0.0 {
+            return None;
+        }
+        let target = self.weight * p.clamp(0.0, 100.0) as f64 / 100.0;
+        let mut cumulative = 0.0;
+        for (i, &w) in self.bins.iter().enumerate() {
+            cumulative += w;
+            if w > 0.0 && cumulative >= target {
+                return Some(self.value_of(i));
+            }
+        }
+        Some(self.value_of(self.bins.len() - 1))
+    }
+}
+
+/// The histograms of an image
+#[derive(Debug,Clone,PartialEq)]
+pub struct Stats {
+    pub red : Histogram,
+    pub green : Histogram,
+    pub blue : Histogram,
+    /// ```0.2126 * R + 0.7152 * G + 0.0722 * B```, the relative luminance when measured in linear light
+    pub luma : Histogram,
+    /// The alpha, never weighted
+    pub alpha : Histogram,
+}
+
+/// Settings of the statistics of images
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct Analyzer {
+    bins : usize,
+    alpha_weighted : bool,
+    linear : bool,
+}
+
+impl Default for Analyzer {
+    fn default() -> Self {
+        Analyzer::new()
+    }
+}
+
+impl Analyzer {
+    /// Create an analyzer of 256 bins in gamma encoded sRGB, where every pixel has the same weight.
+    pub fn new() -> Analyzer {
+        Analyzer{ bins : 256, alpha_weighted : false, linear : false }
+    }
+
+    /// Set the number of bins, at least 2.
+    pub fn with_bins(mut self, bins : usize) -> Analyzer {
+        self.bins = bins.max(2);
+        self
+    }
+
+    /// Weight every pixel by its alpha.
+    pub fn with_alpha_weighted(mut self, alpha_weighted : bool) -> Analyzer {
+        self.alpha_weighted = alpha_weighted;
+        self
+    }
+
+    /// Measure the color channels in linear light.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use xblend::image::ImageView;
+    /// use xblend::stats::Analyzer;
+    /// let pixels = [rgba!(128,128,128,255), rgba!(255,255,255,0)];
+    /// let image = ImageView::from_slice(&pixels, 2, 1).unwrap();
+    /// let stats = Analyzer::new().with_linear(true).with_alpha_weighted(true).analyze(&image);
+    /// // the transparent white does not count
+    /// assert!((stats.luma.mean().unwrap() - 0.2158).abs() < 1e-4);
+    /// assert_eq!(stats.alpha.mean(), Some(0.5));
+    /// ```
+    pub fn with_linear(mut self, linear : bool) -> Analyzer {
+        self.linear = linear;
+        self
+    }
+
+    /// Compute the histograms of an image.
+    pub fn analyze<P : RgbPixel>(&self, image : &ImageView<'_, P>) -> Stats {
+        let histogram = || Histogram::new(self.bins);
+        let mut stats = Stats{ red : histogram(), green : histogram(), blue : histogram(), luma : histogram(), alpha : histogram() };
+        for row in image.rows() {
+            for &p in row {
+                let color = p.srgb();
+                let alpha = p.alpha();
+                let weight = if self.alpha_weighted { alpha } else { 1.0 };
+                let [r, g, b] = if self.linear {
+                    [srgb_to_linear(color.r()), srgb_to_linear(color.g()), srgb_to_linear(color.b())]
+                } else {
+                    [color.r(), color.g(), color.b()]
+                };
+                stats.red.add(r, weight);
+                stats.green.add(g, weight);
+                stats.blue.add(b, weight);
+                stats.luma.add(0.2126 * r + 0.7152 * g + 0.0722 * b, weight);
+                stats.alpha.add(alpha, 1.0);
+            }
+        }
+        stats
+    }
+}
@@ -0,0 +1,288 @@
+//! # Tweening
+//! A [ColorTween] animates a color over a duration with an [Easing] curve,
+//! interpolating in a [Space] with [mix]. <br>
+//! Easing curves that overshoot, like [Curve::Back] and [Curve::Elastic], extrapolate the colors,
+//! and the result is clamped to the sRGB gamut.
+//! ```
+//! # extern crate xblend;
+//! # use xblend::*;
+//! use std::time::Duration;
+//! use xblend::gradient::Space;
+//! use xblend::tween::{ColorTween, Easing};
+//! let tween = ColorTween::new(rgba!(0,0,0,255), rgba!(255,255,255,255), Duration::from_millis(200))
+//!     .with_easing(Easing::EASE_IN_OUT)
+//!     .with_space(Space::Srgb);
+//! assert_eq!(tween.at_u8(Duration::from_millis(100)), rgba!(128,128,128,255));
+//! assert_eq!(tween.at_u8(Duration::from_millis(500)), rgba!(255,255,255,255));
+//! ```
+use std::f32::consts::PI;
+use std::time::Duration;
+use crate::RGBA;
+use crate::gradient::Space;
+use crate::mix::mix;
+
+/// A curve of Robert Penner's easing functions
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Curve {
+    Quad,
+    Cubic,
+    Quart,
+    Quint,
+    Sine,
+    Expo,
+    Circ,
+    /// Goes back a little before moving on
+    Back,
+    /// Oscillates like a spring
+    Elastic,
+    /// Bounces like a ball
+    Bounce,
+}
+
+impl Curve {
+    /// The ease-in form of the curve
+    fn ease_in(self, t : f32) -> f32 {
+        const BACK : f32 = 1.70158;
+        match self {
+            Curve::Quad => t * t,
+            Curve::Cubic => t * t * t,
+            Curve::Quart => t.powi(4),
+            Curve::Quint => t.powi(5),
+            Curve::Sine => 1.0 - (t * PI / 2.0).cos(),
+            Curve::Expo => if t <= 0.0 { 0.0 } else { 2f32.powf(10.0 * t - 10.0) },
+            Curve::Circ => 1.0 - (1.0 - t * t).max(0.0).sqrt(),
+            Curve::Back => (BACK + 1.0) * t * t * t - BACK * t * t,
+            Curve::Elastic => {
+                if t <= 0.0 || t >= 1.0 { t } else { -(2f32.powf(10.0 * t - 10.0)) * ((10.0 * t - 10.75) * 2.0 * PI / 3.0).sin() }
+            }
+            Curve::Bounce => 1.0 - bounce_out(1.0 - t),
+        }
+    }
+}
+
+fn bounce_out(t : f32) -> f32 {
+    const N : f32 = 7.5625;
+    const D : f32 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984_375
+    }
+}
+
+/// Which ends of an easing curve are eased
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Ease {
+    /// Slow at the start
+    In,
+    /// Slow at the end
+    Out,
+    /// Slow at both ends
+    #[default]
+    InOut,
+}
+
+/// When the jumps of CSS ```steps()``` happen
+#[derive(Debug,Default,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum StepPosition {
+    /// The first jump is at the start
+    JumpStart,
+    /// The last jump is at the end
+    #[default]
+    JumpEnd,
+    /// No jump at the start or the end
+    JumpNone,
+    /// Jumps at the start and at the end
+    JumpBoth,
+}
+
+/// A function from the linear progress in 0..=1 to the eased progress
+#[derive(Debug,Default,Copy,Clone,PartialEq)]
+pub enum Easing {
+    #[default]
+    Linear,
+    /// A curve of Robert Penner
+    Penner(Curve, Ease),
+    /// CSS ```cubic-bezier(x1, y1, x2, y2)```, the x coordinates are clamped to 0..=1
+    CubicBezier(f32, f32, f32, f32),
+    /// CSS ```steps(n, position)```
+    Steps(u32, StepPosition),
+}
+
+impl Easing {
+    /// CSS ```ease```
+    pub const EASE : Easing = Easing::CubicBezier(0.25, 0.1, 0.25, 1.0);
+    /// CSS ```ease-in```
+    pub const EASE_IN : Easing = Easing::CubicBezier(0.42, 0.0, 1.0, 1.0);
+    /// CSS ```ease-out```
+    pub const EASE_OUT : Easing = Easing::CubicBezier(0.0, 0.0, 0.58, 1.0);
+    /// CSS ```ease-in-out```
+    pub const EASE_IN_OUT : Easing = Easing::CubicBezier(0.42, 0.0, 0.58, 1.0);
+
+    /// Ease a progress, which is clamped to 0..=1 first.
+    /// ```
+    /// # extern crate xblend;
+    /// use xblend::tween::{Curve, Ease, Easing, StepPosition};
+    /// let close = |a : f32, b : f32| (a - b).abs() < 1e-5;
+    /// assert!(close(Easing::Penner(Curve::Quad, Ease::In).apply(0.5), 0.25));
+    /// assert!(close(Easing::Penner(Curve::Quad, Ease::Out).apply(0.5), 0.75));
+    /// assert!(close(Easing::Penner(Curve::Bounce, Ease::Out).apply(1.0), 1.0));
+    /// assert!(Easing::Penner(Curve::Back, Ease::In).apply(0.2) < 0.0);
+    /// assert!(close(Easing::EASE.apply(0.5), 0.802_403));
+    /// assert_eq!(Easing::Steps(4, StepPosition::JumpEnd).apply(0.5), 0.5);
+    /// assert_eq!(Easing::Steps(4, StepPosition::JumpStart).apply(0.5), 0.75);
+    /// assert_eq!(Easing::Steps(3, StepPosition::JumpNone).apply(0.5), 0.5);
+    /// assert_eq!(Easing::Steps(3, StepPosition::JumpBoth).apply(0.0), 0.25);
+    /// ```
+    pub fn apply(self, t : f32) -> f32 {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        match self {
+            Easing::Linear => t,
+            Easing::Penner(curve, Ease::In) => curve.ease_in(t),
+            Easing::Penner(curve, Ease::Out) => 1.0 - curve.ease_in(1.0 - t),
+            Easing::Penner(curve, Ease::InOut) => {
+                if t < 0.5 { curve.ease_in(2.0 * t) / 2.0 } else { 1.0 - curve.ease_in(2.0 - 2.0 * t) / 2.0 }
+            }
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2, t),
+            Easing::Steps(n, position) => steps(n, position, t),
+        }
+    }
+}
+
+/// Solve x(s) = t on the curve from (0, 0) to (1, 1) and return y(s)
+fn cubic_bezier(x1 : f32, y1 : f32, x2 : f32, y2 : f32, t : f32) -> f32 {
+    // the coordinates as polynomials a s^3 + b s^2 + c s
+    let coefficients = |p1 : f32, p2 : f32| {
+        let c = 3.0 * p1;
+        let b = 3.0 * (p2 - p1) - c;
+        (1.0 - c - b, b, c)
+    };
+    let (ax, bx, cx) = coefficients(x1, x2);
+    let (ay, by, cy) = coefficients(y1, y2);
+    let x = |s : f32| ((ax * s + bx) * s + cx) * s;
+    // x is monotonic because x1 and x2 are in 0..=1, so a few Newton steps and a bisection find s
+    let mut s = t;
+    for _ in 0..8 {
+        let error = x(s) - t;
+        if error.abs() < 1e-6 {
+            return ((ay * s + by) * s + cy) * s;
+        }
+        let slope = (3.0 * ax * s + 2.0 * bx) * s + cx;
+        if slope.abs() < 1e-6 {
+            break;
+        }
+        s -= error / slope;
+    }
+    let (mut lo, mut hi) = (0.0, 1.0);
+    s = t;
+    for _ in 0..32 {
+        if x(s) < t { lo = s } else { hi = s }
+        s = (lo + hi) / 2.0;
+    }
+    ((ay * s + by) * s + cy) * s
+}
+
+fn steps(n : u32, position : StepPosition, t : f32) -> f32 {
+    let n = n.max(1) as f32;
+    let jumps = match position {
+        StepPosition::JumpStart | StepPosition::JumpEnd => n,
+        StepPosition::JumpNone => (n - 1.0).max(1.0),
+        StepPosition::JumpBoth => n + 1.0,
+    };
+    let mut step = (t * n).floor();
+    if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+        step += 1.0;
+    }
+    step.min(jumps) / jumps
+}
+
+/// An animation from one color to another
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub struct ColorTween {
+    start : RGBA<f32>,
+    end : RGBA<f32>,
+    duration : Duration,
+    easing : Easing,
+    space : Space,
+}
+
+impl ColorTween {
+    /// Create a linear tween in [Space::Oklab].
+    pub fn new<A : Into<RGBA<f32>>, B : Into<RGBA<f32>>>(start : A, end : B, duration : Duration) -> ColorTween {
+        ColorTween{ start : start.into(), end : end.into(), duration, easing : Easing::Linear, space : Space::Oklab }
+    }
+
+    /// Set the easing curve.
+    pub fn with_easing(mut self, easing : Easing) -> ColorTween {
+        self.easing = easing;
+        self
+    }
+
+    /// Set the interpolation space.
+    pub fn with_space(mut self, space : Space) -> ColorTween {
+        self.space = space;
+        self
+    }
+
+    /// Get the start color.
+    pub fn start(&self) -> RGBA<f32> {
+        self.start
+    }
+
+    /// Get the end color.
+    pub fn end(&self) -> RGBA<f32> {
+        self.end
+    }
+
+    /// Get the duration.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Get the easing curve.
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// Get the interpolation space.
+    pub fn space(&self) -> Space {
+        self.space
+    }
+
+    /// Get the eased progress after some time, 1 at the end and for an empty duration.
+    pub fn progress(&self, elapsed : Duration) -> f32 {
+        if elapsed >= self.duration {
+            return self.easing.apply(1.0);
+        }
+        self.easing.apply(elapsed.as_secs_f32() / self.duration.as_secs_f32())
+    }
+
+    /// Get the color after some time.
+    /// ```
+    /// # extern crate xblend;
+    /// # use xblend::*;
+    /// use std::time::Duration;
+    /// use xblend::tween::{ColorTween, Curve, Ease, Easing};
+    /// let tween = ColorTween::new(rgba!(1.0,0.0,0.0,1.0), rgba!(1.0,0.0,0.0,0.0), Duration::from_secs(1))
+    ///     .with_easing(Easing::Penner(Curve::Cubic, Ease::In));
+    /// assert_eq!(tween.at(Duration::from_millis(500)).a(), 0.875);
+    /// assert_eq!(tween.at_u8(Duration::ZERO), rgba!(255,0,0,255));
+    /// ```
+    pub fn at(&self, elapsed : Duration) -> RGBA<f32> {
+        mix(self.start, self.end, self.progress(elapsed), self.space)
+    }
+
+    /// Get the color after some time, rounded to RGBA&lt;u8&gt;.
+    pub fn at_u8(&self, elapsed : Duration) -> RGBA<u8> {
+        let color = self.at(elapsed);
+        let round = |v : f32| (v * 255.0).round() as u8;
+        RGBA::new(round(color.r()), round(color.g()), round(color.b()), round(color.a()))
+    }
+}